@@ -7,6 +7,58 @@ use crate::handlers::experience_handler::{
     delete_experience,
     add_responsibility,
     add_environment,
+    export_experience_csv,
+};
+use crate::handlers::story_handler::get_stories;
+use crate::handlers::profile_handler::{
+    create_profile,
+    get_profiles,
+    get_profile,
+    update_profile,
+    delete_profile,
+    get_profile_json_ld,
+    get_profile_vcard,
+    get_profile_qr_svg,
+    get_profile_qr_png,
+    get_profile_timeline,
+};
+use crate::handlers::resume_handler::{
+    create_resume,
+    get_resumes,
+    get_resume,
+    update_resume,
+    delete_resume,
+    get_resolved_resume,
+    get_resume_variants,
+    get_resume_overrides,
+    get_ats_report,
+};
+use crate::handlers::snapshot_handler::{
+    create_snapshot,
+    get_snapshots,
+    get_snapshot,
+    diff_snapshots,
+};
+use crate::handlers::export_handler::{export_resume, render_resume_html};
+use crate::handlers::import_handler::{
+    import_europass,
+    import_experience_csv,
+    import_json_resume,
+    import_linkedin,
+    LINKEDIN_ARCHIVE_LIMIT,
+};
+use crate::handlers::sync_handler::{apply_resume_file, export_resume_file, plan_resume_file};
+use crate::handlers::skill_handler::{create_skill, get_skills, get_skill};
+use crate::handlers::category_handler::{create_category, get_categories};
+use crate::handlers::tailor_handler::tailor;
+use crate::handlers::template_handler::{
+    create_template,
+    get_templates,
+    get_template,
+    update_template,
+    delete_template,
+    preview_template,
+    TEMPLATE_UPLOAD_LIMIT,
 };
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
@@ -14,10 +66,89 @@ pub fn configure_routes(cfg: &mut web::ServiceConfig) {
         web::scope("/api/experience")
             .route("", web::post().to(create_experience))
             .route("", web::get().to(get_experiences))
+            // Registered before /{id}, which would otherwise match them
+            .route("/import.csv", web::post().to(import_experience_csv))
+            .route("/export.csv", web::get().to(export_experience_csv))
             .route("/{id}", web::get().to(get_experience))
             .route("/{id}", web::put().to(update_experience))
             .route("/{id}", web::delete().to(delete_experience))
             .route("/add-responsibility", web::post().to(add_responsibility))
             .route("/add-environment", web::post().to(add_environment))
     );
+    cfg.service(
+        web::scope("/api/stories")
+            .route("", web::get().to(get_stories))
+    );
+    cfg.service(
+        web::scope("/api/profile")
+            .route("", web::post().to(create_profile))
+            .route("", web::get().to(get_profiles))
+            .route("/{id}", web::get().to(get_profile))
+            .route("/{id}", web::put().to(update_profile))
+            .route("/{id}", web::delete().to(delete_profile))
+            .route("/{id}/jsonld", web::get().to(get_profile_json_ld))
+            .route("/{id}/vcard", web::get().to(get_profile_vcard))
+            .route("/{id}/qr.svg", web::get().to(get_profile_qr_svg))
+            .route("/{id}/qr.png", web::get().to(get_profile_qr_png))
+            .route("/{id}/timeline", web::get().to(get_profile_timeline))
+    );
+    cfg.service(
+        web::scope("/api/resumes")
+            .route("", web::post().to(create_resume))
+            .route("", web::get().to(get_resumes))
+            .route("/{id}", web::get().to(get_resume))
+            .route("/{id}", web::put().to(update_resume))
+            .route("/{id}", web::delete().to(delete_resume))
+            .route("/{id}/resolved", web::get().to(get_resolved_resume))
+            .route("/{id}/variants", web::get().to(get_resume_variants))
+            .route("/{id}/overrides", web::get().to(get_resume_overrides))
+            .route("/{id}/ats-report", web::get().to(get_ats_report))
+            .route("/{id}/export", web::get().to(export_resume))
+            .route("/{id}/html", web::get().to(render_resume_html))
+            .route("/{id}/snapshots", web::post().to(create_snapshot))
+            .route("/{id}/snapshots", web::get().to(get_snapshots))
+            .route("/{id}/snapshots/diff", web::get().to(diff_snapshots))
+            .route("/{id}/snapshots/{snapshot_id}", web::get().to(get_snapshot))
+    );
+    cfg.service(
+        web::scope("/api/skills")
+            .route("", web::post().to(create_skill))
+            .route("", web::get().to(get_skills))
+            .route("/{id}", web::get().to(get_skill))
+    );
+    cfg.service(
+        web::scope("/api/categories")
+            .route("", web::post().to(create_category))
+            .route("", web::get().to(get_categories))
+    );
+    cfg.service(
+        web::scope("/api/import")
+            .route("/jsonresume", web::post().to(import_json_resume))
+            .route("/europass", web::post().to(import_europass))
+            .service(
+                web::resource("/linkedin")
+                    .app_data(web::PayloadConfig::new(LINKEDIN_ARCHIVE_LIMIT))
+                    .route(web::post().to(import_linkedin))
+            )
+    );
+    cfg.service(
+        web::scope("/api/sync")
+            .route("/export", web::get().to(export_resume_file))
+            .route("/plan", web::post().to(plan_resume_file))
+            .route("/apply", web::post().to(apply_resume_file))
+    );
+    cfg.service(
+        web::scope("/api/templates")
+            .app_data(web::JsonConfig::default().limit(TEMPLATE_UPLOAD_LIMIT))
+            .route("", web::post().to(create_template))
+            .route("", web::get().to(get_templates))
+            .route("/{id}", web::get().to(get_template))
+            .route("/{id}", web::put().to(update_template))
+            .route("/{id}", web::delete().to(delete_template))
+            .route("/{id}/preview", web::get().to(preview_template))
+    );
+    cfg.service(
+        web::scope("/api/tailor")
+            .route("", web::post().to(tailor))
+    );
 }
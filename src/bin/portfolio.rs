@@ -0,0 +1,100 @@
+//! Generates a static portfolio site for a profile:
+//!
+//! ```text
+//! cargo run --bin portfolio -- <profile_id> --base-url https://ada.dev [--out site]
+//!     [--assets DIR] [--theme classic] [--date-format "%b %Y"]
+//! ```
+use std::path::PathBuf;
+use std::process::ExitCode;
+use mongodb::{Client, options::ClientOptions};
+use rust_server::env::load_env;
+use rust_server::models::Experience;
+use rust_server::renderers::html::Theme;
+use rust_server::renderers::site::{render_site, SiteOptions};
+use rust_server::repositories::experience_repository::ExperienceRepository;
+use rust_server::repositories::profile_repository::ProfileRepository;
+use rust_server::repositories::resume_repository::ResumeRepository;
+use rust_server::repositories::skill_repository::SkillRepository;
+use rust_server::services::experience_service::ExperienceService;
+use rust_server::services::profile_service::ProfileService;
+use rust_server::services::resume_service::ResumeService;
+use rust_server::services::site_service::{read_assets, write_site, SiteService};
+use rust_server::services::skill_service::SkillService;
+
+const USAGE: &str = "Usage: portfolio <profile_id> --base-url <url> [--out <dir>] [--assets <dir>] [--theme <name>] [--date-format <pattern>]";
+
+struct Args {
+    profile_id: String,
+    out: PathBuf,
+    assets: Option<PathBuf>,
+    options: SiteOptions,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut profile_id = None;
+    let mut out = PathBuf::from("site");
+    let mut assets = None;
+    let mut options = SiteOptions::default();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or_else(|| format!("{} needs a value", flag));
+        match arg.as_str() {
+            "--base-url" => options.base_url = value(&arg)?,
+            "--out" => out = PathBuf::from(value(&arg)?),
+            "--assets" => assets = Some(PathBuf::from(value(&arg)?)),
+            "--theme" => options.theme = value(&arg)?.parse::<Theme>().map_err(|e| e.to_string())?,
+            "--date-format" => options.date_format = value(&arg)?,
+            flag if flag.starts_with("--") => return Err(format!("Unknown option {}", flag)),
+            _ if profile_id.is_none() => profile_id = Some(arg),
+            _ => return Err(format!("Unexpected argument {}", arg)),
+        }
+    }
+    let profile_id = profile_id.ok_or("A profile id is required")?;
+    options.validate().map_err(|e| e.to_string())?;
+    Ok(Args { profile_id, out, assets, options })
+}
+
+#[actix_web::main]
+async fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(args).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(args: Args) -> Result<(), String> {
+    let env = load_env()?;
+    let client_options = ClientOptions::parse(&env.mongodb_uri).await.map_err(|e| format!("Failed to parse MongoDB URI: {}", e))?;
+    let client = Client::with_options(client_options).map_err(|e| format!("Failed to create MongoDB client: {}", e))?;
+    let db = env.mongodb_database;
+
+    let experience_repo: ExperienceRepository<'_, Experience> = ExperienceRepository::new(&client, &db, "experience");
+    let experience_service = ExperienceService::new(experience_repo);
+    let profile_service = ProfileService::new(ProfileRepository::new(&client, &db, "profile"));
+    let skill_service = SkillService::new(SkillRepository::new(&client, &db, "skill"));
+    let resume_service = ResumeService::new(
+        ResumeRepository::new(&client, &db, "resume"),
+        profile_service.clone(),
+        experience_service.clone(),
+        skill_service,
+    );
+    let site_service = SiteService::new(profile_service, experience_service, resume_service);
+
+    let mut portfolio = site_service.portfolio(&args.profile_id).await.map_err(|e| e.to_string())?;
+    if let Some(dir) = &args.assets {
+        portfolio.assets = read_assets(dir).map_err(|e| e.to_string())?;
+    }
+    let files = render_site(&portfolio, &args.options).map_err(|e| e.to_string())?;
+    write_site(&files, &args.out).map_err(|e| e.to_string())?;
+    println!("Wrote {} files to {}", files.len(), args.out.display());
+    Ok(())
+}
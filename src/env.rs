@@ -4,6 +4,8 @@ pub struct Env {
     pub app_port: u16,
     pub mongodb_uri: String,
     pub mongodb_database: String,
+    /// Lint rules switched off, from the comma-separated `LINT_DISABLED_RULES`.
+    pub lint_disabled_rules: Vec<String>,
 }
 
 // impl Env {
@@ -21,6 +23,7 @@ impl Clone for Env {
             app_port: self.app_port,
             mongodb_uri: self.mongodb_uri.to_string(),
             mongodb_database: self.mongodb_database.to_string(),
+            lint_disabled_rules: self.lint_disabled_rules.clone(),
         }
     }
 }
@@ -42,11 +45,16 @@ pub fn load_env() -> Result<Env, String> {
     let mongodb_database = std::env::var("DATABASE_NAME")
         .unwrap_or("resume-dev".to_string());
 
+    let lint_disabled_rules = std::env::var("LINT_DISABLED_RULES")
+        .map(|rules| rules.split(',').map(str::trim).filter(|rule| !rule.is_empty()).map(String::from).collect())
+        .unwrap_or_default();
+
     Ok(Env {
         env,
         app_name,
         app_port,
         mongodb_uri,
         mongodb_database,
+        lint_disabled_rules,
     })
 }
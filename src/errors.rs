@@ -5,6 +5,7 @@ use serde_json::Error as JsonError;
 use std::fmt;
 use std::io::Error as IoError;
 use chrono::Utc;
+use validator::{ValidationErrors, ValidationErrorsKind};
 
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -16,6 +17,7 @@ pub enum AppError {
     InvalidObjectId(String),
     SerializationError(String),
     IoError(String),
+    NotAcceptable(String),
 }
 
 impl From<MongoError> for AppError {
@@ -36,6 +38,36 @@ impl From<IoError> for AppError {
     }
 }
 
+/// Flattens validator errors into their messages, prefixed with the field
+/// path (e.g. `responsibilities[0].name: Responsibility name is required`),
+/// sorted for a stable order.
+pub fn validation_messages(errors: &ValidationErrors) -> Vec<String> {
+    fn collect(prefix: &str, errors: &ValidationErrors, messages: &mut Vec<String>) {
+        for (field, kind) in errors.errors() {
+            let path = if prefix.is_empty() { field.to_string() } else { format!("{}.{}", prefix, field) };
+            match kind {
+                ValidationErrorsKind::Field(field_errors) => {
+                    for error in field_errors {
+                        let message = error.message.as_deref().unwrap_or(&error.code);
+                        messages.push(format!("{}: {}", path, message));
+                    }
+                }
+                ValidationErrorsKind::Struct(nested) => collect(&path, nested, messages),
+                ValidationErrorsKind::List(items) => {
+                    for (index, nested) in items {
+                        collect(&format!("{}[{}]", path, index), nested, messages);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut messages = Vec::new();
+    collect("", errors, &mut messages);
+    messages.sort();
+    messages
+}
+
 #[derive(Serialize)]
 struct ErrorResponse {
     error: String,
@@ -57,6 +89,7 @@ impl fmt::Display for AppError {
             AppError::InvalidObjectId(msg) => write!(f, "Invalid Object ID: {}", msg),
             AppError::SerializationError(msg) => write!(f, "Serialization Error: {}", msg),
             AppError::IoError(msg) => write!(f, "IO Error: {}", msg),
+            AppError::NotAcceptable(msg) => write!(f, "Not Acceptable: {}", msg),
         }
     }
 }
@@ -134,6 +167,16 @@ impl ResponseError for AppError {
                     timestamp: Utc::now().to_rfc3339(),
                 },
             ),
+            AppError::NotAcceptable(msg) => (
+                actix_web::http::StatusCode::NOT_ACCEPTABLE,
+                ErrorResponse {
+                    error: "not_acceptable".to_string(),
+                    message: msg.to_string(),
+                    details: None,
+                    error_code: Some("ERR_NOT_ACCEPTABLE".to_string()),
+                    timestamp: Utc::now().to_rfc3339(),
+                },
+            ),
         };
 
         HttpResponse::build(status).json(error)
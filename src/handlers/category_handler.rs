@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, web};
+use validator::Validate;
+use crate::models::category::Category;
+use crate::services::category_service::CategoryService;
+use crate::errors::AppError;
+
+pub async fn create_category(
+    service: web::Data<CategoryService>,
+    category: web::Json<Category>,
+) -> Result<HttpResponse, AppError> {
+    category.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.create_category(category.into_inner())
+        .await
+        .map(|created| HttpResponse::Created().json(created))
+}
+
+pub async fn get_categories(
+    service: web::Data<CategoryService>
+) -> Result<HttpResponse, AppError> {
+    service.get_categories()
+        .await
+        .map(|categories| HttpResponse::Ok().json(categories))
+}
@@ -1,22 +1,52 @@
-use actix_web::{HttpResponse, web};
+use actix_web::{HttpRequest, HttpResponse, web};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use chrono::Utc;
+use serde::Deserialize;
 use validator::Validate;
 use crate::models::experience::Experience;
 use crate::models::responsibility::Responsibility;
 use crate::models::skill::Skill;
+use crate::handlers::export_handler::{accept, negotiated, serialized};
+use crate::renderers::{experience_resume, ordered_experience, DEFAULT_DATE_FORMAT};
+use crate::renderers::experience_csv::{render_experience_csv, CSV_MIME};
+use crate::renderers::markdown::render_experience;
+use crate::renderers::negotiation::{negotiate, MediaType};
+use crate::renderers::pdf::{render_pdf, PdfOptions};
+use crate::renderers::text::{render_experience_text, TextOptions};
 use crate::services::experience_service::ExperienceService;
+use crate::services::lint::Linter;
 use crate::errors::AppError;
 
+#[derive(Debug, Deserialize)]
+pub struct LintQuery {
+    /// Return the responsibilities' lint findings with the response.
+    #[serde(default)]
+    pub lint: bool,
+}
+
+fn lint_response(mut response: actix_web::HttpResponseBuilder, linter: &Linter, experience: Option<&Experience>) -> HttpResponse {
+    match experience {
+        Some(experience) => response.json(linter.lint_experience(experience, Utc::now().date_naive())),
+        None => response.finish(),
+    }
+}
+
 pub async fn create_experience(
     service: web::Data<ExperienceService<'_, Experience>>,
+    linter: web::Data<Linter>,
+    query: web::Query<LintQuery>,
     experience: web::Json<Experience>,
 ) -> Result<HttpResponse, AppError> {
     experience.0.validate().map_err(|err| {
         AppError::ValidationError(err.to_string())
     })?;
     
-    service.create_experience(experience.into_inner())
+    let mut experience = experience.into_inner();
+    experience.assign_responsibility_ids();
+    let linted = query.lint.then(|| experience.clone());
+    service.create_experience(experience)
         .await
-        .map(|_| HttpResponse::Created().finish())
+        .map(|_| lint_response(HttpResponse::Created(), &linter, linted.as_ref()))
         .map_err(|err| AppError::DatabaseError(err.to_string()))
 }
 
@@ -31,20 +61,30 @@ pub async fn get_experiences(
 }
 
 pub async fn get_experience(
+    request: HttpRequest,
     service: web::Data<ExperienceService<'_, Experience>>,
     id: web::Path<String>
 ) -> Result<HttpResponse, AppError> {
-    service.get_experience(&id)
+    let media = negotiate(accept(&request), MediaType::ALL)?;
+    let experience = service.get_experience(&id)
         .await
-        .map(|experience| match experience {
-            Some(exp) => HttpResponse::Ok().json(exp),
-            None => HttpResponse::NotFound().finish(),
-        })
-        .map_err(|err| AppError::DatabaseError(err.to_string()))
+        .map_err(|err| AppError::DatabaseError(err.to_string()))?;
+    let Some(experience) = experience else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+    let body = match media {
+        MediaType::Json | MediaType::Yaml => serialized(media, &experience)?,
+        MediaType::Markdown => render_experience(&ordered_experience(&experience), 1, DEFAULT_DATE_FORMAT).into_bytes(),
+        MediaType::Text => render_experience_text(&ordered_experience(&experience), &TextOptions::default()).into_bytes(),
+        MediaType::Pdf => render_pdf(&experience_resume(&experience), &PdfOptions::default())?,
+    };
+    Ok(negotiated(media, body))
 }
 
 pub async fn update_experience(
     service: web::Data<ExperienceService<'_, Experience>>,
+    linter: web::Data<Linter>,
+    query: web::Query<LintQuery>,
     id: web::Path<String>,
     experience: web::Json<Experience>,
 ) -> Result<HttpResponse, AppError> {
@@ -52,9 +92,12 @@ pub async fn update_experience(
         AppError::ValidationError(err.to_string())
     })?;
     
-    service.update_experience(&id, experience.into_inner())
+    let mut experience = experience.into_inner();
+    experience.assign_responsibility_ids();
+    let linted = query.lint.then(|| experience.clone());
+    service.update_experience(&id, experience)
         .await
-        .map(|_| HttpResponse::Ok().finish())
+        .map(|_| lint_response(HttpResponse::Ok(), &linter, linted.as_ref()))
         .map_err(|err| AppError::DatabaseError(err.to_string()))
 }
 
@@ -97,3 +140,19 @@ pub async fn add_environment(
         .map(|_| HttpResponse::Created().finish())
         .map_err(|err| AppError::DatabaseError(err.to_string()))
 }
+
+pub async fn export_experience_csv(
+    service: web::Data<ExperienceService<'_, Experience>>,
+) -> Result<HttpResponse, AppError> {
+    let experiences = match service.get_experiences().await {
+        Err(AppError::NotFound(_)) => Vec::new(),
+        other => other?,
+    };
+    Ok(HttpResponse::Ok()
+        .content_type(format!("{}; charset=utf-8", CSV_MIME))
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename("experiences.csv".to_string())],
+        })
+        .body(render_experience_csv(&experiences)))
+}
@@ -0,0 +1,186 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use actix_web::http::header::{self, ContentDisposition, DispositionParam, DispositionType};
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::resume::ResolvedResume;
+use crate::renderers::{check_date_format, file_name, ExportFormat, DEFAULT_DATE_FORMAT};
+use crate::renderers::docx::{render_docx, DocxOptions, DOCX_MIME};
+use crate::renderers::europass::render_europass;
+use crate::renderers::html::{render_html, Theme};
+use crate::renderers::json_resume::export_json_resume;
+use crate::renderers::latex::{render_latex, LatexLayout, LatexOptions};
+use crate::renderers::markdown::{render_markdown, MarkdownOptions};
+use crate::renderers::negotiation::MediaType;
+use crate::renderers::pdf::{render_pdf, PageSize, PdfOptions};
+use crate::renderers::text::{render_text, TextOptions};
+use crate::renderers::yaml::render_yaml;
+use crate::services::resume_service::ResumeService;
+use crate::errors::AppError;
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    pub format: String,
+    pub heading_level: Option<u8>,
+    pub date_format: Option<String>,
+    pub page_size: Option<String>,
+    pub layout: Option<String>,
+    pub line_width: Option<usize>,
+    pub ascii: Option<bool>,
+}
+
+pub async fn export_resume(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>,
+    query: web::Query<ExportQuery>,
+) -> Result<HttpResponse, AppError> {
+    let format: ExportFormat = query.format.parse()?;
+    let date_format = query.date_format.clone().unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string());
+    let page_size = match &query.page_size {
+        Some(size) => size.parse()?,
+        None => PageSize::default(),
+    };
+
+    match format {
+        ExportFormat::JsonResume => {
+            let resume = service.resolve(&id).await?;
+            let generated_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+            let document = export_json_resume(&resume, &generated_at)?;
+            Ok(HttpResponse::Ok().json(document))
+        }
+        ExportFormat::Markdown => {
+            let options = MarkdownOptions {
+                heading_level: query.heading_level.unwrap_or(1),
+                date_format,
+            };
+            options.validate()?;
+            let resume = service.resolve(&id).await?;
+            Ok(HttpResponse::Ok()
+                .content_type("text/markdown; charset=utf-8")
+                .body(render_markdown(&resume, &options)))
+        }
+        ExportFormat::Pdf => {
+            let options = PdfOptions { page_size, date_format };
+            options.validate()?;
+            let resume = service.resolve(&id).await?;
+            let document = render_pdf(&resume, &options)?;
+            Ok(HttpResponse::Ok()
+                .content_type("application/pdf")
+                .insert_header(attachment(file_name(&resume, "pdf")))
+                .body(document))
+        }
+        ExportFormat::Latex => {
+            let options = LatexOptions {
+                layout: match &query.layout {
+                    Some(layout) => layout.parse()?,
+                    None => LatexLayout::default(),
+                },
+                date_format,
+            };
+            options.validate()?;
+            let resume = service.resolve(&id).await?;
+            Ok(HttpResponse::Ok()
+                .content_type("application/x-tex; charset=utf-8")
+                .insert_header(attachment(file_name(&resume, "tex")))
+                .body(render_latex(&resume, &options)))
+        }
+        ExportFormat::Docx => {
+            let options = DocxOptions { page_size, date_format };
+            options.validate()?;
+            let resume = service.resolve(&id).await?;
+            let generated_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+            let document = render_docx(&resume, &options, &generated_at)?;
+            Ok(HttpResponse::Ok()
+                .content_type(DOCX_MIME)
+                .insert_header(attachment(file_name(&resume, "docx")))
+                .body(document))
+        }
+        ExportFormat::Text => {
+            let defaults = TextOptions::default();
+            let options = TextOptions {
+                line_width: query.line_width.unwrap_or(defaults.line_width),
+                ascii: query.ascii.unwrap_or(defaults.ascii),
+            };
+            options.validate()?;
+            let resume = service.resolve(&id).await?;
+            Ok(HttpResponse::Ok()
+                .content_type("text/plain; charset=utf-8")
+                .body(render_text(&resume, &options)))
+        }
+        ExportFormat::Europass => {
+            let resume = service.resolve(&id).await?;
+            let generated_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+            Ok(HttpResponse::Ok()
+                .content_type("application/xml; charset=utf-8")
+                .insert_header(attachment(file_name(&resume, "xml")))
+                .body(render_europass(&resume, &generated_at)))
+        }
+    }
+}
+
+fn attachment(file_name: String) -> ContentDisposition {
+    ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(file_name)],
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HtmlQuery {
+    pub theme: Option<String>,
+    pub date_format: Option<String>,
+}
+
+pub async fn render_resume_html(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>,
+    query: web::Query<HtmlQuery>,
+) -> Result<HttpResponse, AppError> {
+    let theme = match &query.theme {
+        Some(theme) => theme.parse()?,
+        None => Theme::default(),
+    };
+    let date_format = query.date_format.as_deref().unwrap_or(DEFAULT_DATE_FORMAT);
+    check_date_format(date_format)?;
+
+    let resume = service.resolve(&id).await?;
+    Ok(HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(render_html(&resume, theme, date_format)?))
+}
+
+/// The `Accept` header of a request, when it is readable.
+pub(crate) fn accept(request: &HttpRequest) -> Option<&str> {
+    request.headers().get(header::ACCEPT).and_then(|value| value.to_str().ok())
+}
+
+/// Response carrying `body` as `media`, marked as varying with `Accept`.
+pub(crate) fn negotiated(media: MediaType, body: Vec<u8>) -> HttpResponse {
+    let content_type = match media {
+        MediaType::Pdf => media.mime_type().to_string(),
+        _ => format!("{}; charset=utf-8", media.mime_type()),
+    };
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((header::VARY, "Accept"))
+        .body(body)
+}
+
+/// Serializes a record as JSON or YAML, the representations of the record
+/// itself rather than of a rendered document.
+pub(crate) fn serialized<T: Serialize>(media: MediaType, record: &T) -> Result<Vec<u8>, AppError> {
+    match media {
+        MediaType::Yaml => Ok(render_yaml(record)?.into_bytes()),
+        _ => Ok(serde_json::to_vec(record)?),
+    }
+}
+
+/// Renders a resolved resume as a document, with the default options of
+/// the matching export.
+pub(crate) fn rendered(media: MediaType, resume: &ResolvedResume) -> Result<Vec<u8>, AppError> {
+    match media {
+        MediaType::Markdown => Ok(render_markdown(resume, &MarkdownOptions::default()).into_bytes()),
+        MediaType::Text => Ok(render_text(resume, &TextOptions::default()).into_bytes()),
+        MediaType::Pdf => render_pdf(resume, &PdfOptions::default()),
+        MediaType::Json | MediaType::Yaml => serialized(media, resume),
+    }
+}
@@ -0,0 +1,76 @@
+use actix_web::{HttpResponse, web};
+use serde::Deserialize;
+use serde_json::Value;
+use crate::importers::experience_csv::CsvImportMode;
+use crate::services::import_service::ImportService;
+use crate::errors::AppError;
+
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+pub async fn import_json_resume(
+    service: web::Data<ImportService>,
+    query: web::Query<ImportQuery>,
+    document: web::Json<Value>,
+) -> Result<HttpResponse, AppError> {
+    service.import_json_resume(&document, query.dry_run)
+        .await
+        .map(|report| HttpResponse::Ok().json(report))
+}
+
+pub async fn import_europass(
+    service: web::Data<ImportService>,
+    query: web::Query<ImportQuery>,
+    document: String,
+) -> Result<HttpResponse, AppError> {
+    service.import_europass(&document, query.dry_run)
+        .await
+        .map(|report| HttpResponse::Ok().json(report))
+}
+
+/// Largest LinkedIn data export accepted, in bytes.
+pub const LINKEDIN_ARCHIVE_LIMIT: usize = 20 * 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct LinkedInQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+    pub profile_id: Option<String>,
+}
+
+pub async fn import_linkedin(
+    service: web::Data<ImportService>,
+    query: web::Query<LinkedInQuery>,
+    archive: web::Bytes,
+) -> Result<HttpResponse, AppError> {
+    service.import_linkedin(&archive, query.profile_id.as_deref(), query.dry_run)
+        .await
+        .map(|report| HttpResponse::Ok().json(report))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CsvImportQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+    pub mode: Option<String>,
+}
+
+/// Responds 422 with the report when an all-or-nothing import was refused.
+pub async fn import_experience_csv(
+    service: web::Data<ImportService>,
+    query: web::Query<CsvImportQuery>,
+    text: String,
+) -> Result<HttpResponse, AppError> {
+    let mode = match &query.mode {
+        Some(mode) => mode.parse()?,
+        None => CsvImportMode::default(),
+    };
+    let report = service.import_experience_csv(&text, mode, query.dry_run).await?;
+    if mode == CsvImportMode::AllOrNothing && !report.failures.is_empty() {
+        return Ok(HttpResponse::UnprocessableEntity().json(report));
+    }
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -1 +1,12 @@
-pub (crate) mod experience_handler;
\ No newline at end of file
+pub (crate) mod experience_handler;
+pub (crate) mod story_handler;
+pub (crate) mod profile_handler;
+pub (crate) mod resume_handler;
+pub (crate) mod skill_handler;
+pub (crate) mod category_handler;
+pub (crate) mod snapshot_handler;
+pub (crate) mod export_handler;
+pub (crate) mod import_handler;
+pub (crate) mod sync_handler;
+pub (crate) mod template_handler;
+pub (crate) mod tailor_handler;
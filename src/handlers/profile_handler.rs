@@ -0,0 +1,184 @@
+use actix_web::{HttpResponse, web};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use chrono::Utc;
+use serde::Deserialize;
+use validator::Validate;
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::skill::Skill;
+use crate::renderers::json_ld::{person, JSON_LD_MIME};
+use crate::renderers::qr::{render_qr_png, render_qr_svg, QrContent, QrOptions};
+use crate::renderers::slug;
+use crate::renderers::vcard::{render_vcard, VCARD_MIME};
+use crate::services::experience_service::ExperienceService;
+use crate::services::profile_service::ProfileService;
+use crate::services::timeline::{timeline, DEFAULT_GAP_DAYS};
+use crate::errors::AppError;
+
+pub async fn create_profile(
+    service: web::Data<ProfileService>,
+    profile: web::Json<Profile>,
+) -> Result<HttpResponse, AppError> {
+    profile.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.create_profile(profile.into_inner())
+        .await
+        .map(|created| HttpResponse::Created().json(created))
+}
+
+pub async fn get_profiles(
+    service: web::Data<ProfileService>
+) -> Result<HttpResponse, AppError> {
+    service.get_profiles()
+        .await
+        .map(|profiles| HttpResponse::Ok().json(profiles))
+}
+
+pub async fn get_profile(
+    service: web::Data<ProfileService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.get_profile(&id)
+        .await
+        .map(|profile| match profile {
+            Some(profile) => HttpResponse::Ok().json(profile),
+            None => HttpResponse::NotFound().finish(),
+        })
+}
+
+pub async fn update_profile(
+    service: web::Data<ProfileService>,
+    id: web::Path<String>,
+    profile: web::Json<Profile>,
+) -> Result<HttpResponse, AppError> {
+    profile.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.update_profile(&id, profile.into_inner())
+        .await
+        .map(|_| HttpResponse::Ok().finish())
+}
+
+pub async fn delete_profile(
+    service: web::Data<ProfileService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.delete_profile(&id)
+        .await
+        .map(|_| HttpResponse::NoContent().finish())
+}
+
+/// The profile and all of its experiences as a schema.org `Person`; the
+/// skills used across those experiences are listed as `knowsAbout`.
+pub async fn get_profile_json_ld(
+    profile_service: web::Data<ProfileService>,
+    experience_service: web::Data<ExperienceService<'static, Experience>>,
+    id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let profile = find_profile(&profile_service, &id).await?;
+    let profile_id = profile.id.ok_or_else(|| AppError::InternalServerError("Stored profile has no id".to_string()))?;
+    let experiences = experience_service.get_experiences_by_profile(&profile_id).await?;
+    let skills: Vec<&Skill> = experiences.iter().flat_map(|e| &e.environment).collect();
+    let document = person(&profile, profile.summary.as_deref(), &experiences, &skills);
+    Ok(HttpResponse::Ok().content_type(JSON_LD_MIME).json(document))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TimelineQuery {
+    /// Shortest gap reported, in days.
+    pub gap_days: Option<u32>,
+}
+
+/// Gaps, overlaps and yearly totals of the profile's experiences.
+pub async fn get_profile_timeline(
+    profile_service: web::Data<ProfileService>,
+    experience_service: web::Data<ExperienceService<'static, Experience>>,
+    id: web::Path<String>,
+    query: web::Query<TimelineQuery>,
+) -> Result<HttpResponse, AppError> {
+    let profile = find_profile(&profile_service, &id).await?;
+    let profile_id = profile.id.ok_or_else(|| AppError::InternalServerError("Stored profile has no id".to_string()))?;
+    let experiences = experience_service.get_experiences_by_profile(&profile_id).await?;
+    let gap_days = query.gap_days.unwrap_or(DEFAULT_GAP_DAYS);
+    Ok(HttpResponse::Ok().json(timeline(&experiences, gap_days, Utc::now().date_naive())))
+}
+
+pub async fn get_profile_vcard(
+    service: web::Data<ProfileService>,
+    id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    let profile = find_profile(&service, &id).await?;
+    let stem = slug(&profile.name);
+    Ok(HttpResponse::Ok()
+        .content_type(format!("{}; charset=utf-8", VCARD_MIME))
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "{}.vcf",
+                if stem.is_empty() { "contact" } else { &stem }
+            ))],
+        })
+        .body(render_vcard(&profile)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QrQuery {
+    pub content: Option<String>,
+    pub scale: Option<u32>,
+    pub border: Option<u32>,
+}
+
+pub async fn get_profile_qr_svg(
+    service: web::Data<ProfileService>,
+    id: web::Path<String>,
+    query: web::Query<QrQuery>,
+) -> Result<HttpResponse, AppError> {
+    let (data, options) = qr_code(&service, &id, &query).await?;
+    Ok(HttpResponse::Ok()
+        .content_type("image/svg+xml")
+        .body(render_qr_svg(&data, &options)?))
+}
+
+pub async fn get_profile_qr_png(
+    service: web::Data<ProfileService>,
+    id: web::Path<String>,
+    query: web::Query<QrQuery>,
+) -> Result<HttpResponse, AppError> {
+    let (data, options) = qr_code(&service, &id, &query).await?;
+    Ok(HttpResponse::Ok()
+        .content_type("image/png")
+        .body(render_qr_png(&data, &options)?))
+}
+
+// The text to encode and the validated rendering options. The vCard leaves
+// out the summary, which would make the code too dense to scan.
+async fn qr_code(service: &ProfileService, id: &str, query: &QrQuery) -> Result<(String, QrOptions), AppError> {
+    let content = match &query.content {
+        Some(content) => content.parse()?,
+        None => QrContent::default(),
+    };
+    let defaults = QrOptions::default();
+    let options = QrOptions {
+        scale: query.scale.unwrap_or(defaults.scale),
+        border: query.border.unwrap_or(defaults.border),
+    };
+    options.validate()?;
+
+    let profile = find_profile(service, id).await?;
+    let data = match content {
+        QrContent::Vcard => render_vcard(&Profile { summary: None, ..profile }),
+        QrContent::Url => profile.url.ok_or_else(|| AppError::ValidationError(
+            "Profile has no url to encode; use content=vcard".to_string()
+        ))?,
+    };
+    Ok((data, options))
+}
+
+async fn find_profile(service: &ProfileService, id: &str) -> Result<Profile, AppError> {
+    service.get_profile(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Profile {} not found", id)))
+}
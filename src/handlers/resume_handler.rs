@@ -0,0 +1,112 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use validator::Validate;
+use crate::handlers::export_handler::{accept, negotiated, rendered, serialized};
+use crate::models::resume::Resume;
+use crate::renderers::negotiation::{negotiate, MediaType};
+use crate::services::ats::ats_report;
+use crate::services::resume_service::ResumeService;
+use crate::errors::AppError;
+
+#[derive(Debug, Deserialize)]
+pub struct AtsQuery {
+    pub job_description: Option<String>,
+}
+
+pub async fn create_resume(
+    service: web::Data<ResumeService>,
+    resume: web::Json<Resume>,
+) -> Result<HttpResponse, AppError> {
+    resume.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.create_resume(resume.into_inner())
+        .await
+        .map(|created| HttpResponse::Created().json(created))
+}
+
+pub async fn get_resumes(
+    service: web::Data<ResumeService>
+) -> Result<HttpResponse, AppError> {
+    service.get_resumes()
+        .await
+        .map(|resumes| HttpResponse::Ok().json(resumes))
+}
+
+pub async fn get_resume(
+    request: HttpRequest,
+    service: web::Data<ResumeService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    let media = negotiate(accept(&request), MediaType::ALL)?;
+    let body = match media {
+        MediaType::Json | MediaType::Yaml => match service.get_resume(&id).await? {
+            Some(resume) => serialized(media, &resume)?,
+            None => return Err(AppError::NotFound(format!("Resume {} not found", id))),
+        },
+        _ => rendered(media, &service.resolve(&id).await?)?,
+    };
+    Ok(negotiated(media, body))
+}
+
+pub async fn update_resume(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>,
+    resume: web::Json<Resume>,
+) -> Result<HttpResponse, AppError> {
+    resume.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.update_resume(&id, resume.into_inner())
+        .await
+        .map(|_| HttpResponse::Ok().finish())
+}
+
+pub async fn delete_resume(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.delete_resume(&id)
+        .await
+        .map(|_| HttpResponse::NoContent().finish())
+}
+
+pub async fn get_resolved_resume(
+    request: HttpRequest,
+    service: web::Data<ResumeService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    let media = negotiate(accept(&request), MediaType::ALL)?;
+    let resolved = service.resolve(&id).await?;
+    Ok(negotiated(media, rendered(media, &resolved)?))
+}
+
+pub async fn get_resume_variants(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.get_variants(&id)
+        .await
+        .map(|variants| HttpResponse::Ok().json(variants))
+}
+
+pub async fn get_resume_overrides(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.get_overrides(&id)
+        .await
+        .map(|overrides| HttpResponse::Ok().json(overrides))
+}
+
+pub async fn get_ats_report(
+    service: web::Data<ResumeService>,
+    id: web::Path<String>,
+    query: web::Query<AtsQuery>,
+) -> Result<HttpResponse, AppError> {
+    let resolved = service.resolve(&id).await?;
+    let job_description = query.job_description.as_deref().filter(|jd| !jd.trim().is_empty());
+    Ok(HttpResponse::Ok().json(ats_report(&resolved, job_description)))
+}
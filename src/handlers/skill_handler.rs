@@ -0,0 +1,38 @@
+use actix_web::{HttpResponse, web};
+use validator::Validate;
+use crate::models::skill::Skill;
+use crate::services::skill_service::SkillService;
+use crate::errors::AppError;
+
+pub async fn create_skill(
+    service: web::Data<SkillService>,
+    skill: web::Json<Skill>,
+) -> Result<HttpResponse, AppError> {
+    skill.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.create_skill(skill.into_inner())
+        .await
+        .map(|created| HttpResponse::Created().json(created))
+}
+
+pub async fn get_skills(
+    service: web::Data<SkillService>
+) -> Result<HttpResponse, AppError> {
+    service.get_skills()
+        .await
+        .map(|skills| HttpResponse::Ok().json(skills))
+}
+
+pub async fn get_skill(
+    service: web::Data<SkillService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.get_skill(&id)
+        .await
+        .map(|skill| match skill {
+            Some(skill) => HttpResponse::Ok().json(skill),
+            None => HttpResponse::NotFound().finish(),
+        })
+}
@@ -0,0 +1,58 @@
+use actix_web::{HttpResponse, web};
+use serde::Deserialize;
+use validator::Validate;
+use crate::models::snapshot::CreateSnapshot;
+use crate::services::snapshot_service::SnapshotService;
+use crate::errors::AppError;
+
+#[derive(Debug, Deserialize)]
+pub struct DiffQuery {
+    pub from: String,
+    pub to: String,
+}
+
+pub async fn create_snapshot(
+    service: web::Data<SnapshotService>,
+    id: web::Path<String>,
+    request: web::Json<CreateSnapshot>,
+) -> Result<HttpResponse, AppError> {
+    request.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.freeze(&id, request.into_inner().label)
+        .await
+        .map(|snapshot| HttpResponse::Created().json(snapshot))
+}
+
+pub async fn get_snapshots(
+    service: web::Data<SnapshotService>,
+    id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    service.get_snapshots(&id)
+        .await
+        .map(|snapshots| HttpResponse::Ok().json(snapshots))
+}
+
+pub async fn get_snapshot(
+    service: web::Data<SnapshotService>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, AppError> {
+    let (id, snapshot_id) = path.into_inner();
+    service.get_snapshot(&id, &snapshot_id)
+        .await
+        .map(|snapshot| match snapshot {
+            Some(snapshot) => HttpResponse::Ok().json(snapshot),
+            None => HttpResponse::NotFound().finish(),
+        })
+}
+
+pub async fn diff_snapshots(
+    service: web::Data<SnapshotService>,
+    id: web::Path<String>,
+    query: web::Query<DiffQuery>,
+) -> Result<HttpResponse, AppError> {
+    service.diff(&id, &query.from, &query.to)
+        .await
+        .map(|diff| HttpResponse::Ok().json(diff))
+}
@@ -0,0 +1,19 @@
+use actix_web::{HttpResponse, web};
+use serde::Deserialize;
+use crate::models::experience::Experience;
+use crate::services::experience_service::ExperienceService;
+use crate::errors::AppError;
+
+#[derive(Debug, Deserialize)]
+pub struct StoryQuery {
+    pub skill: Option<String>,
+}
+
+pub async fn get_stories(
+    service: web::Data<ExperienceService<'static, Experience>>,
+    query: web::Query<StoryQuery>,
+) -> Result<HttpResponse, AppError> {
+    service.get_stories(query.skill.as_deref())
+        .await
+        .map(|stories| HttpResponse::Ok().json(stories))
+}
@@ -0,0 +1,57 @@
+use actix_web::{HttpResponse, web};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use serde::Deserialize;
+use crate::models::resume_file::{FileFormat, ResumeFile};
+use crate::services::sync_service::SyncService;
+use crate::errors::AppError;
+
+#[derive(Debug, Deserialize)]
+pub struct SyncQuery {
+    pub format: Option<String>,
+}
+
+impl SyncQuery {
+    fn format(&self) -> Result<FileFormat, AppError> {
+        match &self.format {
+            Some(format) => format.parse(),
+            None => Ok(FileFormat::default()),
+        }
+    }
+}
+
+pub async fn export_resume_file(
+    service: web::Data<SyncService>,
+    query: web::Query<SyncQuery>,
+) -> Result<HttpResponse, AppError> {
+    let format = query.format()?;
+    let body = service.export().await?.render(format)?;
+    Ok(HttpResponse::Ok()
+        .content_type(format!("{}; charset=utf-8", format.mime_type()))
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!("resume.{}", format.extension()))],
+        })
+        .body(body))
+}
+
+pub async fn plan_resume_file(
+    service: web::Data<SyncService>,
+    query: web::Query<SyncQuery>,
+    text: String,
+) -> Result<HttpResponse, AppError> {
+    let mut file = ResumeFile::parse(&text, query.format()?)?;
+    service.plan(&mut file)
+        .await
+        .map(|plan| HttpResponse::Ok().json(plan))
+}
+
+pub async fn apply_resume_file(
+    service: web::Data<SyncService>,
+    query: web::Query<SyncQuery>,
+    text: String,
+) -> Result<HttpResponse, AppError> {
+    let file = ResumeFile::parse(&text, query.format()?)?;
+    service.apply(file)
+        .await
+        .map(|plan| HttpResponse::Ok().json(plan))
+}
@@ -0,0 +1,20 @@
+use actix_web::{HttpResponse, web};
+use validator::Validate;
+use crate::models::tailor::TailorRequest;
+use crate::services::tailor_service::TailorService;
+use crate::errors::AppError;
+
+pub async fn tailor(
+    service: web::Data<TailorService>,
+    request: web::Json<TailorRequest>,
+) -> Result<HttpResponse, AppError> {
+    request.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    let report = service.tailor(&request).await?;
+    Ok(match report.resume {
+        Some(_) => HttpResponse::Created().json(report),
+        None => HttpResponse::Ok().json(report),
+    })
+}
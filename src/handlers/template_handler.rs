@@ -0,0 +1,86 @@
+use actix_web::{HttpResponse, web};
+use serde::Deserialize;
+use validator::Validate;
+use crate::models::template::Template;
+use crate::renderers::{check_date_format, DEFAULT_DATE_FORMAT};
+use crate::services::template_service::TemplateService;
+use crate::errors::AppError;
+
+/// Largest template upload accepted, in bytes. Assets are base64 encoded,
+/// which takes a third more room than the files themselves.
+pub const TEMPLATE_UPLOAD_LIMIT: usize = 4 * 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct PreviewQuery {
+    pub resume_id: String,
+    pub date_format: Option<String>,
+}
+
+pub async fn create_template(
+    service: web::Data<TemplateService>,
+    template: web::Json<Template>,
+) -> Result<HttpResponse, AppError> {
+    template.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.create_template(template.into_inner())
+        .await
+        .map(|created| HttpResponse::Created().json(created))
+}
+
+pub async fn get_templates(
+    service: web::Data<TemplateService>
+) -> Result<HttpResponse, AppError> {
+    service.get_templates()
+        .await
+        .map(|templates| HttpResponse::Ok().json(templates))
+}
+
+pub async fn get_template(
+    service: web::Data<TemplateService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.get_template(&id)
+        .await
+        .map(|template| match template {
+            Some(template) => HttpResponse::Ok().json(template),
+            None => HttpResponse::NotFound().finish(),
+        })
+}
+
+pub async fn update_template(
+    service: web::Data<TemplateService>,
+    id: web::Path<String>,
+    template: web::Json<Template>,
+) -> Result<HttpResponse, AppError> {
+    template.0.validate().map_err(|err| {
+        AppError::ValidationError(err.to_string())
+    })?;
+
+    service.update_template(&id, template.into_inner())
+        .await
+        .map(|_| HttpResponse::Ok().finish())
+}
+
+pub async fn delete_template(
+    service: web::Data<TemplateService>,
+    id: web::Path<String>
+) -> Result<HttpResponse, AppError> {
+    service.delete_template(&id)
+        .await
+        .map(|_| HttpResponse::NoContent().finish())
+}
+
+pub async fn preview_template(
+    service: web::Data<TemplateService>,
+    id: web::Path<String>,
+    query: web::Query<PreviewQuery>,
+) -> Result<HttpResponse, AppError> {
+    let date_format = query.date_format.clone().unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string());
+    check_date_format(&date_format)?;
+
+    service.preview(&id, &query.resume_id, date_format)
+        .await
+        .map(|html| HttpResponse::Ok().content_type("text/html; charset=utf-8").body(html))
+}
@@ -0,0 +1,405 @@
+use chrono::NaiveDate;
+use lazy_static::lazy_static;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use regex::Regex;
+use crate::importers::{linked_responsibilities, normalize_partial_date, validated, ImportReport, MappedImport};
+use crate::models::category::Category;
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::language::{Language, CEFR_REGEX};
+use crate::models::profile::{Profile, ProfileLink};
+use crate::models::skill::Skill;
+use crate::renderers::europass::{PERSONAL_WEBSITE, PROFICIENCY_SKILLS};
+use crate::renderers::UNCATEGORIZED;
+
+lazy_static! {
+    // Tags that end an item of rich text: list items, paragraphs, line breaks.
+    static ref BREAK_TAG: Regex = Regex::new(r"(?i)<\s*(li|p|br)\b[^>]*>|<\s*/\s*(li|p|ul|ol)\s*>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+const ROOT_FIELDS: &[&str] = &["DocumentInfo", "LearnerInfo"];
+const LEARNER_FIELDS: &[&str] = &["Identification", "Headline", "WorkExperienceList", "EducationList", "Skills"];
+const IDENTIFICATION_FIELDS: &[&str] = &["PersonName", "ContactInfo"];
+const CONTACT_FIELDS: &[&str] = &["Address", "Email", "TelephoneList", "WebsiteList"];
+const WORK_FIELDS: &[&str] = &["Period", "Position", "Activities", "Employer"];
+const EMPLOYER_FIELDS: &[&str] = &["Name", "ContactInfo"];
+const EDUCATION_FIELDS: &[&str] = &["Period", "Title", "Organisation", "Field"];
+const SKILLS_FIELDS: &[&str] = &["Linguistic", "Computer"];
+const FOREIGN_LANGUAGE_FIELDS: &[&str] = &["Description", "ProficiencyLevel"];
+
+/// Maps a Europass CV (XML schema v3) onto our models, the same way
+/// [`map_json_resume`](crate::importers::json_resume::map_json_resume)
+/// does: invalid entries are reported as failures and skipped, and elements
+/// without a counterpart are listed as unmapped. A work experience without
+/// an end date is ongoing and ends on `today`. Activities and the computer
+/// skills description are XHTML; each list item (or paragraph) becomes one
+/// responsibility or one `Category: skill, skill` line. A foreign language
+/// takes the lowest of its five CEFR levels.
+pub fn map_europass(xml: &str, today: NaiveDate) -> MappedImport {
+    let mut import = MappedImport::default();
+    let report = &mut import.report;
+    let root = match parse(xml) {
+        Ok(root) => root,
+        Err(message) => {
+            report.fail("$", vec![message]);
+            return import;
+        }
+    };
+    if root.name != "SkillsPassport" {
+        report.fail("$", vec![format!("Root element must be SkillsPassport, found {}", root.name)]);
+        return import;
+    }
+    root.unmapped(ROOT_FIELDS, "", report);
+    let Some(learner) = root.child("LearnerInfo") else {
+        report.fail("LearnerInfo", vec!["LearnerInfo is required".to_string()]);
+        return import;
+    };
+    learner.unmapped(LEARNER_FIELDS, "LearnerInfo", report);
+
+    import.profile = map_identification(learner, report);
+
+    for (index, node) in learner.find_all(&["WorkExperienceList", "WorkExperience"]).enumerate() {
+        let source = format!("LearnerInfo.WorkExperienceList.WorkExperience[{}]", index);
+        if let Some(experience) = map_work(&source, node, today, report) {
+            import.experiences.push((source, experience));
+        }
+    }
+
+    let education: Vec<Education> = learner.find_all(&["EducationList", "Education"])
+        .enumerate()
+        .filter_map(|(index, node)| {
+            map_education(&format!("LearnerInfo.EducationList.Education[{}]", index), node, report)
+        })
+        .collect();
+
+    let mut languages = Vec::new();
+    if let Some(skills) = learner.child("Skills") {
+        skills.unmapped(SKILLS_FIELDS, "LearnerInfo.Skills", report);
+        languages = map_languages(skills, report);
+        if let Some(description) = skills.text(&["Computer", "Description"]) {
+            for (index, line) in html_items(&description).iter().enumerate() {
+                let source = format!("LearnerInfo.Skills.Computer.Description[{}]", index);
+                import.skills.extend(map_skill_line(&source, line, report));
+            }
+        }
+    }
+
+    match &mut import.profile {
+        Some(profile) => {
+            profile.education = education;
+            profile.languages = languages;
+        }
+        None => {
+            if !education.is_empty() {
+                report.warnings.push("EducationList: skipped because Identification could not be imported".to_string());
+            }
+            if !languages.is_empty() {
+                report.warnings.push("Skills.Linguistic: skipped because Identification could not be imported".to_string());
+            }
+        }
+    }
+    import
+}
+
+fn map_identification(learner: &Node, report: &mut ImportReport) -> Option<Profile> {
+    let source = "LearnerInfo.Identification";
+    let Some(identification) = learner.child("Identification") else {
+        report.fail(source, vec!["Identification is required".to_string()]);
+        return None;
+    };
+    identification.unmapped(IDENTIFICATION_FIELDS, source, report);
+
+    let name: Vec<String> = [["PersonName", "FirstName"], ["PersonName", "Surname"]]
+        .iter()
+        .filter_map(|path| identification.text(path))
+        .collect();
+    let mut profile = Profile {
+        id: None,
+        name: name.join(" "),
+        label: learner.text(&["Headline", "Description", "Label"]),
+        email: None,
+        phone: None,
+        url: None,
+        location: None,
+        summary: None,
+        links: vec![],
+        education: vec![],
+        languages: vec![],
+        certificates: vec![],
+    };
+
+    if let Some(contact) = identification.child("ContactInfo") {
+        let contact_source = format!("{}.ContactInfo", source);
+        contact.unmapped(CONTACT_FIELDS, &contact_source, report);
+        profile.location = contact.child("Address").and_then(location);
+        profile.email = contact.text(&["Email", "Contact"]);
+        let mut telephones = contact.find_all(&["TelephoneList", "Telephone"]).filter_map(|t| t.text(&["Contact"]));
+        profile.phone = telephones.next();
+        if telephones.next().is_some() {
+            report.warnings.push(format!("{}.TelephoneList: only the first telephone was imported", contact_source));
+        }
+        for website in contact.find_all(&["WebsiteList", "Website"]) {
+            let Some(url) = website.text(&["Contact"]) else { continue };
+            let code = website.text(&["Use", "Code"]);
+            match website.text(&["Use", "Label"]) {
+                None if profile.url.is_none() && code.as_deref().is_none_or(|c| c == PERSONAL_WEBSITE) => {
+                    profile.url = Some(url);
+                }
+                label => profile.links.push(ProfileLink {
+                    network: label.or(code).unwrap_or_else(|| "Website".to_string()),
+                    username: None,
+                    url,
+                }),
+            }
+        }
+    }
+    validated(source, profile, vec![], report)
+}
+
+fn map_work(source: &str, node: &Node, today: NaiveDate, report: &mut ImportReport) -> Option<Experience> {
+    node.unmapped(WORK_FIELDS, source, report);
+    if let Some(employer) = node.child("Employer") {
+        employer.unmapped(EMPLOYER_FIELDS, &format!("{}.Employer", source), report);
+    }
+
+    let period = node.child("Period");
+    let start_date = period.and_then(|p| p.child("From")).and_then(date).unwrap_or_default();
+    // An experience without an end is ongoing, whether or not Current is set
+    let end_date = period
+        .and_then(|p| p.child("To"))
+        .and_then(date)
+        .unwrap_or_else(|| today.format("%Y-%m-%d").to_string());
+
+    let experience = Experience {
+        id: None,
+        profile_id: None,
+        job_title: node.text(&["Position", "Label"]).unwrap_or_default(),
+        company: node.text(&["Employer", "Name"]).unwrap_or_default(),
+        location: node.find(&["Employer", "ContactInfo", "Address"]).and_then(location).unwrap_or_default(),
+        start_date,
+        end_date,
+        responsibilities: linked_responsibilities(node.text(&["Activities"]).map(|html| html_items(&html)).unwrap_or_default()),
+        environment: vec![],
+    };
+    validated(source, experience, vec![], report)
+}
+
+fn map_education(source: &str, node: &Node, report: &mut ImportReport) -> Option<Education> {
+    node.unmapped(EDUCATION_FIELDS, source, report);
+    let period = node.child("Period");
+    let education = Education {
+        institution: node.text(&["Organisation", "Name"]).unwrap_or_default(),
+        area: node.text(&["Field", "Label"]),
+        study_type: node.text(&["Title"]),
+        start_date: period.and_then(|p| p.child("From")).and_then(date),
+        end_date: period.and_then(|p| p.child("To")).and_then(date),
+        score: None,
+    };
+    validated(source, education, vec![], report)
+}
+
+fn map_languages(skills: &Node, report: &mut ImportReport) -> Vec<Language> {
+    let mut languages = Vec::new();
+    let native = ["Linguistic", "MotherTongueList", "MotherTongue"];
+    for (index, node) in skills.find_all(&native).enumerate() {
+        let source = format!("LearnerInfo.Skills.Linguistic.MotherTongueList.MotherTongue[{}]", index);
+        let language = Language { name: language_name(node), native: true, level: None };
+        languages.extend(validated(&source, language, vec![], report));
+    }
+
+    let foreign = ["Linguistic", "ForeignLanguageList", "ForeignLanguage"];
+    for (index, node) in skills.find_all(&foreign).enumerate() {
+        let source = format!("LearnerInfo.Skills.Linguistic.ForeignLanguageList.ForeignLanguage[{}]", index);
+        node.unmapped(FOREIGN_LANGUAGE_FIELDS, &source, report);
+        let mut errors = Vec::new();
+        let mut levels: Vec<String> = Vec::new();
+        for skill in PROFICIENCY_SKILLS {
+            match node.text(&["ProficiencyLevel", skill]) {
+                Some(level) if CEFR_REGEX.is_match(&level) => levels.push(level),
+                Some(level) => errors.push(format!("ProficiencyLevel.{}: '{}' is not a CEFR level", skill, level)),
+                None => {}
+            }
+        }
+        levels.sort();
+        levels.dedup();
+        if levels.len() > 1 {
+            report.warnings.push(format!(
+                "{}: levels {} differ across skills, using the lowest",
+                source,
+                levels.join(", ")
+            ));
+        }
+        let language = Language { name: language_name(node), native: false, level: levels.first().cloned() };
+        languages.extend(validated(&source, language, errors, report));
+    }
+    languages
+}
+
+// "Category: skill, skill"; a line without a category lists uncategorised
+// skills.
+fn map_skill_line(source: &str, line: &str, report: &mut ImportReport) -> Vec<(String, Skill)> {
+    let (category, names) = match line.split_once(':') {
+        Some((category, names)) => (Some(category.trim()).filter(|c| !c.is_empty() && *c != UNCATEGORIZED), names),
+        None => (None, line),
+    };
+    let categories: Vec<Category> = category.map(|name| Category { id: None, name: name.to_string() }).into_iter().collect();
+    names.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let source = format!("{}[{}]", source, index);
+            let skill = Skill {
+                id: None,
+                name: name.to_string(),
+                description: name.to_string(),
+                categories: categories.clone(),
+                next: None,
+            };
+            validated(&source, skill, vec![], report).map(|skill| (source, skill))
+        })
+        .collect()
+}
+
+fn language_name(node: &Node) -> String {
+    node.text(&["Description", "Label"])
+        .or_else(|| node.text(&["Description", "Code"]))
+        .unwrap_or_default()
+}
+
+// "Municipality, Country", whichever parts are present.
+fn location(address: &Node) -> Option<String> {
+    let parts: Vec<String> = [["Contact", "Municipality"].as_slice(), ["Contact", "Country", "Label"].as_slice()]
+        .into_iter()
+        .filter_map(|path| address.text(path))
+        .collect();
+    Some(parts.join(", ")).filter(|joined| !joined.is_empty())
+}
+
+// `year`, `month` (`--MM`) and `day` (`---DD`) attributes as `YYYY-MM-DD`;
+// missing parts default to the first month or day.
+fn date(node: &Node) -> Option<String> {
+    let year = node.attribute("year")?;
+    let mut raw = year.to_string();
+    if let Some(month) = node.attribute("month") {
+        raw.push_str(&format!("-{}", month.trim_start_matches('-')));
+        if let Some(day) = node.attribute("day") {
+            raw.push_str(&format!("-{}", day.trim_start_matches('-')));
+        }
+    }
+    // An unparseable date is kept as-is so the model validator reports it
+    Some(normalize_partial_date(&raw).unwrap_or(raw))
+}
+
+// Splits Europass rich text into its list items or paragraphs, as plain
+// text.
+fn html_items(html: &str) -> Vec<String> {
+    let text = BREAK_TAG.replace_all(html, "\n");
+    let text = TAG.replace_all(&text, "").replace("&nbsp;", " ");
+    let text = quick_xml::escape::unescape(&text).map(|t| t.into_owned()).unwrap_or(text);
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+// Just enough of a DOM to walk a Europass document: namespaces are
+// dropped and text is kept per element.
+#[derive(Debug, Default)]
+struct Node {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+    text: String,
+}
+
+impl Node {
+    fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn find(&self, path: &[&str]) -> Option<&Node> {
+        path.iter().try_fold(self, |node, name| node.child(name))
+    }
+
+    // Every node at `path`, following all branches.
+    fn find_all<'a>(&'a self, path: &'a [&'a str]) -> Box<dyn Iterator<Item = &'a Node> + 'a> {
+        match path.split_first() {
+            None => Box::new(std::iter::once(self)),
+            Some((name, rest)) => Box::new(
+                self.children.iter().filter(move |child| child.name == *name).flat_map(move |child| child.find_all(rest)),
+            ),
+        }
+    }
+
+    fn text(&self, path: &[&str]) -> Option<String> {
+        self.find(path).map(|node| node.text.trim().to_string()).filter(|text| !text.is_empty())
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.trim())
+    }
+
+    fn unmapped(&self, known: &[&str], source: &str, report: &mut ImportReport) {
+        let mut seen: Vec<&str> = Vec::new();
+        for child in self.children.iter().filter(|child| !known.contains(&child.name.as_str())) {
+            if !seen.contains(&child.name.as_str()) {
+                seen.push(&child.name);
+                let path = if source.is_empty() { child.name.clone() } else { format!("{}.{}", source, child.name) };
+                report.unmapped.push(path);
+            }
+        }
+    }
+}
+
+fn parse(xml: &str) -> Result<Node, String> {
+    let mut reader = Reader::from_str(xml);
+    let invalid = |position: u64, err: &dyn std::fmt::Display| format!("Invalid XML at byte {}: {}", position, err);
+    let mut stack = vec![Node::default()];
+    loop {
+        let event = reader.read_event().map_err(|err| invalid(reader.buffer_position(), &err))?;
+        match event {
+            Event::Start(start) => stack.push(element(&start).map_err(|err| invalid(reader.buffer_position(), &err))?),
+            Event::Empty(start) => {
+                let node = element(&start).map_err(|err| invalid(reader.buffer_position(), &err))?;
+                stack.last_mut().expect("document node").children.push(node);
+            }
+            Event::End(_) => {
+                let node = stack.pop().expect("open element");
+                stack.last_mut().ok_or_else(|| "Unbalanced closing tag".to_string())?.children.push(node);
+            }
+            Event::Text(text) => {
+                let text = text.unescape().map_err(|err| invalid(reader.buffer_position(), &err))?;
+                stack.last_mut().expect("document node").text.push_str(&text);
+            }
+            Event::CData(data) => {
+                stack.last_mut().expect("document node").text.push_str(&String::from_utf8_lossy(&data));
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if stack.len() != 1 {
+        return Err("Unexpected end of document".to_string());
+    }
+    stack.pop()
+        .and_then(|document| document.children.into_iter().next())
+        .ok_or_else(|| "Document has no root element".to_string())
+}
+
+fn element(start: &BytesStart) -> Result<Node, quick_xml::Error> {
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute = attribute?;
+        let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+        attributes.push((key, attribute.unescape_value()?.into_owned()));
+    }
+    Ok(Node {
+        name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+        attributes,
+        children: vec![],
+        text: String::new(),
+    })
+}
@@ -0,0 +1,159 @@
+use std::str::FromStr;
+use mongodb::bson::oid::ObjectId;
+use crate::errors::AppError;
+use crate::importers::{linked_responsibilities, validated, ImportReport};
+use crate::models::experience::Experience;
+use crate::renderers::experience_csv::{EXPERIENCE_CSV_COLUMNS, LIST_DELIMITER, LIST_ESCAPE};
+
+/// What `POST /api/experience/import.csv` does when some rows are invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvImportMode {
+    /// Store nothing unless every row is valid.
+    #[default]
+    AllOrNothing,
+    /// Store the valid rows and report the others.
+    BestEffort,
+}
+
+impl CsvImportMode {
+    pub const SUPPORTED: &'static [&'static str] = &["all_or_nothing", "best_effort"];
+}
+
+impl FromStr for CsvImportMode {
+    type Err = AppError;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode.to_ascii_lowercase().as_str() {
+            "all_or_nothing" => Ok(CsvImportMode::AllOrNothing),
+            "best_effort" => Ok(CsvImportMode::BestEffort),
+            other => Err(AppError::ValidationError(format!(
+                "Unsupported import mode '{}'. Supported modes: {}",
+                other,
+                CsvImportMode::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+/// A valid row of the experience spreadsheet. Skills are named only; they
+/// are resolved against the stored skills when the row is imported.
+#[derive(Debug, Clone)]
+pub struct ExperienceRow {
+    /// `line N` of the CSV file.
+    pub source: String,
+    pub experience: Experience,
+    pub skills: Vec<String>,
+}
+
+/// Reads an experience spreadsheet in the layout of
+/// [`EXPERIENCE_CSV_COLUMNS`]. Only `id`, `profile_id`, `responsibilities`
+/// and `skills` may be missing from the header. Each row is validated like
+/// an experience sent to the API; invalid rows are reported as failures
+/// with the validator messages and left out. Unknown columns are reported
+/// as unmapped.
+pub fn map_experience_csv(text: &str, report: &mut ImportReport) -> Vec<ExperienceRow> {
+    let mut reader = csv::ReaderBuilder::new().from_reader(text.trim_start_matches('\u{feff}').as_bytes());
+    let headers: Vec<String> = match reader.headers() {
+        Ok(headers) => headers.iter().map(|header| header.trim().to_ascii_lowercase()).collect(),
+        Err(e) => {
+            report.fail("line 1", vec![e.to_string()]);
+            return vec![];
+        }
+    };
+    let optional = ["id", "profile_id", "responsibilities", "skills"];
+    let missing: Vec<&str> = EXPERIENCE_CSV_COLUMNS.iter()
+        .filter(|column| !optional.contains(column) && !headers.iter().any(|header| header == *column))
+        .copied()
+        .collect();
+    if !missing.is_empty() {
+        report.fail("line 1", vec![format!("Missing columns: {}", missing.join(", "))]);
+        return vec![];
+    }
+    report.unmapped.extend(
+        headers.iter()
+            .filter(|header| !EXPERIENCE_CSV_COLUMNS.contains(&header.as_str()))
+            .map(|header| format!("column {}", header)),
+    );
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|position| position.line()).unwrap_or_default();
+                report.fail(format!("line {}", line), vec![e.to_string()]);
+                continue;
+            }
+        };
+        if record.iter().all(|value| value.trim().is_empty()) {
+            continue;
+        }
+        let source = format!("line {}", record.position().map(|position| position.line()).unwrap_or_default());
+        let cell = |column: &str| {
+            headers.iter()
+                .position(|header| header == column)
+                .and_then(|index| record.get(index))
+                .map(str::trim)
+                .unwrap_or_default()
+        };
+
+        let mut errors = Vec::new();
+        let mut object_id = |column: &str| match cell(column) {
+            "" => None,
+            hex => ObjectId::parse_str(hex)
+                .map_err(|_| errors.push(format!("{}: '{}' is not a valid ObjectId", column, hex)))
+                .ok(),
+        };
+        let id = object_id("id");
+        let profile_id = object_id("profile_id");
+
+        let experience = Experience {
+            id,
+            profile_id,
+            job_title: cell("job_title").to_string(),
+            company: cell("company").to_string(),
+            location: cell("location").to_string(),
+            start_date: cell("start_date").to_string(),
+            end_date: cell("end_date").to_string(),
+            responsibilities: linked_responsibilities(list(cell("responsibilities"))),
+            environment: vec![],
+        };
+        let skills = list(cell("skills"));
+        if let Some(experience) = validated(&source, experience, errors, report) {
+            rows.push(ExperienceRow { source, experience, skills });
+        }
+    }
+    rows
+}
+
+// Items of a list cell; line breaks separate items too, as spreadsheets
+// make them easy to type. `\|`, `\\` and `\n` stand for a literal pipe,
+// backslash and line break; any other backslash is kept as typed.
+fn list(cell: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut chars = cell.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            LIST_ESCAPE => match chars.peek() {
+                Some(&next @ (LIST_DELIMITER | LIST_ESCAPE)) => {
+                    item.push(next);
+                    chars.next();
+                }
+                Some('n') => {
+                    item.push('\n');
+                    chars.next();
+                }
+                _ => item.push(c),
+            },
+            LIST_DELIMITER | '\n' => items.push(std::mem::take(&mut item)),
+            _ => item.push(c),
+        }
+    }
+    items.push(item);
+    items.iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,356 @@
+use chrono::NaiveDate;
+use serde_json::{Map, Value};
+use validator::Validate;
+use crate::errors::validation_messages;
+use crate::importers::{linked_responsibilities, normalize_partial_date, ImportReport, MappedImport};
+use crate::models::category::Category;
+use crate::models::certificate::Certificate;
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::language::{Language, CEFR_REGEX};
+use crate::models::profile::{Profile, ProfileLink};
+use crate::models::skill::Skill;
+
+const BASICS_FIELDS: &[&str] = &["name", "label", "email", "phone", "url", "summary", "location", "profiles"];
+const LOCATION_FIELDS: &[&str] = &["city", "region", "countryCode"];
+const PROFILE_FIELDS: &[&str] = &["network", "username", "url"];
+const WORK_FIELDS: &[&str] = &["name", "position", "location", "startDate", "endDate", "highlights"];
+const SKILL_FIELDS: &[&str] = &["name", "keywords"];
+const EDUCATION_FIELDS: &[&str] = &["institution", "area", "studyType", "startDate", "endDate", "score"];
+const LANGUAGE_FIELDS: &[&str] = &["language", "fluency"];
+const CERTIFICATE_FIELDS: &[&str] = &["name", "issuer", "date", "url"];
+
+/// Maps a JSON Resume document onto our models. Nothing is rejected as a
+/// whole: invalid entries are reported as failures and skipped, and fields
+/// without a counterpart are listed as unmapped. A work entry without an
+/// `endDate` is an ongoing role and ends on `today`. Education, language and
+/// certificate entries are stored on the profile.
+pub fn map_json_resume(document: &Value, today: NaiveDate) -> MappedImport {
+    let mut import = MappedImport::default();
+    let mut education = Vec::new();
+    let mut languages = Vec::new();
+    let mut certificates = Vec::new();
+    let Some(root) = document.as_object() else {
+        import.report.fail("$", vec!["Document must be a JSON object".to_string()]);
+        return import;
+    };
+
+    for (key, value) in root {
+        match key.as_str() {
+            "$schema" | "meta" => {}
+            "basics" => import.profile = map_basics(value, &mut import.report),
+            "work" => {
+                for (index, item) in array(value, "work", &mut import.report).iter().enumerate() {
+                    let source = format!("work[{}]", index);
+                    if let Some(experience) = map_work(&source, item, today, &mut import.report) {
+                        import.experiences.push((source, experience));
+                    }
+                }
+            }
+            "skills" => {
+                for (index, item) in array(value, "skills", &mut import.report).iter().enumerate() {
+                    let source = format!("skills[{}]", index);
+                    import.skills.extend(map_skill_group(&source, item, &mut import.report));
+                }
+            }
+            "education" => {
+                for (index, item) in array(value, "education", &mut import.report).iter().enumerate() {
+                    let source = format!("education[{}]", index);
+                    education.extend(map_education(&source, item, &mut import.report));
+                }
+            }
+            "languages" => {
+                for (index, item) in array(value, "languages", &mut import.report).iter().enumerate() {
+                    let source = format!("languages[{}]", index);
+                    languages.extend(map_language(&source, item, &mut import.report));
+                }
+            }
+            "certificates" => {
+                for (index, item) in array(value, "certificates", &mut import.report).iter().enumerate() {
+                    let source = format!("certificates[{}]", index);
+                    certificates.extend(map_certificate(&source, item, &mut import.report));
+                }
+            }
+            other => import.report.unmapped.push(other.to_string()),
+        }
+    }
+
+    match &mut import.profile {
+        Some(profile) => {
+            profile.education = education;
+            profile.languages = languages;
+            profile.certificates = certificates;
+        }
+        None => {
+            if !education.is_empty() {
+                import.report.warnings.push("education: skipped because basics could not be imported".to_string());
+            }
+            if !languages.is_empty() {
+                import.report.warnings.push("languages: skipped because basics could not be imported".to_string());
+            }
+            if !certificates.is_empty() {
+                import.report.warnings.push("certificates: skipped because basics could not be imported".to_string());
+            }
+        }
+    }
+    import
+}
+
+fn map_basics(value: &Value, report: &mut ImportReport) -> Option<Profile> {
+    let mut entry = Entry::new("basics", value, report)?;
+    entry.unmapped(BASICS_FIELDS, report);
+
+    let location = entry.object.get("location").and_then(|location| {
+        let mut location = Entry::new("basics.location", location, report)?;
+        location.unmapped(LOCATION_FIELDS, report);
+        let parts: Vec<String> = LOCATION_FIELDS.iter().filter_map(|key| location.string(key)).collect();
+        entry.errors.append(&mut location.errors);
+        Some(parts.join(", ")).filter(|joined| !joined.is_empty())
+    });
+
+    let mut links = Vec::new();
+    if let Some(profiles) = entry.object.get("profiles") {
+        for (index, item) in array(profiles, "basics.profiles", report).iter().enumerate() {
+            let source = format!("basics.profiles[{}]", index);
+            let Some(mut link) = Entry::new(&source, item, report) else { continue };
+            link.unmapped(PROFILE_FIELDS, report);
+            let profile_link = ProfileLink {
+                network: link.string("network").unwrap_or_default(),
+                username: link.string("username"),
+                url: link.string("url").unwrap_or_default(),
+            };
+            if let Some(link) = link.finish(&profile_link, report) {
+                links.push(link);
+            }
+        }
+    }
+
+    let profile = Profile {
+        id: None,
+        name: entry.string("name").unwrap_or_default(),
+        label: entry.string("label"),
+        email: entry.string("email"),
+        phone: entry.string("phone"),
+        url: entry.string("url"),
+        location,
+        summary: entry.string("summary"),
+        links,
+        education: vec![],
+        languages: vec![],
+        certificates: vec![],
+    };
+    entry.finish(&profile, report)
+}
+
+fn map_work(source: &str, value: &Value, today: NaiveDate, report: &mut ImportReport) -> Option<Experience> {
+    let mut entry = Entry::new(source, value, report)?;
+    entry.unmapped(WORK_FIELDS, report);
+
+    let start_date = entry.date("startDate");
+    let end_date = match entry.object.get("endDate") {
+        Some(_) => entry.date("endDate"),
+        None => {
+            report.warnings.push(format!("{}: no endDate, treated as ongoing until {}", source, today));
+            today.format("%Y-%m-%d").to_string()
+        }
+    };
+
+    let highlights: Vec<String> = match entry.object.get("highlights") {
+        Some(highlights) => array(highlights, &format!("{}.highlights", source), report)
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| match item.as_str() {
+                Some(text) => Some(text.trim().to_string()),
+                None => {
+                    entry.errors.push(format!("highlights[{}]: expected a string", index));
+                    None
+                }
+            })
+            .collect(),
+        None => vec![],
+    };
+
+    let experience = Experience {
+        id: None,
+        profile_id: None,
+        job_title: entry.string("position").unwrap_or_default(),
+        company: entry.string("name").unwrap_or_default(),
+        location: entry.string("location").unwrap_or_default(),
+        start_date,
+        end_date,
+        responsibilities: linked_responsibilities(highlights),
+        environment: vec![],
+    };
+    entry.finish(&experience, report)
+}
+
+fn map_education(source: &str, value: &Value, report: &mut ImportReport) -> Option<Education> {
+    let mut entry = Entry::new(source, value, report)?;
+    entry.unmapped(EDUCATION_FIELDS, report);
+
+    let education = Education {
+        institution: entry.string("institution").unwrap_or_default(),
+        area: entry.string("area"),
+        study_type: entry.string("studyType"),
+        start_date: entry.optional_date("startDate"),
+        end_date: entry.optional_date("endDate"),
+        score: entry.string("score"),
+    };
+    entry.finish(&education, report)
+}
+
+fn map_certificate(source: &str, value: &Value, report: &mut ImportReport) -> Option<Certificate> {
+    let mut entry = Entry::new(source, value, report)?;
+    entry.unmapped(CERTIFICATE_FIELDS, report);
+
+    let certificate = Certificate {
+        name: entry.string("name").unwrap_or_default(),
+        issuer: entry.string("issuer"),
+        date: entry.optional_date("date"),
+        url: entry.string("url"),
+    };
+    entry.finish(&certificate, report)
+}
+
+// `fluency` is free text: a mother tongue is recognised by wording, a level
+// by a leading CEFR code ("B2", "C1 - advanced"). Anything else is dropped
+// with a warning rather than guessed.
+fn map_language(source: &str, value: &Value, report: &mut ImportReport) -> Option<Language> {
+    let mut entry = Entry::new(source, value, report)?;
+    entry.unmapped(LANGUAGE_FIELDS, report);
+
+    let mut language = Language {
+        name: entry.string("language").unwrap_or_default(),
+        native: false,
+        level: None,
+    };
+    if let Some(fluency) = entry.string("fluency") {
+        let lower = fluency.to_lowercase();
+        let code = fluency.get(..2).map(str::to_ascii_uppercase).filter(|code| CEFR_REGEX.is_match(code));
+        if lower.contains("native") || lower.contains("mother") || lower.contains("bilingual") {
+            language.native = true;
+        } else if let Some(code) = code.filter(|_| !fluency[2..].starts_with(char::is_alphanumeric)) {
+            language.level = Some(code);
+        } else {
+            report.warnings.push(format!("{}.fluency: '{}' is not a CEFR level and was dropped", source, fluency));
+        }
+    }
+    entry.finish(&language, report)
+}
+
+// A group with keywords is a category of skills; a group without is a
+// single skill.
+fn map_skill_group(source: &str, value: &Value, report: &mut ImportReport) -> Vec<(String, Skill)> {
+    let Some(mut entry) = Entry::new(source, value, report) else {
+        return vec![];
+    };
+    entry.unmapped(SKILL_FIELDS, report);
+    let name = entry.string("name");
+
+    let mut keywords: Vec<(String, String)> = Vec::new();
+    if let Some(value) = entry.object.get("keywords") {
+        for (index, keyword) in array(value, &format!("{}.keywords", source), report).iter().enumerate() {
+            match keyword.as_str() {
+                Some(keyword) => keywords.push((format!("{}.keywords[{}]", source, index), keyword.trim().to_string())),
+                None => entry.errors.push(format!("keywords[{}]: expected a string", index)),
+            }
+        }
+    }
+    if name.is_none() && keywords.is_empty() {
+        entry.errors.push("name: a skill needs a name or keywords".to_string());
+    }
+    if !entry.errors.is_empty() {
+        report.fail(source, entry.errors);
+        return vec![];
+    }
+
+    let (skills, categories) = match name {
+        Some(name) if keywords.is_empty() => (vec![(source.to_string(), name)], vec![]),
+        name => (keywords, name.map(|name| vec![Category { id: None, name }]).unwrap_or_default()),
+    };
+
+    skills.into_iter()
+        .filter_map(|(source, name)| {
+            let skill = Skill {
+                id: None,
+                description: name.clone(),
+                name,
+                categories: categories.clone(),
+                next: None,
+            };
+            Entry { source: source.clone(), object: entry.object, errors: vec![] }
+                .finish(&skill, report)
+                .map(|skill| (source, skill))
+        })
+        .collect()
+}
+
+fn array<'a>(value: &'a Value, source: &str, report: &mut ImportReport) -> &'a [Value] {
+    match value.as_array() {
+        Some(items) => items,
+        None => {
+            report.fail(source, vec!["expected an array".to_string()]);
+            &[]
+        }
+    }
+}
+
+// One object of the source document being mapped, collecting its errors.
+struct Entry<'a> {
+    source: String,
+    object: &'a Map<String, Value>,
+    errors: Vec<String>,
+}
+
+impl<'a> Entry<'a> {
+    fn new(source: &str, value: &'a Value, report: &mut ImportReport) -> Option<Entry<'a>> {
+        match value.as_object() {
+            Some(object) => Some(Entry { source: source.to_string(), object, errors: vec![] }),
+            None => {
+                report.fail(source, vec!["expected an object".to_string()]);
+                None
+            }
+        }
+    }
+
+    fn string(&mut self, key: &str) -> Option<String> {
+        match self.object.get(key) {
+            None | Some(Value::Null) => None,
+            Some(Value::String(text)) => Some(text.trim().to_string()).filter(|t| !t.is_empty()),
+            Some(_) => {
+                self.errors.push(format!("{}: expected a string", key));
+                None
+            }
+        }
+    }
+
+    fn date(&mut self, key: &str) -> String {
+        let raw = self.string(key).unwrap_or_default();
+        // An unparseable date is kept as-is so the model validator reports it
+        normalize_partial_date(&raw).unwrap_or(raw)
+    }
+
+    fn optional_date(&mut self, key: &str) -> Option<String> {
+        self.string(key).map(|raw| normalize_partial_date(&raw).unwrap_or(raw))
+    }
+
+    fn unmapped(&self, known: &[&str], report: &mut ImportReport) {
+        for key in self.object.keys().filter(|key| !known.contains(&key.as_str())) {
+            report.unmapped.push(format!("{}.{}", self.source, key));
+        }
+    }
+
+    // Validates the mapped record; on any error the entry is reported as a
+    // failure and dropped.
+    fn finish<T: Validate + Clone>(&mut self, record: &T, report: &mut ImportReport) -> Option<T> {
+        if let Err(errors) = record.validate() {
+            self.errors.extend(validation_messages(&errors));
+        }
+        if self.errors.is_empty() {
+            Some(record.clone())
+        } else {
+            report.fail(self.source.clone(), std::mem::take(&mut self.errors));
+            None
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use chrono::NaiveDate;
+use zip::ZipArchive;
+use crate::importers::{linked_responsibilities, normalize_partial_date, validated, ImportReport, MappedImport};
+use crate::models::certificate::Certificate;
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::skill::Skill;
+
+pub const PROFILE_FILE: &str = "Profile.csv";
+pub const POSITIONS_FILE: &str = "Positions.csv";
+pub const SKILLS_FILE: &str = "Skills.csv";
+pub const EDUCATION_FILE: &str = "Education.csv";
+pub const CERTIFICATIONS_FILE: &str = "Certifications.csv";
+/// Largest uncompressed export file read, in bytes.
+pub const LINKEDIN_FILE_LIMIT: usize = 8 * 1024 * 1024;
+/// Largest uncompressed total of the export files read, in bytes.
+pub const LINKEDIN_TOTAL_LIMIT: usize = 16 * 1024 * 1024;
+const FILES: &[&str] = &[PROFILE_FILE, POSITIONS_FILE, SKILLS_FILE, EDUCATION_FILE, CERTIFICATIONS_FILE];
+
+const PROFILE_COLUMNS: &[&str] = &["First Name", "Last Name", "Headline", "Summary", "Geo Location"];
+const POSITION_COLUMNS: &[&str] = &["Company Name", "Title", "Description", "Location", "Started On", "Finished On"];
+const SKILL_COLUMNS: &[&str] = &["Name"];
+const EDUCATION_COLUMNS: &[&str] = &["School Name", "Degree Name", "Start Date", "End Date"];
+const CERTIFICATION_COLUMNS: &[&str] = &["Name", "Authority", "Url", "Started On"];
+
+/// Records mapped from a LinkedIn data export. Education and certificates
+/// belong on a profile, which the export may not contain, so they are kept
+/// apart from the profile until it is known which one they are stored on.
+#[derive(Debug, Default)]
+pub struct LinkedInImport {
+    pub mapped: MappedImport,
+    pub education: Vec<(String, Education)>,
+    pub certificates: Vec<(String, Certificate)>,
+}
+
+/// Maps a LinkedIn data export (the ZIP of CSV files LinkedIn lets members
+/// download) onto our models. `Positions.csv` gives experiences, each line
+/// of a description becoming a responsibility; `Skills.csv` skills;
+/// `Education.csv` and `Certifications.csv` education and certificates; and
+/// `Profile.csv`, when present, the profile. Every row is mapped on its own
+/// and reported by file and line, e.g. `Positions.csv:3`; other files of the
+/// export and columns without a counterpart are listed as unmapped. A
+/// position without `Finished On` is ongoing and ends on `today`.
+pub fn map_linkedin(archive: &[u8], today: NaiveDate) -> LinkedInImport {
+    let mut import = LinkedInImport::default();
+    let report = &mut import.mapped.report;
+    let files = match read_archive(archive, report) {
+        Ok(files) => files,
+        Err(message) => {
+            report.fail("$", vec![message]);
+            return import;
+        }
+    };
+    if !FILES.iter().any(|file| files.contains_key(&file.to_lowercase())) {
+        report.fail("$", vec![format!("The archive contains none of {}", FILES.join(", "))]);
+        return import;
+    }
+    let rows = |file: &str, columns: &[&str], report: &mut ImportReport| match files.get(&file.to_lowercase()) {
+        Some(contents) => read_rows(file, contents, columns, report),
+        None => vec![],
+    };
+
+    let profiles = rows(PROFILE_FILE, PROFILE_COLUMNS, report);
+    if let Some(row) = profiles.first() {
+        import.mapped.profile = map_profile(row, report);
+    }
+    if profiles.len() > 1 {
+        report.warnings.push(format!("{}: only the first row was imported", PROFILE_FILE));
+    }
+
+    for row in rows(POSITIONS_FILE, POSITION_COLUMNS, report) {
+        if let Some(experience) = map_position(&row, today, report) {
+            import.mapped.experiences.push((row.source, experience));
+        }
+    }
+
+    for row in rows(SKILLS_FILE, SKILL_COLUMNS, report) {
+        let name = row.get("Name").unwrap_or_default();
+        let skill = Skill { id: None, description: name.clone(), name, categories: vec![], next: None };
+        if let Some(skill) = validated(&row.source, skill, vec![], report) {
+            import.mapped.skills.push((row.source, skill));
+        }
+    }
+
+    for row in rows(EDUCATION_FILE, EDUCATION_COLUMNS, report) {
+        let education = Education {
+            institution: row.get("School Name").unwrap_or_default(),
+            area: None,
+            study_type: row.get("Degree Name"),
+            start_date: row.get("Start Date").map(|date| linkedin_date(&date)),
+            end_date: row.get("End Date").map(|date| linkedin_date(&date)),
+            score: None,
+        };
+        if let Some(education) = validated(&row.source, education, vec![], report) {
+            import.education.push((row.source, education));
+        }
+    }
+
+    for row in rows(CERTIFICATIONS_FILE, CERTIFICATION_COLUMNS, report) {
+        let certificate = Certificate {
+            name: row.get("Name").unwrap_or_default(),
+            issuer: row.get("Authority"),
+            date: row.get("Started On").map(|date| linkedin_date(&date)),
+            url: row.get("Url"),
+        };
+        if let Some(certificate) = validated(&row.source, certificate, vec![], report) {
+            import.certificates.push((row.source, certificate));
+        }
+    }
+    import
+}
+
+/// Whether a stored experience is the imported position: the same profile,
+/// company, title and start date, ignoring the case of company and title.
+pub fn same_position(stored: &Experience, imported: &Experience) -> bool {
+    stored.profile_id == imported.profile_id
+        && stored.company.eq_ignore_ascii_case(&imported.company)
+        && stored.job_title.eq_ignore_ascii_case(&imported.job_title)
+        && stored.start_date == imported.start_date
+}
+
+/// Converts the dates found in LinkedIn exports (`2021`, `Mar 2021`,
+/// `Mar 15, 2021`, or ISO dates) to `YYYY-MM-DD`. Anything else is returned
+/// unchanged so that validation reports it against the field it was meant
+/// for.
+pub fn linkedin_date(value: &str) -> String {
+    let value = value.trim();
+    normalize_partial_date(value)
+        .or_else(|| NaiveDate::parse_from_str(&format!("1 {}", value), "%d %b %Y").ok().map(|d| d.format("%Y-%m-%d").to_string()))
+        .or_else(|| NaiveDate::parse_from_str(value, "%b %d, %Y").ok().map(|d| d.format("%Y-%m-%d").to_string()))
+        .unwrap_or_else(|| value.to_string())
+}
+
+fn map_profile(row: &Row, report: &mut ImportReport) -> Option<Profile> {
+    let name: Vec<String> = ["First Name", "Last Name"].iter().filter_map(|column| row.get(column)).collect();
+    let profile = Profile {
+        id: None,
+        name: name.join(" "),
+        label: row.get("Headline"),
+        email: None,
+        phone: None,
+        url: None,
+        location: row.get("Geo Location"),
+        summary: row.get("Summary"),
+        links: vec![],
+        education: vec![],
+        languages: vec![],
+        certificates: vec![],
+    };
+    validated(&row.source, profile, vec![], report)
+}
+
+fn map_position(row: &Row, today: NaiveDate, report: &mut ImportReport) -> Option<Experience> {
+    let end_date = match row.get("Finished On") {
+        Some(date) => linkedin_date(&date),
+        None => {
+            report.warnings.push(format!("{}: no Finished On, treated as ongoing until {}", row.source, today));
+            today.format("%Y-%m-%d").to_string()
+        }
+    };
+    let experience = Experience {
+        id: None,
+        profile_id: None,
+        job_title: row.get("Title").unwrap_or_default(),
+        company: row.get("Company Name").unwrap_or_default(),
+        location: row.get("Location").unwrap_or_default(),
+        start_date: row.get("Started On").map(|date| linkedin_date(&date)).unwrap_or_default(),
+        end_date,
+        responsibilities: linked_responsibilities(description_lines(&row.get("Description").unwrap_or_default())),
+        environment: vec![],
+    };
+    validated(&row.source, experience, vec![], report)
+}
+
+// Descriptions are free text; each non-empty line is one responsibility,
+// without the bullet it was typed with.
+fn description_lines(description: &str) -> Vec<String> {
+    description.lines()
+        .map(|line| line.trim().trim_start_matches(['•', '-', '*', '–', '·', '▪']).trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// CSV files of the archive by lower-cased file name, wherever they sit in
+// it. Other files are reported as unmapped. Files are read no further than
+// LINKEDIN_FILE_LIMIT, whatever size the archive claims for them, so that a
+// small archive cannot expand to gigabytes.
+fn read_archive(archive: &[u8], report: &mut ImportReport) -> Result<HashMap<String, String>, String> {
+    let mut zip = ZipArchive::new(Cursor::new(archive)).map_err(|e| format!("Not a ZIP archive: {}", e))?;
+    let mut files = HashMap::new();
+    let mut total = 0;
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index).map_err(|e| format!("Unreadable archive entry: {}", e))?;
+        if file.is_dir() {
+            continue;
+        }
+        let path = file.name().to_string();
+        let name = path.rsplit('/').next().unwrap_or_default();
+        if !FILES.iter().any(|known| known.eq_ignore_ascii_case(name)) {
+            report.unmapped.push(path);
+            continue;
+        }
+        let too_large = format!("{} is larger than the limit of {} bytes uncompressed", name, LINKEDIN_FILE_LIMIT);
+        if file.size() > LINKEDIN_FILE_LIMIT as u64 {
+            report.fail(name, vec![too_large]);
+            continue;
+        }
+        let mut bytes = Vec::new();
+        if let Err(e) = (&mut file).take(LINKEDIN_FILE_LIMIT as u64 + 1).read_to_end(&mut bytes) {
+            report.fail(name, vec![format!("Unreadable archive entry: {}", e)]);
+            continue;
+        }
+        if bytes.len() > LINKEDIN_FILE_LIMIT {
+            report.fail(name, vec![too_large]);
+            continue;
+        }
+        total += bytes.len();
+        if total > LINKEDIN_TOTAL_LIMIT {
+            return Err(format!("The export files are larger than the limit of {} bytes uncompressed", LINKEDIN_TOTAL_LIMIT));
+        }
+        let contents = match String::from_utf8(bytes) {
+            Ok(contents) => contents,
+            Err(e) => {
+                report.fail(name, vec![format!("Not a UTF-8 text file: {}", e)]);
+                continue;
+            }
+        };
+        files.insert(name.to_lowercase(), contents.trim_start_matches('\u{feff}').to_string());
+    }
+    Ok(files)
+}
+
+// One row of a CSV file, by column name.
+struct Row {
+    source: String,
+    values: HashMap<String, String>,
+}
+
+impl Row {
+    fn get(&self, column: &str) -> Option<String> {
+        self.values.get(column).map(|value| value.trim()).filter(|value| !value.is_empty()).map(str::to_string)
+    }
+}
+
+// Reads the rows of a CSV file, skipping blank ones. Columns outside
+// `columns` are reported as unmapped when any row has a value for them.
+fn read_rows(file: &str, contents: &str, columns: &[&str], report: &mut ImportReport) -> Vec<Row> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(contents.as_bytes());
+    let headers: Vec<String> = match reader.headers() {
+        Ok(headers) => headers.iter().map(|header| header.trim().to_string()).collect(),
+        Err(e) => {
+            report.fail(file, vec![e.to_string()]);
+            return vec![];
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut unmapped: Vec<&str> = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|position| position.line()).unwrap_or_default();
+                report.fail(format!("{}:{}", file, line), vec![e.to_string()]);
+                continue;
+            }
+        };
+        if record.iter().all(|value| value.trim().is_empty()) {
+            continue;
+        }
+        let line = record.position().map(|position| position.line()).unwrap_or_default();
+        let mut values = HashMap::new();
+        for (header, value) in headers.iter().zip(record.iter()) {
+            if !columns.contains(&header.as_str()) && !value.trim().is_empty() && !unmapped.contains(&header.as_str()) {
+                unmapped.push(header);
+            }
+            values.insert(header.clone(), value.to_string());
+        }
+        rows.push(Row { source: format!("{}:{}", file, line), values });
+    }
+    report.unmapped.extend(unmapped.iter().map(|column| format!("{}.{}", file, column)));
+    rows
+}
@@ -0,0 +1,120 @@
+pub mod europass;
+pub mod experience_csv;
+pub mod json_resume;
+pub mod linkedin;
+
+use chrono::NaiveDate;
+use mongodb::bson::oid::ObjectId;
+use serde::Serialize;
+use validator::Validate;
+use crate::errors::validation_messages;
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::responsibility::Responsibility;
+use crate::models::skill::Skill;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Profile,
+    Experience,
+    Skill,
+    Category,
+    Education,
+    Certificate,
+}
+
+/// Entity created by an import, or that would be created on a dry run.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ImportedEntity {
+    pub entity: EntityKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]  // Where the entity came from in the source document
+    pub source: Option<String>,
+}
+
+/// Entry of the source document that could not be imported.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct EntryFailure {
+    pub source: String,
+    pub messages: Vec<String>,
+}
+
+/// Outcome of an import. Entries are imported independently, so a failure
+/// only excludes the entry it belongs to.
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub created: Vec<ImportedEntity>,
+    /// Entities already present, matched by name, that were reused.
+    /// Entries embedded in a profile, such as education, have no id.
+    pub existing: Vec<ImportedEntity>,
+    /// Stored entities the import changed, matched by id or natural key.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub updated: Vec<ImportedEntity>,
+    /// Source fields that have no counterpart in our models.
+    pub unmapped: Vec<String>,
+    pub warnings: Vec<String>,
+    pub failures: Vec<EntryFailure>,
+}
+
+impl ImportReport {
+    pub fn fail(&mut self, source: impl Into<String>, messages: Vec<String>) {
+        self.failures.push(EntryFailure { source: source.into(), messages });
+    }
+}
+
+/// Records mapped from a source document, ready to be stored.
+#[derive(Debug, Default)]
+pub struct MappedImport {
+    pub profile: Option<Profile>,
+    /// Experiences paired with their location in the source, e.g. `work[2]`.
+    pub experiences: Vec<(String, Experience)>,
+    /// Skills paired with their source; categories are referenced by name only.
+    pub skills: Vec<(String, Skill)>,
+    pub report: ImportReport,
+}
+
+/// Expands a partial ISO 8601 date (`YYYY`, `YYYY-MM` or `YYYY-MM-DD`)
+/// to the `YYYY-MM-DD` form used by our models.
+pub fn normalize_partial_date(value: &str) -> Option<String> {
+    let value = value.trim();
+    let full = match value.len() {
+        4 => format!("{}-01-01", value),
+        7 => format!("{}-01", value),
+        _ => value.to_string(),
+    };
+    NaiveDate::parse_from_str(&full, "%Y-%m-%d")
+        .ok()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Turns imported bullet points into responsibilities linked in order.
+pub(crate) fn linked_responsibilities(highlights: Vec<String>) -> Vec<Responsibility> {
+    let ids: Vec<ObjectId> = highlights.iter().map(|_| ObjectId::new()).collect();
+    highlights.into_iter()
+        .enumerate()
+        .map(|(i, name)| Responsibility {
+            id: Some(ids[i]),
+            name,
+            next: ids.get(i + 1).copied(),
+            star: None,
+        })
+        .collect()
+}
+
+/// Validates a mapped record together with the errors found while mapping
+/// it; on any error the entry is reported as a failure and dropped.
+pub(crate) fn validated<T: Validate>(source: &str, record: T, mut errors: Vec<String>, report: &mut ImportReport) -> Option<T> {
+    if let Err(validation) = record.validate() {
+        errors.extend(validation_messages(&validation));
+    }
+    if errors.is_empty() {
+        Some(record)
+    } else {
+        report.fail(source, errors);
+        None
+    }
+}
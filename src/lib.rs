@@ -5,3 +5,5 @@ pub mod handlers;
 pub mod models;
 pub mod repositories;
 pub mod services;
+pub mod renderers;
+pub mod importers;
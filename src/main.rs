@@ -5,14 +5,30 @@ use mongodb::{Client, options::ClientOptions};
 
 use rust_server::api::configure_routes;
 use rust_server::repositories::experience_repository::ExperienceRepository;
+use rust_server::repositories::profile_repository::ProfileRepository;
+use rust_server::repositories::resume_repository::ResumeRepository;
+use rust_server::repositories::skill_repository::SkillRepository;
+use rust_server::repositories::category_repository::CategoryRepository;
+use rust_server::repositories::snapshot_repository::SnapshotRepository;
+use rust_server::repositories::template_repository::TemplateRepository;
 use rust_server::services::experience_service::ExperienceService;
+use rust_server::services::profile_service::ProfileService;
+use rust_server::services::resume_service::ResumeService;
+use rust_server::services::skill_service::SkillService;
+use rust_server::services::category_service::CategoryService;
+use rust_server::services::snapshot_service::SnapshotService;
+use rust_server::services::import_service::ImportService;
+use rust_server::services::sync_service::SyncService;
+use rust_server::services::template_service::TemplateService;
+use rust_server::services::tailor_service::TailorService;
+use rust_server::services::lint::Linter;
 use rust_server::models::Experience;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     let env = load_env().map_err(|e| {
         eprintln!("Failed to load environment: {}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, e)
+        std::io::Error::other(e)
     })?;
 
     // Initialize MongoDB client
@@ -21,17 +37,57 @@ async fn main() -> std::io::Result<()> {
     let app_port = env.app_port;
     let client_options = ClientOptions::parse(&mongo_uri).await.map_err(|e| {
         eprintln!("Failed to parse MongoDB URI: {}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+        std::io::Error::other(e.to_string())
     })?;
     
     let client = Client::with_options(client_options).map_err(|e| {
         eprintln!("Failed to create MongoDB client: {}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+        std::io::Error::other(e.to_string())
     })?;
 
     // Create repository and service instances
     let experience_repo: ExperienceRepository<'_, Experience> = ExperienceRepository::new(&client, &mongo_db, "experience");
     let experience_service = ExperienceService::new(experience_repo);
+    let profile_service = ProfileService::new(ProfileRepository::new(&client, &mongo_db, "profile"));
+    let skill_service = SkillService::new(SkillRepository::new(&client, &mongo_db, "skill"));
+    let category_service = CategoryService::new(CategoryRepository::new(&client, &mongo_db, "category"));
+    let resume_service = ResumeService::new(
+        ResumeRepository::new(&client, &mongo_db, "resume"),
+        profile_service.clone(),
+        experience_service.clone(),
+        skill_service.clone(),
+    );
+    let snapshot_service = SnapshotService::new(
+        SnapshotRepository::new(&client, &mongo_db, "resume_snapshot"),
+        resume_service.clone(),
+    );
+    let import_service = ImportService::new(
+        profile_service.clone(),
+        experience_service.clone(),
+        skill_service.clone(),
+        category_service.clone(),
+    );
+    let sync_service = SyncService::new(
+        experience_service.clone(),
+        profile_service.clone(),
+        skill_service.clone(),
+        category_service.clone(),
+        resume_service.clone(),
+    );
+    let template_service = TemplateService::new(
+        TemplateRepository::new(&client, &mongo_db, "templates"),
+        resume_service.clone(),
+    );
+    let tailor_service = TailorService::new(
+        experience_service.clone(),
+        skill_service.clone(),
+        resume_service.clone(),
+    );
+
+    let linter = Linter::new(&env.lint_disabled_rules).map_err(|e| {
+        eprintln!("Invalid LINT_DISABLED_RULES: {}", e);
+        std::io::Error::other(e.to_string())
+    })?;
 
     println!("Server: [{}]", env.app_name);
     println!("Environment: [{}]", env.env);
@@ -46,6 +102,16 @@ async fn main() -> std::io::Result<()> {
         App::new()
             .wrap(Logger::default())
             .app_data(Data::new(experience_service.clone()))
+            .app_data(Data::new(profile_service.clone()))
+            .app_data(Data::new(skill_service.clone()))
+            .app_data(Data::new(category_service.clone()))
+            .app_data(Data::new(resume_service.clone()))
+            .app_data(Data::new(snapshot_service.clone()))
+            .app_data(Data::new(import_service.clone()))
+            .app_data(Data::new(sync_service.clone()))
+            .app_data(Data::new(template_service.clone()))
+            .app_data(Data::new(tailor_service.clone()))
+            .app_data(Data::new(linter.clone()))
             .configure(configure_routes)
     })
         .bind(format!("0.0.0.0:{}", app_port))?
@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// One problem found in a resume, with what to do about it.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Finding {
+    /// Stable identifier of the rule that produced the finding, such as
+    /// `bullet-too-long`.
+    pub rule: String,
+    pub severity: Severity,
+    pub message: String,
+    /// Path of the offending field in the resolved resume, e.g.
+    /// `experiences[0].responsibilities[2]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// How many of a job description's keywords the resume mentions.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct KeywordCoverage {
+    /// Share of the keywords found, from 0 to 1.
+    pub coverage: f64,
+    pub matched: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct AtsReport {
+    /// Overall score from 0 to 100.
+    pub score: u8,
+    /// Present when a job description was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<KeywordCoverage>,
+    /// Findings, most severe first.
+    pub findings: Vec<Finding>,
+}
@@ -6,5 +6,6 @@ use validator::Validate;
 pub struct Category {
     #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
     pub id: Option<ObjectId>,
+    #[validate(length(min = 1, message = "Category name is required"))]
     pub name: String,
 }
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use super::experience::DATE_REGEX;
+
+/// A certification or licence listed on the profile.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, Default, PartialEq)]
+pub struct Certificate {
+    #[validate(length(min = 1, message = "Certificate name is required"))]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Awarding body, e.g. "CNCF"
+    pub issuer: Option<String>,
+    #[validate(regex(path = "DATE_REGEX", message = "Date must be in YYYY-MM-DD format"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[validate(url(message = "Certificate url must be a valid URL"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use super::experience::DATE_REGEX;
+
+/// A degree, diploma or course listed on the profile.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, Default, PartialEq)]
+pub struct Education {
+    #[validate(length(min = 1, message = "Institution is required"))]
+    pub institution: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Field of study, e.g. "Computer Science"
+    pub area: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Degree or qualification, e.g. "BSc"
+    pub study_type: Option<String>,
+    #[validate(regex(path = "DATE_REGEX", message = "Start date must be in YYYY-MM-DD format"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[validate(regex(path = "DATE_REGEX", message = "End date must be in YYYY-MM-DD format"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Grade or GPA, as written
+    pub score: Option<String>,
+}
@@ -3,17 +3,20 @@ use lazy_static::lazy_static;
 use regex::Regex;
 
 lazy_static! {
-    static ref DATE_REGEX: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+    pub(crate) static ref DATE_REGEX: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
 }
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use super::skill::Skill;
 use super::responsibility::Responsibility;
+use super::ordering::order_linked;
 
 #[derive(Debug, Serialize, Deserialize, Validate, Clone)]
 pub struct Experience {
     #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
     pub id: Option<ObjectId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<ObjectId>,
     #[validate(length(min = 1, message = "Job title is required"))]
     pub job_title: String,
     
@@ -39,3 +42,19 @@ pub struct Experience {
     #[serde(default)]  // Make environment optional with default empty Vec
     pub environment: Vec<Skill>
 }
+
+impl Experience {
+    /// Responsibilities in the order given by their `next` links.
+    pub fn ordered_responsibilities(&self) -> Vec<Responsibility> {
+        order_linked(&self.responsibilities)
+    }
+
+    /// Gives every responsibility sent without an id a new one, since
+    /// resumes select and override responsibilities by id. Responsibilities
+    /// without an id cannot be linked to, so their stored order is kept.
+    pub fn assign_responsibility_ids(&mut self) {
+        for responsibility in &mut self.responsibilities {
+            responsibility.id.get_or_insert_with(ObjectId::new);
+        }
+    }
+}
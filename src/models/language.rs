@@ -0,0 +1,21 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+lazy_static! {
+    pub(crate) static ref CEFR_REGEX: Regex = Regex::new(r"^[ABC][12]$").unwrap();
+}
+
+/// A spoken language with its level on the Common European Framework of
+/// Reference (A1 to C2).
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, Default, PartialEq)]
+pub struct Language {
+    #[validate(length(min = 1, message = "Language name is required"))]
+    pub name: String,
+    #[serde(default)]  // Mother tongue; a native language has no level
+    pub native: bool,
+    #[validate(regex(path = "CEFR_REGEX", message = "Level must be a CEFR level from A1 to C2"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+}
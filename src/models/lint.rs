@@ -0,0 +1,39 @@
+use mongodb::bson::oid::ObjectId;
+use serde::Serialize;
+use super::ats::Severity;
+
+/// Characters `start..end` of the linted text, counted in Unicode scalar
+/// values.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct LintFinding {
+    /// Stable identifier of the rule, such as `weak-opening`.
+    pub rule: String,
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+}
+
+/// Findings for one responsibility, linted as its resume bullet.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ResponsibilityLint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responsibility_id: Option<ObjectId>,
+    /// Path of the responsibility in the experience, e.g. `responsibilities[1]`.
+    pub location: String,
+    /// The bullet the spans refer to.
+    pub text: String,
+    pub findings: Vec<LintFinding>,
+}
+
+/// Returned by experience create and update when linting is requested.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct LintReport {
+    /// Responsibilities with at least one finding.
+    pub responsibilities: Vec<ResponsibilityLint>,
+}
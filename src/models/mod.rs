@@ -1,6 +1,22 @@
 pub mod category;
+pub mod certificate;
 pub mod skill;
 pub mod experience;
 pub mod responsibility;
+pub mod story;
+pub mod ordering;
+pub mod profile;
+pub mod education;
+pub mod language;
+pub mod resume;
+pub mod resume_file;
+pub mod snapshot;
+pub mod tailor;
+pub mod ats;
+pub mod lint;
+pub mod timeline;
+pub mod template;
 
 pub use experience::Experience;
+pub use profile::Profile;
+pub use resume::Resume;
@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet};
+use mongodb::bson::oid::ObjectId;
+use super::responsibility::Responsibility;
+use super::skill::Skill;
+
+/// Items chained together through a `next` pointer.
+pub trait Linked {
+    fn id(&self) -> Option<ObjectId>;
+    fn next(&self) -> Option<ObjectId>;
+    fn set_next(&mut self, next: Option<ObjectId>);
+}
+
+impl Linked for Responsibility {
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn next(&self) -> Option<ObjectId> {
+        self.next
+    }
+
+    fn set_next(&mut self, next: Option<ObjectId>) {
+        self.next = next;
+    }
+}
+
+impl Linked for Skill {
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn next(&self) -> Option<ObjectId> {
+        self.next
+    }
+
+    fn set_next(&mut self, next: Option<ObjectId>) {
+        self.next = next;
+    }
+}
+
+/// Orders items by following their `next` pointers, starting from every item
+/// no other item points to. Items that are unreachable (broken or cyclic
+/// chains) keep their stored position at the end of the list.
+pub fn order_linked<T: Linked + Clone>(items: &[T]) -> Vec<T> {
+    let index: HashMap<ObjectId, usize> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| item.id().map(|id| (id, i)))
+        .collect();
+    let referenced: HashSet<ObjectId> = items.iter().filter_map(Linked::next).collect();
+
+    let mut visited = vec![false; items.len()];
+    let mut ordered = Vec::with_capacity(items.len());
+    for (start, item) in items.iter().enumerate() {
+        if item.id().is_some_and(|id| referenced.contains(&id)) {
+            continue;
+        }
+        let mut current = Some(start);
+        while let Some(i) = current {
+            if visited[i] {
+                break;
+            }
+            visited[i] = true;
+            ordered.push(items[i].clone());
+            current = items[i].next().and_then(|next| index.get(&next).copied());
+        }
+    }
+
+    for (i, item) in items.iter().enumerate() {
+        if !visited[i] {
+            ordered.push(item.clone());
+        }
+    }
+    ordered
+}
+
+/// Rewrites the `next` pointers so that they follow the order of `items`.
+pub fn relink<T: Linked>(items: &mut [T]) {
+    let ids: Vec<Option<ObjectId>> = items.iter().map(Linked::id).collect();
+    for (i, item) in items.iter_mut().enumerate() {
+        item.set_next(ids.get(i + 1).copied().flatten());
+    }
+}
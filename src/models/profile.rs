@@ -0,0 +1,68 @@
+use mongodb::bson::oid::ObjectId;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use super::certificate::Certificate;
+use super::education::Education;
+use super::language::Language;
+
+lazy_static! {
+    // Schemes that are safe to link to from rendered resumes; `javascript:`
+    // and `data:` URLs pass the `url` check too.
+    static ref LINK_URL_REGEX: Regex = Regex::new(r"(?i)^(https?://|mailto:)").unwrap();
+    // The same, for validation: text without a scheme is left to the `url`
+    // check so that it is reported once.
+    pub(crate) static ref LINK_SCHEME_REGEX: Regex = Regex::new(r"(?i)^(https?://|mailto:|[^:]*$)").unwrap();
+}
+
+/// Whether `url` may be rendered as a link: `http`, `https` or `mailto`.
+pub fn is_link_url(url: &str) -> bool {
+    LINK_URL_REGEX.is_match(url.trim_start())
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, Default)]
+pub struct Profile {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    #[validate(length(min = 1, message = "Profile name is required"))]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Headline shown under the name, e.g. "Backend Engineer"
+    pub label: Option<String>,
+    #[validate(email(message = "Email must be a valid address"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[validate(url(message = "Website must be a valid URL"))]
+    #[validate(regex(path = "LINK_SCHEME_REGEX", message = "Website must be an http, https or mailto URL"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[validate]
+    #[serde(default)]
+    pub links: Vec<ProfileLink>,
+    #[validate]
+    #[serde(default)]
+    pub education: Vec<Education>,
+    #[validate]
+    #[serde(default)]
+    pub languages: Vec<Language>,
+    #[validate]
+    #[serde(default)]
+    pub certificates: Vec<Certificate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, Clone)]
+pub struct ProfileLink {
+    #[validate(length(min = 1, message = "Network name is required"))]
+    pub network: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[validate(url(message = "Link must be a valid URL"))]
+    #[validate(regex(path = "LINK_SCHEME_REGEX", message = "Link must be an http, https or mailto URL"))]
+    pub url: String,
+}
@@ -8,5 +8,76 @@ pub struct Responsibility {
     pub id: Option<ObjectId>,
     #[validate(length(min = 1, message = "Responsibility name is required"))]
     pub name: String,
-    pub next: Option<ObjectId>
+    pub next: Option<ObjectId>,
+    #[validate]
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Only STAR-structured responsibilities carry a story
+    pub star: Option<Star>,
+}
+
+/// Situation/Task/Action/Result breakdown of a responsibility, used for
+/// detailed CVs and interview preparation.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, Default, PartialEq)]
+pub struct Star {
+    #[validate(length(min = 1, message = "Situation must not be empty"))]
+    pub situation: Option<String>,
+    #[validate(length(min = 1, message = "Task must not be empty"))]
+    pub task: Option<String>,
+    #[validate(length(min = 1, message = "Action must not be empty"))]
+    pub action: Option<String>,
+    #[validate(length(min = 1, message = "Result must not be empty"))]
+    pub result: Option<String>,
+}
+
+impl Star {
+    /// Condenses the story into a single resume bullet: the action taken
+    /// followed by its result. Returns `None` when neither is present.
+    pub fn condensed(&self) -> Option<String> {
+        let action = self.action.as_deref().map(trim_sentence);
+        let result = self.result.as_deref().map(trim_sentence);
+        match (action, result) {
+            (Some(action), Some(result)) => Some(format!("{}, {}", action, lowercase_first(result))),
+            (Some(action), None) => Some(action.to_string()),
+            (None, Some(result)) => Some(result.to_string()),
+            (None, None) => None,
+        }
+    }
+
+    /// Full interview narrative with one labelled paragraph per STAR field.
+    pub fn narrative(&self) -> String {
+        [
+            ("Situation", &self.situation),
+            ("Task", &self.task),
+            ("Action", &self.action),
+            ("Result", &self.result),
+        ]
+        .iter()
+        .filter_map(|(label, value)| value.as_ref().map(|v| format!("{}: {}", label, v.trim())))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+    }
+}
+
+impl Responsibility {
+    /// Text used when the responsibility is rendered as a resume bullet.
+    pub fn bullet(&self) -> String {
+        self.star
+            .as_ref()
+            .and_then(Star::condensed)
+            .unwrap_or_else(|| self.name.clone())
+    }
+}
+
+fn trim_sentence(text: &str) -> &str {
+    text.trim().trim_end_matches('.')
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        // Keep acronyms such as "API" or "AWS" intact
+        Some(first) if !chars.next().is_some_and(|c| c.is_uppercase()) => {
+            first.to_lowercase().chain(text[first.len_utf8()..].chars()).collect()
+        }
+        _ => text.to_string(),
+    }
 }
@@ -0,0 +1,147 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use super::experience::{Experience, DATE_REGEX};
+use super::profile::Profile;
+use super::skill::Skill;
+
+/// A curated document assembled from the profile's data pool. A resume only
+/// references experiences and skills; overrides are stored on the resume so
+/// the source records stay untouched.
+///
+/// A resume with a `parent_id` is a variant: it inherits everything from its
+/// parent and only stores what it overrides. A non-empty `sections` or
+/// `skills` list and a `summary` replace the parent's; `experiences` entries
+/// are merged into the parent's entry with the same `experience_id`, and
+/// `excluded_experiences` drops inherited entries.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone)]
+pub struct Resume {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    #[validate(length(min = 1, message = "Resume name is required"))]
+    pub name: String,
+    pub profile_id: ObjectId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<ObjectId>,
+    #[validate(length(min = 1, message = "Summary override must not be empty"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // Replaces the profile summary on this resume only
+    pub summary: Option<String>,
+    #[serde(default)]  // Empty means the default layout
+    pub sections: Vec<ResumeSection>,
+    #[validate]
+    #[serde(default)]
+    pub experiences: Vec<ResumeExperience>,
+    #[serde(default)]  // Skill ids, in display order
+    pub skills: Vec<ObjectId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]  // Inherited experiences left out of a variant
+    pub excluded_experiences: Vec<ObjectId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionKind {
+    Summary,
+    Experience,
+    Skills,
+}
+
+impl SectionKind {
+    pub fn default_title(&self) -> &'static str {
+        match self {
+            SectionKind::Summary => "Summary",
+            SectionKind::Experience => "Experience",
+            SectionKind::Skills => "Skills",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResumeSection {
+    pub kind: SectionKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl ResumeSection {
+    pub fn new(kind: SectionKind) -> Self {
+        ResumeSection { kind, title: None }
+    }
+
+    pub fn heading(&self) -> &str {
+        self.title.as_deref().unwrap_or(self.kind.default_title())
+    }
+
+    pub fn default_layout() -> Vec<ResumeSection> {
+        vec![
+            ResumeSection::new(SectionKind::Summary),
+            ResumeSection::new(SectionKind::Experience),
+            ResumeSection::new(SectionKind::Skills),
+        ]
+    }
+}
+
+/// Selection of one experience on a resume.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone)]
+pub struct ResumeExperience {
+    pub experience_id: ObjectId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]  // None keeps every responsibility in stored order
+    pub responsibilities: Option<Vec<ObjectId>>,
+    #[validate]
+    #[serde(default)]
+    pub overrides: ExperienceOverrides,
+    #[validate]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub responsibility_overrides: Vec<ResponsibilityOverride>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, Default, PartialEq)]
+pub struct ExperienceOverrides {
+    #[validate(length(min = 1, message = "Job title override must not be empty"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[validate(length(min = 1, message = "Company override must not be empty"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[validate(length(min = 1, message = "Location override must not be empty"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[validate(regex(path = "DATE_REGEX", message = "Start date must be in YYYY-MM-DD format"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[validate(regex(path = "DATE_REGEX", message = "End date must be in YYYY-MM-DD format"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, PartialEq)]
+pub struct ResponsibilityOverride {
+    pub responsibility_id: ObjectId,
+    #[validate(length(min = 1, message = "Responsibility override must not be empty"))]
+    pub name: String,
+}
+
+/// Fields a variant overrides relative to its parent.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct VariantOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume_id: Option<ObjectId>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<ObjectId>,
+    pub overrides: Vec<String>,
+}
+
+/// Fully materialized resume: every reference replaced by the record it
+/// points to, with ordering and overrides already applied.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResolvedResume {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume_id: Option<ObjectId>,
+    pub name: String,
+    pub profile: Profile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    pub sections: Vec<ResumeSection>,
+    pub experiences: Vec<Experience>,
+    pub skills: Vec<Skill>,
+}
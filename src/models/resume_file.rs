@@ -0,0 +1,168 @@
+use std::str::FromStr;
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use crate::errors::AppError;
+use crate::renderers::yaml::{simplify, YAML_MIME};
+use super::category::Category;
+use super::experience::Experience;
+use super::profile::Profile;
+use super::resume::Resume;
+use super::skill::Skill;
+
+/// Version written to and expected in resume files.
+pub const RESUME_FILE_VERSION: u32 = 1;
+
+/// All resume data as one document, meant to be kept in a git-tracked YAML
+/// or TOML file. Records keep their ids so that the file can be compared
+/// with the database. Responsibilities are listed in display order, which
+/// replaces their `next` links. `experiences` must be present, even if
+/// empty, and unknown keys are rejected: syncing a file that lost or
+/// misspelt the key would otherwise delete every stored experience.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ResumeFile {
+    pub version: u32,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+    #[serde(default)]
+    pub skills: Vec<Skill>,
+    pub experiences: Vec<Experience>,
+    #[serde(default)]
+    pub resumes: Vec<Resume>,
+}
+
+impl Default for ResumeFile {
+    fn default() -> Self {
+        ResumeFile {
+            version: RESUME_FILE_VERSION,
+            profiles: vec![],
+            categories: vec![],
+            skills: vec![],
+            experiences: vec![],
+            resumes: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FileFormat {
+    #[default]
+    Yaml,
+    Toml,
+}
+
+impl FileFormat {
+    pub const SUPPORTED: &'static [&'static str] = &["yaml", "toml"];
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            FileFormat::Yaml => YAML_MIME,
+            FileFormat::Toml => "application/toml",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FileFormat::Yaml => "yaml",
+            FileFormat::Toml => "toml",
+        }
+    }
+}
+
+impl FromStr for FileFormat {
+    type Err = AppError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Ok(FileFormat::Yaml),
+            "toml" => Ok(FileFormat::Toml),
+            other => Err(AppError::ValidationError(format!(
+                "Unsupported file format '{}'. Supported formats: {}",
+                other,
+                FileFormat::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+impl ResumeFile {
+    /// Writes the file with ids as plain hex strings and without empty
+    /// values, which TOML cannot represent.
+    pub fn render(&self, format: FileFormat) -> Result<String, AppError> {
+        let mut file = self.clone();
+        for experience in &mut file.experiences {
+            experience.responsibilities = experience.ordered_responsibilities();
+        }
+        let mut document = serde_json::to_value(&file).map_err(|e| AppError::SerializationError(e.to_string()))?;
+        simplify(&mut document);
+        for experience in document["experiences"].as_array_mut().into_iter().flatten() {
+            for responsibility in experience["responsibilities"].as_array_mut().into_iter().flatten() {
+                if let Some(responsibility) = responsibility.as_object_mut() {
+                    responsibility.remove("next");
+                }
+            }
+        }
+        match format {
+            FileFormat::Yaml => serde_yaml::to_string(&document).map_err(|e| AppError::SerializationError(e.to_string())),
+            FileFormat::Toml => toml::to_string(&document).map_err(|e| AppError::SerializationError(e.to_string())),
+        }
+    }
+
+    /// Reads a file written by [`ResumeFile::render`] or by hand. Syntax
+    /// errors carry the line and column. Ids missing from the file are left
+    /// out, for [`assign_ids`](crate::services::resume_sync::assign_ids) to
+    /// fill in against the stored records.
+    pub fn parse(text: &str, format: FileFormat) -> Result<ResumeFile, AppError> {
+        let invalid = |e: String| AppError::ValidationError(format!("Invalid resume file: {}", e));
+        let file: ResumeFile = match format {
+            FileFormat::Yaml => serde_yaml::from_str(text).map_err(|e| invalid(e.to_string()))?,
+            FileFormat::Toml => toml::from_str(text).map_err(|e| invalid(e.to_string()))?,
+        };
+        if file.version != RESUME_FILE_VERSION {
+            return Err(invalid(format!("unsupported version {}, expected {}", file.version, RESUME_FILE_VERSION)));
+        }
+        Ok(file)
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncTarget {
+    Experience,
+    Responsibility,
+}
+
+/// One step of a sync plan. A created experience brings its
+/// responsibilities along, so they are not listed on their own.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct SyncChange {
+    pub action: SyncAction,
+    pub target: SyncTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experience_id: Option<ObjectId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responsibility_id: Option<ObjectId>,
+    /// `Job title at Company`, or the responsibility name.
+    pub name: String,
+    /// Fields an update changes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+/// Differences between a resume file and the database, from the file's
+/// point of view: applying the plan makes the database match the file.
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct SyncPlan {
+    pub applied: bool,
+    pub changes: Vec<SyncChange>,
+}
@@ -0,0 +1,128 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use super::resume::ResolvedResume;
+
+/// Frozen copy of a resolved resume, exactly as it was sent out.
+/// Snapshots are never updated once stored.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResumeSnapshot {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub resume_id: ObjectId,
+    pub label: String,
+    /// SHA-256 of the serialized document, hex encoded.
+    pub content_hash: String,
+    /// RFC 3339 timestamp of when the snapshot was taken.
+    pub created_at: String,
+    pub document: ResolvedResume,
+}
+
+/// Listing entry for a snapshot, without the document itself.
+#[derive(Debug, Serialize, Clone)]
+pub struct SnapshotSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub resume_id: ObjectId,
+    pub label: String,
+    pub content_hash: String,
+    pub created_at: String,
+}
+
+impl From<&ResumeSnapshot> for SnapshotSummary {
+    fn from(snapshot: &ResumeSnapshot) -> Self {
+        SnapshotSummary {
+            id: snapshot.id,
+            resume_id: snapshot.resume_id,
+            label: snapshot.label.clone(),
+            content_hash: snapshot.content_hash.clone(),
+            created_at: snapshot.created_at.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateSnapshot {
+    #[validate(length(min = 1, message = "Snapshot label is required"))]
+    pub label: String,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WordOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// Run of consecutive words sharing the same diff operation.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct WordChange {
+    pub op: WordOp,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordChange>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BulletDiff {
+    pub change: ChangeKind,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordChange>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ExperienceDiff {
+    pub change: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experience_id: Option<ObjectId>,
+    pub job_title: String,
+    pub company: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub responsibilities: Vec<BulletDiff>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct SkillDiff {
+    pub change: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_id: Option<ObjectId>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+}
+
+/// Structured difference between two resolved resumes.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ResumeDiff {
+    pub identical: bool,
+    pub fields: Vec<FieldChange>,
+    pub experiences: Vec<ExperienceDiff>,
+    pub skills: Vec<SkillDiff>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SnapshotDiff {
+    pub from: SnapshotSummary,
+    pub to: SnapshotSummary,
+    pub diff: ResumeDiff,
+}
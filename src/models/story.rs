@@ -0,0 +1,51 @@
+use mongodb::bson::oid::ObjectId;
+use serde::Serialize;
+use super::experience::Experience;
+use super::responsibility::Star;
+
+/// Interview story derived from a STAR-structured responsibility.
+#[derive(Debug, Serialize, Clone)]
+pub struct Story {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experience_id: Option<ObjectId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responsibility_id: Option<ObjectId>,
+    pub job_title: String,
+    pub company: String,
+    pub title: String,
+    pub star: Star,
+    pub narrative: String,
+    pub skills: Vec<String>,
+}
+
+impl Story {
+    /// Collects every STAR entry across the given experiences. When `skill`
+    /// is set, only stories from experiences using that skill (matched
+    /// case-insensitively against the environment) are kept.
+    pub fn collect(experiences: &[Experience], skill: Option<&str>) -> Vec<Story> {
+        experiences
+            .iter()
+            .filter(|experience| match skill {
+                Some(skill) => experience
+                    .environment
+                    .iter()
+                    .any(|s| s.name.eq_ignore_ascii_case(skill.trim())),
+                None => true,
+            })
+            .flat_map(|experience| {
+                experience.ordered_responsibilities().into_iter().filter_map(move |responsibility| {
+                    responsibility.star.as_ref().map(|star| Story {
+                        experience_id: experience.id,
+                        responsibility_id: responsibility.id,
+                        job_title: experience.job_title.clone(),
+                        company: experience.company.clone(),
+                        title: responsibility.name.clone(),
+                        narrative: star.narrative(),
+                        star: star.clone(),
+                        skills: experience.environment.iter().map(|s| s.name.clone()).collect(),
+                    })
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,70 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::services::relevance::Keyword;
+use super::resume::Resume;
+
+/// Body of `POST /api/tailor`.
+#[derive(Debug, Deserialize, Validate, Clone)]
+pub struct TailorRequest {
+    #[validate(length(min = 1, message = "Job description is required"))]
+    pub job_description: String,
+    /// Only rank this profile's experiences. Required to create a draft.
+    #[serde(default)]
+    pub profile_id: Option<ObjectId>,
+    /// Bullets kept per job in the draft resume.
+    #[validate(range(min = 1, max = 20, message = "Top must be between 1 and 20"))]
+    #[serde(default = "default_top")]
+    pub top: usize,
+    /// Store a draft resume built from the ranking.
+    #[serde(default)]
+    pub create_resume: bool,
+    #[validate(length(min = 1, message = "Resume name must not be empty"))]
+    #[serde(default)]
+    pub resume_name: Option<String>,
+}
+
+fn default_top() -> usize {
+    3
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RankedExperience {
+    pub experience_id: Option<ObjectId>,
+    pub job_title: String,
+    pub company: String,
+    pub score: f64,
+    /// Job description keywords found, most significant first.
+    pub matched: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RankedResponsibility {
+    pub experience_id: Option<ObjectId>,
+    pub responsibility_id: Option<ObjectId>,
+    /// The bullet as printed on a resume.
+    pub text: String,
+    pub score: f64,
+    pub matched: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct RankedSkill {
+    pub skill_id: Option<ObjectId>,
+    pub name: String,
+    pub score: f64,
+    pub matched: Vec<String>,
+}
+
+/// Everything ranked against a job description, most relevant first.
+#[derive(Debug, Serialize, Clone)]
+pub struct TailorReport {
+    /// Terms of the job description, most frequent first.
+    pub keywords: Vec<Keyword>,
+    pub experiences: Vec<RankedExperience>,
+    pub responsibilities: Vec<RankedResponsibility>,
+    pub skills: Vec<RankedSkill>,
+    /// The draft resume, when one was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume: Option<Resume>,
+}
@@ -0,0 +1,57 @@
+use lazy_static::lazy_static;
+use mongodb::bson::oid::ObjectId;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+lazy_static! {
+    pub(crate) static ref ASSET_NAME_REGEX: Regex = Regex::new(r"^[A-Za-z0-9][A-Za-z0-9._-]*$").unwrap();
+}
+
+/// A resume template uploaded by a designer: a Tera template rendered
+/// against the same view model as the bundled themes, plus the files it
+/// needs. Templates are compiled when they are stored, so a stored template
+/// always parses.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone)]
+pub struct Template {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    #[validate(length(min = 1, message = "Template name is required"))]
+    pub name: String,
+    #[validate(length(min = 1, message = "Template source is required"))]
+    pub source: String,
+    #[validate]
+    #[serde(default)]
+    pub assets: Vec<TemplateAsset>,
+}
+
+/// A file shipped with a template, such as a stylesheet or a logo. The
+/// template reaches it as a `data:` URL under `assets[name]`.
+#[derive(Debug, Serialize, Deserialize, Validate, Clone, PartialEq)]
+pub struct TemplateAsset {
+    #[validate(regex(path = "ASSET_NAME_REGEX", message = "Asset name may only contain letters, digits, '.', '_' and '-'"))]
+    pub name: String,
+    #[validate(length(min = 1, message = "Asset content type is required"))]
+    pub content_type: String,
+    /// Base64-encoded content.
+    pub data: String,
+}
+
+/// Listing entry for a template, without its source and asset contents.
+#[derive(Debug, Serialize, Clone)]
+pub struct TemplateSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub name: String,
+    pub assets: Vec<String>,
+}
+
+impl From<&Template> for TemplateSummary {
+    fn from(template: &Template) -> Self {
+        TemplateSummary {
+            id: template.id,
+            name: template.name.clone(),
+            assets: template.assets.iter().map(|asset| asset.name.clone()).collect(),
+        }
+    }
+}
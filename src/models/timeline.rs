@@ -0,0 +1,64 @@
+use mongodb::bson::oid::ObjectId;
+use serde::Serialize;
+
+/// A role as placed on the timeline.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TimelineRole {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experience_id: Option<ObjectId>,
+    pub job_title: String,
+    pub company: String,
+}
+
+/// Time between two roles with nothing in it. Dates are inclusive.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Gap {
+    pub start: String,
+    pub end: String,
+    pub days: i64,
+    /// The role that ended before the gap.
+    pub after: TimelineRole,
+    /// The role that started after it.
+    pub before: TimelineRole,
+}
+
+/// Time during which two roles were held at once. Dates are inclusive.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Overlap {
+    pub start: String,
+    pub end: String,
+    pub days: i64,
+    pub roles: [TimelineRole; 2],
+}
+
+/// A role whose dates cannot be placed on the timeline.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Inconsistency {
+    /// `invalid-date`, `end-before-start` or `starts-in-future`.
+    pub kind: String,
+    pub message: String,
+    pub role: TimelineRole,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct YearBreakdown {
+    pub year: i32,
+    /// Days worked in the year, counting overlapping roles once.
+    pub days: i64,
+    /// Roles held at some point during the year.
+    pub roles: Vec<TimelineRole>,
+}
+
+/// Body of `GET /api/profile/{id}/timeline`.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Timeline {
+    /// Days worked, counting overlapping roles once.
+    pub total_days: i64,
+    pub total_years: f64,
+    /// Gaps longer than the threshold, oldest first.
+    pub gaps: Vec<Gap>,
+    pub overlaps: Vec<Overlap>,
+    pub inconsistencies: Vec<Inconsistency>,
+    /// Every year from the first role to the last, gap years included.
+    pub years: Vec<YearBreakdown>,
+}
@@ -0,0 +1,284 @@
+use std::io::{Cursor, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::profile::is_link_url;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::pdf::PageSize;
+use crate::renderers::{check_date_format, format_period, group_by_category, DEFAULT_DATE_FORMAT};
+
+pub const DOCX_MIME: &str = "application/vnd.openxmlformats-officedocument.wordprocessingml.document";
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+<Override PartName="/word/numbering.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml"/>
+<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+</Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>
+</Relationships>"#;
+
+const STYLES: &str = include_str!("../../templates/docx/styles.xml");
+const NUMBERING: &str = include_str!("../../templates/docx/numbering.xml");
+
+// Bullet list defined in numbering.xml.
+const BULLET_LIST: u32 = 1;
+
+/// Options for [`render_docx`].
+#[derive(Debug, Clone)]
+pub struct DocxOptions {
+    pub page_size: PageSize,
+    /// strftime pattern used for experience dates.
+    pub date_format: String,
+}
+
+impl Default for DocxOptions {
+    fn default() -> Self {
+        DocxOptions { page_size: PageSize::default(), date_format: DEFAULT_DATE_FORMAT.to_string() }
+    }
+}
+
+impl DocxOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        check_date_format(&self.date_format)
+    }
+}
+
+/// Renders a resolved resume as a Word document. Content only uses named
+/// styles (`Title`, `Heading1`, `Heading2`, `ListBullet`, ...) so it can be
+/// restyled in Word; `generated_at` is the RFC 3339 creation time recorded
+/// in the document properties.
+pub fn render_docx(resume: &ResolvedResume, options: &DocxOptions, generated_at: &str) -> Result<Vec<u8>, AppError> {
+    let mut document = DocumentBuilder::default();
+    document.build(resume, options);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let file_options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    let parts = [
+        ("[Content_Types].xml", CONTENT_TYPES.to_string()),
+        ("_rels/.rels", PACKAGE_RELS.to_string()),
+        ("docProps/core.xml", core_properties(resume, generated_at)),
+        ("word/document.xml", document.document_xml(options.page_size)),
+        ("word/_rels/document.xml.rels", document.relationships_xml()),
+        ("word/styles.xml", STYLES.to_string()),
+        ("word/numbering.xml", NUMBERING.to_string()),
+    ];
+    for (name, content) in parts {
+        zip.start_file(name, file_options).map_err(zip_error)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    Ok(zip.finish().map_err(zip_error)?.into_inner())
+}
+
+fn zip_error(err: zip::result::ZipError) -> AppError {
+    AppError::InternalServerError(format!("Failed to write DOCX: {}", err))
+}
+
+/// Escapes text for XML content and attribute values, dropping characters
+/// XML 1.0 does not allow.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(' '),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn core_properties(resume: &ResolvedResume, generated_at: &str) -> String {
+    let keywords: Vec<&str> = resume.skills.iter().map(|s| s.name.as_str()).collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+<dc:title>{} – {}</dc:title>
+<dc:creator>{}</dc:creator>
+<cp:keywords>{}</cp:keywords>
+<dcterms:created xsi:type="dcterms:W3CDTF">{}</dcterms:created>
+<dcterms:modified xsi:type="dcterms:W3CDTF">{}</dcterms:modified>
+</cp:coreProperties>"#,
+        escape(&resume.profile.name),
+        escape(&resume.name),
+        escape(&resume.profile.name),
+        escape(&keywords.join(", ")),
+        escape(generated_at),
+        escape(generated_at)
+    )
+}
+
+// Accumulates the document body and the external links it references.
+#[derive(Default)]
+struct DocumentBuilder {
+    body: String,
+    links: Vec<String>,
+}
+
+impl DocumentBuilder {
+    fn build(&mut self, resume: &ResolvedResume, options: &DocxOptions) {
+        let profile = &resume.profile;
+        self.paragraph("Title", &run(&profile.name));
+        if let Some(label) = &profile.label {
+            self.paragraph("Subtitle", &run(label));
+        }
+
+        let mut contact: Vec<String> = Vec::new();
+        if let Some(email) = &profile.email {
+            contact.push(self.hyperlink(&format!("mailto:{}", email), email));
+        }
+        if let Some(phone) = &profile.phone {
+            contact.push(run(phone));
+        }
+        if let Some(url) = profile.url.as_ref().filter(|url| is_link_url(url)) {
+            contact.push(self.hyperlink(url, url));
+        }
+        if let Some(location) = &profile.location {
+            contact.push(run(location));
+        }
+        for link in profile.links.iter().filter(|link| is_link_url(&link.url)) {
+            contact.push(self.hyperlink(&link.url, &link.network));
+        }
+        if !contact.is_empty() {
+            self.paragraph("Contact", &contact.join(&run(" · ")));
+        }
+
+        for section in &resume.sections {
+            match section.kind {
+                SectionKind::Summary => {
+                    if let Some(summary) = &resume.summary {
+                        self.paragraph("Heading1", &run(section.heading()));
+                        for text in summary.split("\n\n").map(str::trim).filter(|t| !t.is_empty()) {
+                            self.paragraph("Normal", &run(text));
+                        }
+                    }
+                }
+                SectionKind::Experience => {
+                    if !resume.experiences.is_empty() {
+                        self.paragraph("Heading1", &run(section.heading()));
+                        for experience in &resume.experiences {
+                            self.experience(experience, &options.date_format);
+                        }
+                    }
+                }
+                SectionKind::Skills => {
+                    if !resume.skills.is_empty() {
+                        self.paragraph("Heading1", &run(section.heading()));
+                        self.skills_table(resume);
+                    }
+                }
+            }
+        }
+    }
+
+    fn experience(&mut self, experience: &Experience, date_format: &str) {
+        self.paragraph("Heading2", &run(&format!("{} — {}", experience.job_title, experience.company)));
+        self.paragraph("EntryDetails", &run(&format!(
+            "{} · {}",
+            experience.location,
+            format_period(&experience.start_date, &experience.end_date, date_format)
+        )));
+        for responsibility in &experience.responsibilities {
+            self.body.push_str(&format!(
+                "<w:p><w:pPr><w:pStyle w:val=\"ListBullet\"/><w:numPr><w:ilvl w:val=\"0\"/><w:numId w:val=\"{}\"/></w:numPr></w:pPr>{}</w:p>",
+                BULLET_LIST,
+                run(&responsibility.bullet())
+            ));
+        }
+        if !experience.environment.is_empty() {
+            let names: Vec<&str> = experience.environment.iter().map(|s| s.name.as_str()).collect();
+            self.paragraph("EntryDetails", &format!("{}{}", bold_run("Environment: "), run(&names.join(", "))));
+        }
+    }
+
+    fn skills_table(&mut self, resume: &ResolvedResume) {
+        self.body.push_str(
+            "<w:tbl><w:tblPr><w:tblStyle w:val=\"SkillsTable\"/><w:tblW w:w=\"5000\" w:type=\"pct\"/>\
+             <w:tblLook w:val=\"04A0\" w:firstRow=\"1\" w:lastRow=\"0\" w:firstColumn=\"1\" w:lastColumn=\"0\" w:noHBand=\"0\" w:noVBand=\"1\"/></w:tblPr>\
+             <w:tblGrid><w:gridCol w:w=\"2600\"/><w:gridCol w:w=\"7000\"/></w:tblGrid>",
+        );
+        self.table_row(true, "Category", "Skills");
+        for group in group_by_category(&resume.skills) {
+            let names: Vec<&str> = group.skills.iter().map(|s| s.name.as_str()).collect();
+            self.table_row(false, &group.category, &names.join(", "));
+        }
+        self.body.push_str("</w:tbl>");
+    }
+
+    fn table_row(&mut self, header: bool, category: &str, skills: &str) {
+        let row_properties = if header { "<w:trPr><w:tblHeader/></w:trPr>" } else { "" };
+        self.body.push_str(&format!(
+            "<w:tr>{}<w:tc><w:tcPr><w:tcW w:w=\"2600\" w:type=\"dxa\"/></w:tcPr><w:p>{}</w:p></w:tc>\
+             <w:tc><w:tcPr><w:tcW w:w=\"7000\" w:type=\"dxa\"/></w:tcPr><w:p>{}</w:p></w:tc></w:tr>",
+            row_properties,
+            run(category),
+            run(skills)
+        ));
+    }
+
+    fn paragraph(&mut self, style: &str, runs: &str) {
+        self.body.push_str(&format!("<w:p><w:pPr><w:pStyle w:val=\"{}\"/></w:pPr>{}</w:p>", style, runs));
+    }
+
+    // Registers an external link and returns the hyperlink markup.
+    fn hyperlink(&mut self, target: &str, text: &str) -> String {
+        self.links.push(target.to_string());
+        format!(
+            "<w:hyperlink r:id=\"rIdLink{}\"><w:r><w:rPr><w:rStyle w:val=\"Hyperlink\"/></w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r></w:hyperlink>",
+            self.links.len(),
+            escape(text)
+        )
+    }
+
+    fn document_xml(&self, page_size: PageSize) -> String {
+        // Page size in twentieths of a point, margins of 2 cm
+        let (width, height) = match page_size {
+            PageSize::A4 => (11906, 16838),
+            PageSize::Letter => (12240, 15840),
+        };
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:body>{}<w:sectPr><w:pgSz w:w="{}" w:h="{}"/><w:pgMar w:top="1134" w:right="1134" w:bottom="1134" w:left="1134" w:header="567" w:footer="567" w:gutter="0"/></w:sectPr></w:body></w:document>"#,
+            self.body, width, height
+        )
+    }
+
+    fn relationships_xml(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\n\
+             <Relationship Id=\"rIdStyles\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles\" Target=\"styles.xml\"/>\n\
+             <Relationship Id=\"rIdNumbering\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering\" Target=\"numbering.xml\"/>\n",
+        );
+        for (index, target) in self.links.iter().enumerate() {
+            xml.push_str(&format!(
+                "<Relationship Id=\"rIdLink{}\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink\" Target=\"{}\" TargetMode=\"External\"/>\n",
+                index + 1,
+                escape(target)
+            ));
+        }
+        xml.push_str("</Relationships>");
+        xml
+    }
+}
+
+fn run(text: &str) -> String {
+    format!("<w:r><w:t xml:space=\"preserve\">{}</w:t></w:r>", escape(text))
+}
+
+fn bold_run(text: &str) -> String {
+    format!("<w:r><w:rPr><w:b/></w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r>", escape(text))
+}
@@ -0,0 +1,237 @@
+use crate::models::education::Education;
+use crate::models::language::Language;
+use crate::models::profile::Profile;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::docx::escape;
+use crate::renderers::group_by_category;
+use crate::renderers::latex::split_name;
+
+pub const EUROPASS_NAMESPACE: &str = "http://europass.cedefop.europa.eu/Europass";
+const SCHEMA_LOCATION: &str = "http://europass.cedefop.europa.eu/Europass http://europass.cedefop.europa.eu/xml/v3.3.0/EuropassSchema.xsd";
+const XSD_VERSION: &str = "V3.3";
+
+/// `Use` code of the website holding the profile URL; other websites are
+/// the profile links, labelled with their network.
+pub const PERSONAL_WEBSITE: &str = "personal";
+pub const LINK_WEBSITE: &str = "business";
+
+/// CEFR skills a Europass foreign language is assessed on.
+pub const PROFICIENCY_SKILLS: &[&str] = &["Listening", "Reading", "SpokenInteraction", "SpokenProduction", "Writing"];
+
+/// Renders a resolved resume as a Europass CV (XML schema v3.3).
+/// Experiences become `WorkExperience` entries with their responsibilities
+/// as an HTML list of activities, the profile's education and languages
+/// fill `EducationList` and `Skills/Linguistic`, and skills are listed by
+/// category under `Skills/Computer`. A foreign language is given its level
+/// for all five CEFR skills. Europass has no place for the summary, the
+/// environment of an experience or an education score, so they are left
+/// out, as are sections missing from the layout.
+pub fn render_europass(resume: &ResolvedResume, generated_at: &str) -> String {
+    let has = |kind: SectionKind| resume.sections.iter().any(|s| s.kind == kind);
+    let profile = &resume.profile;
+    let mut out = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <SkillsPassport xmlns=\"{}\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"{}\" locale=\"en\">\n\
+         <DocumentInfo>\n\
+         <DocumentType>ECV</DocumentType>\n\
+         <CreationDate>{}</CreationDate>\n\
+         <LastUpdateDate>{}</LastUpdateDate>\n\
+         <XSDVersion>{}</XSDVersion>\n\
+         <Generator>rust-server</Generator>\n\
+         </DocumentInfo>\n\
+         <LearnerInfo>\n",
+        EUROPASS_NAMESPACE,
+        SCHEMA_LOCATION,
+        escape(generated_at),
+        escape(generated_at),
+        XSD_VERSION
+    );
+
+    out.push_str(&identification(profile));
+    if let Some(label) = &profile.label {
+        out.push_str(&format!(
+            "<Headline>\n<Type><Code>position</Code><Label>Position</Label></Type>\n<Description><Label>{}</Label></Description>\n</Headline>\n",
+            escape(label)
+        ));
+    }
+
+    if has(SectionKind::Experience) && !resume.experiences.is_empty() {
+        out.push_str("<WorkExperienceList>\n");
+        for experience in &resume.experiences {
+            let activities: Vec<String> = experience.responsibilities.iter().map(|r| r.bullet()).collect();
+            out.push_str("<WorkExperience>\n");
+            out.push_str(&period(Some(&experience.start_date), Some(&experience.end_date)));
+            out.push_str(&format!("<Position><Label>{}</Label></Position>\n", escape(&experience.job_title)));
+            if !activities.is_empty() {
+                out.push_str(&format!("<Activities>{}</Activities>\n", escape(&html_list(&activities))));
+            }
+            out.push_str(&format!("<Employer>\n<Name>{}</Name>\n", escape(&experience.company)));
+            if !experience.location.is_empty() {
+                out.push_str(&format!(
+                    "<ContactInfo><Address><Contact><Municipality>{}</Municipality></Contact></Address></ContactInfo>\n",
+                    escape(&experience.location)
+                ));
+            }
+            out.push_str("</Employer>\n</WorkExperience>\n");
+        }
+        out.push_str("</WorkExperienceList>\n");
+    }
+
+    if !profile.education.is_empty() {
+        out.push_str("<EducationList>\n");
+        for entry in &profile.education {
+            out.push_str(&education(entry));
+        }
+        out.push_str("</EducationList>\n");
+    }
+
+    let skills = has(SectionKind::Skills) && !resume.skills.is_empty();
+    if skills || !profile.languages.is_empty() {
+        out.push_str("<Skills>\n");
+        if !profile.languages.is_empty() {
+            out.push_str(&linguistic(&profile.languages));
+        }
+        if skills {
+            let groups: Vec<String> = group_by_category(&resume.skills)
+                .into_iter()
+                .map(|group| {
+                    let names: Vec<&str> = group.skills.iter().map(|s| s.name.as_str()).collect();
+                    format!("{}: {}", group.category, names.join(", "))
+                })
+                .collect();
+            out.push_str(&format!("<Computer>\n<Description>{}</Description>\n</Computer>\n", escape(&html_list(&groups))));
+        }
+        out.push_str("</Skills>\n");
+    }
+
+    out.push_str("</LearnerInfo>\n</SkillsPassport>\n");
+    out
+}
+
+fn identification(profile: &Profile) -> String {
+    let (first, last) = split_name(&profile.name);
+    let mut out = format!(
+        "<Identification>\n<PersonName>\n<FirstName>{}</FirstName>\n<Surname>{}</Surname>\n</PersonName>\n",
+        escape(first),
+        escape(last)
+    );
+
+    let mut contact = String::new();
+    if let Some(location) = &profile.location {
+        // "City, Country" is the convention used across our models
+        let (municipality, country) = match location.split_once(',') {
+            Some((municipality, country)) => (municipality.trim(), Some(country.trim()).filter(|c| !c.is_empty())),
+            None => (location.trim(), None),
+        };
+        contact.push_str(&format!("<Address><Contact>\n<Municipality>{}</Municipality>\n", escape(municipality)));
+        if let Some(country) = country {
+            contact.push_str(&format!("<Country><Label>{}</Label></Country>\n", escape(country)));
+        }
+        contact.push_str("</Contact></Address>\n");
+    }
+    if let Some(email) = &profile.email {
+        contact.push_str(&format!("<Email><Contact>{}</Contact></Email>\n", escape(email)));
+    }
+    if let Some(phone) = &profile.phone {
+        contact.push_str(&format!(
+            "<TelephoneList><Telephone><Contact>{}</Contact><Use><Code>mobile</Code></Use></Telephone></TelephoneList>\n",
+            escape(phone)
+        ));
+    }
+    let mut websites: Vec<String> = Vec::new();
+    if let Some(url) = &profile.url {
+        websites.push(format!("<Website><Contact>{}</Contact><Use><Code>{}</Code></Use></Website>\n", escape(url), PERSONAL_WEBSITE));
+    }
+    websites.extend(profile.links.iter().map(|link| format!(
+        "<Website><Contact>{}</Contact><Use><Code>{}</Code><Label>{}</Label></Use></Website>\n",
+        escape(&link.url),
+        LINK_WEBSITE,
+        escape(&link.network)
+    )));
+    if !websites.is_empty() {
+        contact.push_str(&format!("<WebsiteList>\n{}</WebsiteList>\n", websites.concat()));
+    }
+
+    if !contact.is_empty() {
+        out.push_str(&format!("<ContactInfo>\n{}</ContactInfo>\n", contact));
+    }
+    out.push_str("</Identification>\n");
+    out
+}
+
+fn education(entry: &Education) -> String {
+    let mut out = String::from("<Education>\n");
+    out.push_str(&period(entry.start_date.as_ref(), entry.end_date.as_ref()));
+    if let Some(study_type) = &entry.study_type {
+        out.push_str(&format!("<Title>{}</Title>\n", escape(study_type)));
+    }
+    out.push_str(&format!("<Organisation><Name>{}</Name></Organisation>\n", escape(&entry.institution)));
+    if let Some(area) = &entry.area {
+        out.push_str(&format!("<Field><Label>{}</Label></Field>\n", escape(area)));
+    }
+    out.push_str("</Education>\n");
+    out
+}
+
+fn linguistic(languages: &[Language]) -> String {
+    let (native, foreign): (Vec<&Language>, Vec<&Language>) = languages.iter().partition(|l| l.native);
+    let mut out = String::from("<Linguistic>\n");
+    if !native.is_empty() {
+        out.push_str("<MotherTongueList>\n");
+        for language in native {
+            out.push_str(&format!(
+                "<MotherTongue><Description><Label>{}</Label></Description></MotherTongue>\n",
+                escape(&language.name)
+            ));
+        }
+        out.push_str("</MotherTongueList>\n");
+    }
+    if !foreign.is_empty() {
+        out.push_str("<ForeignLanguageList>\n");
+        for language in foreign {
+            out.push_str(&format!(
+                "<ForeignLanguage>\n<Description><Label>{}</Label></Description>\n",
+                escape(&language.name)
+            ));
+            if let Some(level) = &language.level {
+                out.push_str("<ProficiencyLevel>");
+                for skill in PROFICIENCY_SKILLS {
+                    out.push_str(&format!("<{0}>{1}</{0}>", skill, escape(level)));
+                }
+                out.push_str("</ProficiencyLevel>\n");
+            }
+            out.push_str("</ForeignLanguage>\n");
+        }
+        out.push_str("</ForeignLanguageList>\n");
+    }
+    out.push_str("</Linguistic>\n");
+    out
+}
+
+// Europass dates are split into gYear, gMonth and gDay attributes.
+fn period(from: Option<&String>, to: Option<&String>) -> String {
+    let date = |element: &str, date: Option<&String>| {
+        date.map(|date| {
+            let mut parts = date.splitn(3, '-');
+            let mut attributes = format!(" year=\"{}\"", escape(parts.next().unwrap_or_default()));
+            if let Some(month) = parts.next() {
+                attributes.push_str(&format!(" month=\"--{}\"", escape(month)));
+            }
+            if let Some(day) = parts.next() {
+                attributes.push_str(&format!(" day=\"---{}\"", escape(day)));
+            }
+            format!("<{}{}/>\n", element, attributes)
+        })
+        .unwrap_or_default()
+    };
+    if from.is_none() && to.is_none() {
+        return String::new();
+    }
+    format!("<Period>\n{}{}</Period>\n", date("From", from), date("To", to))
+}
+
+// Rich-text fields hold XHTML, escaped once more as element text.
+fn html_list(items: &[String]) -> String {
+    let items: Vec<String> = items.iter().map(|item| format!("<li>{}</li>", escape(item))).collect();
+    format!("<ul>{}</ul>", items.concat())
+}
@@ -0,0 +1,67 @@
+use crate::models::experience::Experience;
+
+pub const CSV_MIME: &str = "text/csv";
+
+/// Columns of the experience spreadsheet, in order. `id` and `profile_id`
+/// are ObjectId hex strings and may be left empty; dates are `YYYY-MM-DD`.
+pub const EXPERIENCE_CSV_COLUMNS: &[&str] = &[
+    "id",
+    "profile_id",
+    "job_title",
+    "company",
+    "location",
+    "start_date",
+    "end_date",
+    "responsibilities",
+    "skills",
+];
+
+/// Separates the items of the `responsibilities` and `skills` cells.
+pub const LIST_DELIMITER: char = '|';
+
+/// Escapes a [`LIST_DELIMITER`], a line break or itself inside a list item,
+/// as in `CI \| CD`.
+pub const LIST_ESCAPE: char = '\\';
+
+/// Writes experiences as CSV in the layout of [`EXPERIENCE_CSV_COLUMNS`],
+/// one row per experience. Responsibilities are listed by name in their
+/// linked order and skills by name, each list joined with
+/// [`LIST_DELIMITER`] and its items escaped with [`LIST_ESCAPE`], so that
+/// the file can be edited and imported back.
+pub fn render_experience_csv(experiences: &[Experience]) -> String {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    // Writing to memory only fails on I/O errors, which a Vec cannot raise
+    writer.write_record(EXPERIENCE_CSV_COLUMNS).unwrap();
+    let separator = format!(" {} ", LIST_DELIMITER);
+    for experience in experiences {
+        let responsibilities: Vec<String> = experience.ordered_responsibilities().iter().map(|r| escape_item(&r.name)).collect();
+        let skills: Vec<String> = experience.environment.iter().map(|s| escape_item(&s.name)).collect();
+        writer.write_record([
+            experience.id.map(|id| id.to_hex()).unwrap_or_default(),
+            experience.profile_id.map(|id| id.to_hex()).unwrap_or_default(),
+            experience.job_title.clone(),
+            experience.company.clone(),
+            experience.location.clone(),
+            experience.start_date.clone(),
+            experience.end_date.clone(),
+            responsibilities.join(&separator),
+            skills.join(&separator),
+        ]).unwrap();
+    }
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+fn escape_item(item: &str) -> String {
+    let mut escaped = String::with_capacity(item.len());
+    for c in item.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            LIST_DELIMITER | LIST_ESCAPE => {
+                escaped.push(LIST_ESCAPE);
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,258 @@
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use serde::Serialize;
+use tera::{Context, Tera};
+use crate::errors::AppError;
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::profile::{is_link_url, Profile};
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::json_ld::{resume_json_ld, to_script};
+use crate::renderers::{format_date, group_by_category};
+
+lazy_static! {
+    static ref THEMES: Tera = {
+        let mut tera = Tera::default();
+        tera.add_raw_templates(vec![
+            ("macros.html", include_str!("../../templates/themes/macros.html")),
+            ("base.html", include_str!("../../templates/themes/base.html")),
+            ("classic.html", include_str!("../../templates/themes/classic.html")),
+            ("modern.html", include_str!("../../templates/themes/modern.html")),
+            ("compact.html", include_str!("../../templates/themes/compact.html")),
+        ])
+        .expect("bundled themes compile");
+        tera
+    };
+}
+
+/// Themes bundled with the binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    #[default]
+    Classic,
+    Modern,
+    Compact,
+}
+
+impl Theme {
+    pub const SUPPORTED: &'static [&'static str] = &["classic", "modern", "compact"];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Classic => "classic",
+            Theme::Modern => "modern",
+            Theme::Compact => "compact",
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = AppError;
+
+    fn from_str(theme: &str) -> Result<Self, Self::Err> {
+        match theme.to_ascii_lowercase().as_str() {
+            "classic" => Ok(Theme::Classic),
+            "modern" => Ok(Theme::Modern),
+            "compact" => Ok(Theme::Compact),
+            other => Err(AppError::ValidationError(format!(
+                "Unknown theme '{}'. Available themes: {}",
+                other,
+                Theme::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+/// View model handed to HTML templates. Its field names are the contract
+/// themes are written against, so renaming one breaks every theme.
+#[derive(Debug, Serialize)]
+pub struct ResumeView {
+    pub name: String,
+    pub profile: ProfileView,
+    /// Summary split into paragraphs; empty when the resume has none.
+    pub summary: Vec<String>,
+    /// Sections in display order.
+    pub sections: Vec<SectionView>,
+    pub experiences: Vec<ExperienceView>,
+    /// The profile's education, shown after the experiences.
+    pub education: Vec<EducationView>,
+    pub skill_groups: Vec<SkillGroupView>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProfileView {
+    pub name: String,
+    pub label: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub url: Option<String>,
+    pub location: Option<String>,
+    pub links: Vec<LinkView>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LinkView {
+    pub network: String,
+    pub username: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SectionView {
+    /// `summary`, `experience` or `skills`.
+    pub kind: SectionKind,
+    pub title: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExperienceView {
+    pub id: Option<String>,
+    pub job_title: String,
+    pub company: String,
+    pub location: String,
+    /// Dates as stored (`YYYY-MM-DD`), for machine-readable markup.
+    pub start_date: String,
+    pub end_date: String,
+    /// Dates formatted for display.
+    pub start: String,
+    pub end: String,
+    pub responsibilities: Vec<ResponsibilityView>,
+    /// Names of the skills used in this role.
+    pub environment: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResponsibilityView {
+    pub id: Option<String>,
+    /// The bullet as printed: the condensed STAR story when there is one.
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EducationView {
+    pub institution: String,
+    pub area: Option<String>,
+    pub study_type: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkillGroupView {
+    pub category: String,
+    pub skills: Vec<SkillView>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkillView {
+    pub id: Option<String>,
+    pub name: String,
+    pub description: String,
+}
+
+impl ResumeView {
+    pub fn new(resume: &ResolvedResume, date_format: &str) -> Self {
+        ResumeView {
+            name: resume.name.clone(),
+            profile: ProfileView::from(&resume.profile),
+            summary: resume.summary
+                .as_deref()
+                .map(|summary| summary.split("\n\n").map(str::trim).filter(|p| !p.is_empty()).map(String::from).collect())
+                .unwrap_or_default(),
+            sections: resume.sections
+                .iter()
+                .map(|section| SectionView { kind: section.kind, title: section.heading().to_string() })
+                .collect(),
+            experiences: resume.experiences.iter().map(|e| ExperienceView::new(e, date_format)).collect(),
+            education: resume.profile.education.iter().map(|e| EducationView::new(e, date_format)).collect(),
+            skill_groups: group_by_category(&resume.skills)
+                .into_iter()
+                .map(|group| SkillGroupView {
+                    category: group.category,
+                    skills: group.skills
+                        .into_iter()
+                        .map(|skill| SkillView {
+                            id: skill.id.map(|id| id.to_hex()),
+                            name: skill.name.clone(),
+                            description: skill.description.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Profile> for ProfileView {
+    fn from(profile: &Profile) -> Self {
+        ProfileView {
+            name: profile.name.clone(),
+            label: profile.label.clone(),
+            email: profile.email.clone(),
+            phone: profile.phone.clone(),
+            // Stored data may predate the scheme check; never link to
+            // `javascript:` and the like
+            url: profile.url.clone().filter(|url| is_link_url(url)),
+            location: profile.location.clone(),
+            links: profile.links
+                .iter()
+                .filter(|link| is_link_url(&link.url))
+                .map(|link| LinkView {
+                    network: link.network.clone(),
+                    username: link.username.clone(),
+                    url: link.url.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ExperienceView {
+    pub fn new(experience: &Experience, date_format: &str) -> Self {
+        ExperienceView {
+            id: experience.id.map(|id| id.to_hex()),
+            job_title: experience.job_title.clone(),
+            company: experience.company.clone(),
+            location: experience.location.clone(),
+            start_date: experience.start_date.clone(),
+            end_date: experience.end_date.clone(),
+            start: format_date(&experience.start_date, date_format),
+            end: format_date(&experience.end_date, date_format),
+            responsibilities: experience.responsibilities
+                .iter()
+                .map(|r| ResponsibilityView { id: r.id.map(|id| id.to_hex()), text: r.bullet() })
+                .collect(),
+            environment: experience.environment.iter().map(|s| s.name.clone()).collect(),
+        }
+    }
+}
+
+impl EducationView {
+    pub fn new(education: &Education, date_format: &str) -> Self {
+        let format = |date: &Option<String>| date.as_deref().map(|d| format_date(d, date_format));
+        EducationView {
+            institution: education.institution.clone(),
+            area: education.area.clone(),
+            study_type: education.study_type.clone(),
+            start_date: education.start_date.clone(),
+            end_date: education.end_date.clone(),
+            start: format(&education.start_date),
+            end: format(&education.end_date),
+        }
+    }
+}
+
+/// Renders a resolved resume as a standalone HTML page with one of the
+/// bundled themes. Template output is HTML-escaped. Pages are marked up with
+/// h-resume microformat classes and embed the schema.org description of the
+/// resume as JSON-LD.
+pub fn render_html(resume: &ResolvedResume, theme: Theme, date_format: &str) -> Result<String, AppError> {
+    let mut context = Context::from_serialize(ResumeView::new(resume, date_format))
+        .map_err(|err| AppError::SerializationError(err.to_string()))?;
+    context.insert("theme", theme.name());
+    context.insert("json_ld", &to_script(&resume_json_ld(resume)));
+    THEMES.render(&format!("{}.html", theme.name()), &context)
+        .map_err(|err| AppError::InternalServerError(format!("Failed to render theme {}: {}", theme.name(), err)))
+}
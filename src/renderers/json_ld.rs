@@ -0,0 +1,128 @@
+use serde_json::{json, Map, Value};
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::profile::{is_link_url, Profile};
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::models::skill::Skill;
+
+pub const JSON_LD_MIME: &str = "application/ld+json";
+
+/// Describes a profile as a schema.org `Person`: contact details, links as
+/// `sameAs`, each experience as an `OrganizationRole` under `worksFor`
+/// (the role carries the title and dates, the organization the company),
+/// education as `hasCredential` entries of type
+/// `EducationalOccupationalCredential`, languages as `knowsLanguage` and
+/// skill names as `knowsAbout`.
+pub fn person(profile: &Profile, summary: Option<&str>, experiences: &[Experience], skills: &[&Skill]) -> Value {
+    let mut person = Map::new();
+    person.insert("@context".to_string(), json!("https://schema.org"));
+    person.insert("@type".to_string(), json!("Person"));
+    person.insert("name".to_string(), json!(profile.name));
+    insert(&mut person, "jobTitle", profile.label.as_deref());
+    insert(&mut person, "description", summary);
+    insert(&mut person, "email", profile.email.as_deref());
+    insert(&mut person, "telephone", profile.phone.as_deref());
+    insert(&mut person, "url", profile.url.as_deref().filter(|url| is_link_url(url)));
+    if let Some(location) = &profile.location {
+        person.insert("address".to_string(), address(location));
+    }
+    let links: Vec<Value> = profile.links.iter().filter(|link| is_link_url(&link.url)).map(|link| json!(link.url)).collect();
+    if !links.is_empty() {
+        person.insert("sameAs".to_string(), Value::Array(links));
+    }
+    if !experiences.is_empty() {
+        person.insert("worksFor".to_string(), experiences.iter().map(role).collect());
+    }
+    if !profile.education.is_empty() {
+        person.insert("hasCredential".to_string(), profile.education.iter().map(credential).collect());
+    }
+    if !profile.languages.is_empty() {
+        let languages = profile.languages.iter().map(|language| json!({ "@type": "Language", "name": language.name }));
+        person.insert("knowsLanguage".to_string(), languages.collect());
+    }
+    let mut topics: Vec<&str> = Vec::new();
+    for skill in skills {
+        if !topics.contains(&skill.name.as_str()) {
+            topics.push(&skill.name);
+        }
+    }
+    if !topics.is_empty() {
+        person.insert("knowsAbout".to_string(), json!(topics));
+    }
+    Value::Object(person)
+}
+
+/// The `Person` for a resolved resume, limited to the sections in its
+/// layout like the other exports.
+pub fn resume_json_ld(resume: &ResolvedResume) -> Value {
+    let has = |kind: SectionKind| resume.sections.iter().any(|s| s.kind == kind);
+    let summary = resume.summary.as_deref().filter(|_| has(SectionKind::Summary));
+    let experiences: &[Experience] = if has(SectionKind::Experience) { &resume.experiences } else { &[] };
+    let skills: Vec<&Skill> = if has(SectionKind::Skills) { resume.skills.iter().collect() } else { vec![] };
+    person(&resume.profile, summary, experiences, &skills)
+}
+
+/// Serializes JSON-LD for a `<script type="application/ld+json">` element:
+/// `<`, `>` and `&` are written as unicode escapes so no value can close
+/// the script.
+pub fn to_script(document: &Value) -> String {
+    document.to_string()
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
+
+fn role(experience: &Experience) -> Value {
+    let mut role = Map::new();
+    role.insert("@type".to_string(), json!("OrganizationRole"));
+    role.insert("roleName".to_string(), json!(experience.job_title));
+    role.insert("startDate".to_string(), json!(experience.start_date));
+    role.insert("endDate".to_string(), json!(experience.end_date));
+    let bullets: Vec<String> = experience.ordered_responsibilities().iter().map(|r| r.bullet()).collect();
+    if !bullets.is_empty() {
+        role.insert("description".to_string(), json!(bullets.join("\n")));
+    }
+    let mut organization = json!({ "@type": "Organization", "name": experience.company });
+    if !experience.location.is_empty() {
+        organization["location"] = json!({ "@type": "Place", "name": experience.location });
+    }
+    role.insert("worksFor".to_string(), organization);
+    Value::Object(role)
+}
+
+fn credential(education: &Education) -> Value {
+    let name: Vec<&str> = [education.study_type.as_deref(), education.area.as_deref()].into_iter().flatten().collect();
+    let mut credential = Map::new();
+    credential.insert("@type".to_string(), json!("EducationalOccupationalCredential"));
+    credential.insert(
+        "name".to_string(),
+        json!(if name.is_empty() { education.institution.clone() } else { name.join(", ") }),
+    );
+    insert(&mut credential, "credentialCategory", education.study_type.as_deref());
+    insert(&mut credential, "about", education.area.as_deref());
+    credential.insert(
+        "recognizedBy".to_string(),
+        json!({ "@type": "EducationalOrganization", "name": education.institution }),
+    );
+    // The date the credential was awarded
+    insert(&mut credential, "validFrom", education.end_date.as_deref());
+    Value::Object(credential)
+}
+
+// "City, Country", as across our models.
+fn address(location: &str) -> Value {
+    match location.split_once(',') {
+        Some((locality, country)) => json!({
+            "@type": "PostalAddress",
+            "addressLocality": locality.trim(),
+            "addressCountry": country.trim(),
+        }),
+        None => json!({ "@type": "PostalAddress", "addressLocality": location.trim() }),
+    }
+}
+
+fn insert(object: &mut Map<String, Value>, key: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        object.insert(key.to_string(), json!(value));
+    }
+}
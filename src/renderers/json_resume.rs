@@ -0,0 +1,256 @@
+use jsonschema::JSONSchema;
+use lazy_static::lazy_static;
+use serde::Serialize;
+use serde_json::Value;
+use crate::errors::AppError;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::group_by_category;
+
+pub const SCHEMA_URL: &str = "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+const SCHEMA_VERSION: &str = "v1.0.0";
+/// `fluency` written for a mother tongue.
+pub const NATIVE_FLUENCY: &str = "Native speaker";
+
+lazy_static! {
+    static ref SCHEMA: JSONSchema = {
+        let schema: Value = serde_json::from_str(include_str!("../../schemas/jsonresume.schema.json"))
+            .expect("bundled JSON Resume schema is valid JSON");
+        JSONSchema::compile(&schema).expect("bundled JSON Resume schema compiles")
+    };
+}
+
+/// Document following the jsonresume.org schema. Only the sections this
+/// API has data for are modelled.
+#[derive(Debug, Serialize, Default)]
+pub struct JsonResume {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub basics: Basics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<EducationEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<SkillGroup>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<LanguageEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<CertificateEntry>,
+    pub meta: Meta,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct Basics {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<SocialProfile>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SocialProfile {
+    pub network: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Work {
+    pub name: String,
+    pub position: String,
+    pub location: String,
+    pub start_date: String,
+    pub end_date: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EducationEntry {
+    pub institution: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkillGroup {
+    pub name: String,
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LanguageEntry {
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fluency: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CertificateEntry {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Meta {
+    pub version: String,
+    pub last_modified: String,
+}
+
+/// Maps a resolved resume onto the JSON Resume schema: experiences become
+/// `work`, responsibilities their `highlights`, and skills are grouped by
+/// category into `skills[].keywords`, and the profile's education, languages
+/// and certificates become `education`, `languages` and `certificates`, the
+/// fluency being the CEFR level or `Native speaker`. Sections left out of the layout are left out of the document.
+pub fn to_json_resume(resume: &ResolvedResume, last_modified: &str) -> JsonResume {
+    let has = |kind: SectionKind| resume.sections.iter().any(|s| s.kind == kind);
+    let profile = &resume.profile;
+
+    let basics = Basics {
+        name: profile.name.clone(),
+        label: profile.label.clone(),
+        email: profile.email.clone(),
+        phone: profile.phone.clone(),
+        url: profile.url.clone(),
+        summary: resume.summary.clone().filter(|_| has(SectionKind::Summary)),
+        location: profile.location.as_deref().map(split_location),
+        profiles: profile.links.iter()
+            .map(|link| SocialProfile {
+                network: link.network.clone(),
+                username: link.username.clone(),
+                url: link.url.clone(),
+            })
+            .collect(),
+    };
+
+    let work = if has(SectionKind::Experience) {
+        resume.experiences.iter()
+            .map(|experience| Work {
+                name: experience.company.clone(),
+                position: experience.job_title.clone(),
+                location: experience.location.clone(),
+                start_date: experience.start_date.clone(),
+                end_date: experience.end_date.clone(),
+                highlights: experience.responsibilities.iter().map(|r| r.bullet()).collect(),
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let skills = if has(SectionKind::Skills) {
+        group_by_category(&resume.skills)
+            .into_iter()
+            .map(|group| SkillGroup {
+                name: group.category,
+                keywords: group.skills.iter().map(|s| s.name.clone()).collect(),
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let education = profile.education.iter()
+        .map(|education| EducationEntry {
+            institution: education.institution.clone(),
+            area: education.area.clone(),
+            study_type: education.study_type.clone(),
+            start_date: education.start_date.clone(),
+            end_date: education.end_date.clone(),
+            score: education.score.clone(),
+        })
+        .collect();
+
+    let languages = profile.languages.iter()
+        .map(|language| LanguageEntry {
+            language: language.name.clone(),
+            fluency: if language.native { Some(NATIVE_FLUENCY.to_string()) } else { language.level.clone() },
+        })
+        .collect();
+
+    let certificates = profile.certificates.iter()
+        .map(|certificate| CertificateEntry {
+            name: certificate.name.clone(),
+            issuer: certificate.issuer.clone(),
+            date: certificate.date.clone(),
+            url: certificate.url.clone(),
+        })
+        .collect();
+
+    JsonResume {
+        schema: SCHEMA_URL.to_string(),
+        basics,
+        work,
+        education,
+        skills,
+        languages,
+        certificates,
+        meta: Meta {
+            version: SCHEMA_VERSION.to_string(),
+            last_modified: last_modified.to_string(),
+        },
+    }
+}
+
+/// Builds the document and checks it against the bundled schema.
+pub fn export_json_resume(resume: &ResolvedResume, last_modified: &str) -> Result<Value, AppError> {
+    let document = serde_json::to_value(to_json_resume(resume, last_modified))?;
+    validate_json_resume(&document).map_err(|errors| AppError::InternalServerError(format!(
+        "Generated JSON Resume does not match the schema: {}", errors.join("; ")
+    )))?;
+    Ok(document)
+}
+
+/// Validates a document against the bundled JSON Resume schema, returning
+/// one message per violation prefixed with its JSON pointer.
+pub fn validate_json_resume(document: &Value) -> Result<(), Vec<String>> {
+    SCHEMA.validate(document).map_err(|errors| {
+        errors.map(|error| format!("{}: {}", error.instance_path, error)).collect()
+    })
+}
+
+fn split_location(location: &str) -> Location {
+    match location.split_once(',') {
+        Some((city, region)) => Location {
+            city: Some(city.trim().to_string()),
+            region: Some(region.trim().to_string()).filter(|r| !r.is_empty()),
+        },
+        None => Location { city: Some(location.trim().to_string()), region: None },
+    }
+}
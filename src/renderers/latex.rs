@@ -0,0 +1,305 @@
+use std::str::FromStr;
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::models::skill::Skill;
+use crate::renderers::{check_date_format, format_date, group_by_category, DEFAULT_DATE_FORMAT};
+
+// Networks with a dedicated moderncv `\social` command.
+const MODERNCV_NETWORKS: &[&str] = &["linkedin", "github", "gitlab", "twitter", "stackoverflow", "orcid", "researchgate"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatexLayout {
+    /// `moderncv` document class, classic style.
+    #[default]
+    Moderncv,
+    /// Standard `article` class, for installations without moderncv.
+    Article,
+}
+
+impl LatexLayout {
+    pub const SUPPORTED: &'static [&'static str] = &["moderncv", "article"];
+}
+
+impl FromStr for LatexLayout {
+    type Err = AppError;
+
+    fn from_str(layout: &str) -> Result<Self, Self::Err> {
+        match layout.to_ascii_lowercase().as_str() {
+            "moderncv" => Ok(LatexLayout::Moderncv),
+            "article" | "plain" => Ok(LatexLayout::Article),
+            other => Err(AppError::ValidationError(format!(
+                "Unsupported LaTeX layout '{}'. Supported layouts: {}",
+                other,
+                LatexLayout::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+/// Options for [`render_latex`].
+#[derive(Debug, Clone)]
+pub struct LatexOptions {
+    pub layout: LatexLayout,
+    /// strftime pattern used for experience dates.
+    pub date_format: String,
+}
+
+impl Default for LatexOptions {
+    fn default() -> Self {
+        LatexOptions { layout: LatexLayout::default(), date_format: DEFAULT_DATE_FORMAT.to_string() }
+    }
+}
+
+impl LatexOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        check_date_format(&self.date_format)
+    }
+}
+
+/// Renders a resolved resume as a standalone `.tex` document. Only packages
+/// shipped with TeX Live are used, so the output compiles offline with
+/// `pdflatex`.
+pub fn render_latex(resume: &ResolvedResume, options: &LatexOptions) -> String {
+    match options.layout {
+        LatexLayout::Moderncv => moderncv(resume, &options.date_format),
+        LatexLayout::Article => article(resume, &options.date_format),
+    }
+}
+
+/// Escapes text for use in a LaTeX document body.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.split_whitespace().collect::<Vec<_>>().join(" ").chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '<' => escaped.push_str("\\textless{}"),
+            '>' => escaped.push_str("\\textgreater{}"),
+            '|' => escaped.push_str("\\textbar{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// URLs go through \href and \url verbatim, except for the characters that
+// still end or break the argument.
+fn escape_url(url: &str) -> String {
+    url.replace('\\', "%5C")
+        .replace('{', "%7B")
+        .replace('}', "%7D")
+        .replace('%', "\\%")
+        .replace('#', "\\#")
+}
+
+fn moderncv(resume: &ResolvedResume, date_format: &str) -> String {
+    let profile = &resume.profile;
+    let mut out = String::from(
+        "\\documentclass[11pt,a4paper,sans]{moderncv}\n\
+         \\moderncvstyle{classic}\n\
+         \\moderncvcolor{blue}\n\
+         \\usepackage[utf8]{inputenc}\n\
+         \\usepackage[T1]{fontenc}\n\
+         \\usepackage[scale=0.8]{geometry}\n\n",
+    );
+
+    let (first, last) = split_name(&profile.name);
+    out.push_str(&format!("\\name{{{}}}{{{}}}\n", escape(first), escape(last)));
+    if let Some(label) = &profile.label {
+        out.push_str(&format!("\\title{{{}}}\n", escape(label)));
+    }
+    if let Some(location) = &profile.location {
+        out.push_str(&format!("\\address{{{}}}{{}}{{}}\n", escape(location)));
+    }
+    if let Some(phone) = &profile.phone {
+        out.push_str(&format!("\\phone[mobile]{{{}}}\n", escape(phone)));
+    }
+    if let Some(email) = &profile.email {
+        out.push_str(&format!("\\email{{{}}}\n", escape(email)));
+    }
+    if let Some(url) = &profile.url {
+        out.push_str(&format!("\\homepage{{{}}}\n", escape_url(strip_scheme(url))));
+    }
+    for link in &profile.links {
+        let network = link.network.to_ascii_lowercase();
+        match &link.username {
+            Some(username) if MODERNCV_NETWORKS.contains(&network.as_str()) => {
+                out.push_str(&format!("\\social[{}]{{{}}}\n", network, escape(username)));
+            }
+            _ => out.push_str(&format!(
+                "\\extrainfo{{\\href{{{}}}{{{}}}}}\n",
+                escape_url(&link.url),
+                escape(&link.network)
+            )),
+        }
+    }
+
+    out.push_str("\n\\begin{document}\n\\makecvtitle\n");
+    for section in &resume.sections {
+        match section.kind {
+            SectionKind::Summary => {
+                if let Some(summary) = &resume.summary {
+                    out.push_str(&format!("\n\\section{{{}}}\n{}\n", escape(section.heading()), paragraphs(summary)));
+                }
+            }
+            SectionKind::Experience => {
+                if !resume.experiences.is_empty() {
+                    out.push_str(&format!("\n\\section{{{}}}\n", escape(section.heading())));
+                    for experience in &resume.experiences {
+                        out.push_str(&format!(
+                            "\\cventry{{{}}}{{{}}}{{{}}}{{{}}}{{}}{{{}}}\n",
+                            period(experience, date_format),
+                            escape(&experience.job_title),
+                            escape(&experience.company),
+                            escape(&experience.location),
+                            entry_body(experience)
+                        ));
+                    }
+                }
+            }
+            SectionKind::Skills => {
+                if !resume.skills.is_empty() {
+                    out.push_str(&format!("\n\\section{{{}}}\n", escape(section.heading())));
+                    for group in group_by_category(&resume.skills) {
+                        out.push_str(&format!("\\cvitem{{{}}}{{{}}}\n", escape(&group.category), skill_list(&group.skills)));
+                    }
+                }
+            }
+        }
+    }
+    out.push_str("\n\\end{document}\n");
+    out
+}
+
+fn article(resume: &ResolvedResume, date_format: &str) -> String {
+    let profile = &resume.profile;
+    let mut out = String::from(
+        "\\documentclass[11pt,a4paper]{article}\n\
+         \\usepackage[utf8]{inputenc}\n\
+         \\usepackage[T1]{fontenc}\n\
+         \\usepackage[margin=2cm]{geometry}\n\
+         \\usepackage{enumitem}\n\
+         \\usepackage[hidelinks]{hyperref}\n\
+         \\pagestyle{empty}\n\
+         \\setlength{\\parindent}{0pt}\n\n\
+         \\begin{document}\n\n",
+    );
+
+    out.push_str(&format!("\\begin{{center}}\n{{\\LARGE\\bfseries {}}}\\\\[4pt]\n", escape(&profile.name)));
+    if let Some(label) = &profile.label {
+        out.push_str(&format!("{}\\\\[2pt]\n", escape(label)));
+    }
+    out.push_str(&format!("\\small {}\n\\end{{center}}\n", contact_line(profile)));
+
+    for section in &resume.sections {
+        match section.kind {
+            SectionKind::Summary => {
+                if let Some(summary) = &resume.summary {
+                    out.push_str(&format!("\n\\section*{{{}}}\n{}\n", escape(section.heading()), paragraphs(summary)));
+                }
+            }
+            SectionKind::Experience => {
+                if !resume.experiences.is_empty() {
+                    out.push_str(&format!("\n\\section*{{{}}}\n", escape(section.heading())));
+                    for experience in &resume.experiences {
+                        out.push_str(&format!(
+                            "\n\\subsection*{{{}, {} \\hfill {{\\normalfont\\small {}}}}}\n\\textit{{{}}}\n{}\n",
+                            escape(&experience.job_title),
+                            escape(&experience.company),
+                            period(experience, date_format),
+                            escape(&experience.location),
+                            entry_body(experience)
+                        ));
+                    }
+                }
+            }
+            SectionKind::Skills => {
+                if !resume.skills.is_empty() {
+                    out.push_str(&format!("\n\\section*{{{}}}\n\\begin{{description}}[leftmargin=3cm,style=sameline]\n", escape(section.heading())));
+                    for group in group_by_category(&resume.skills) {
+                        out.push_str(&format!("\\item[{{{}}}] {}\n", escape(&group.category), skill_list(&group.skills)));
+                    }
+                    out.push_str("\\end{description}\n");
+                }
+            }
+        }
+    }
+    out.push_str("\n\\end{document}\n");
+    out
+}
+
+fn contact_line(profile: &Profile) -> String {
+    let mut items: Vec<String> = Vec::new();
+    if let Some(email) = &profile.email {
+        items.push(format!("\\href{{mailto:{}}}{{{}}}", escape_url(email), escape(email)));
+    }
+    if let Some(phone) = &profile.phone {
+        items.push(escape(phone));
+    }
+    if let Some(url) = &profile.url {
+        items.push(format!("\\href{{{}}}{{{}}}", escape_url(url), escape(strip_scheme(url))));
+    }
+    if let Some(location) = &profile.location {
+        items.push(escape(location));
+    }
+    items.extend(profile.links.iter().map(|link| format!("\\href{{{}}}{{{}}}", escape_url(&link.url), escape(&link.network))));
+    items.join(" \\textperiodcentered{} ")
+}
+
+// Responsibilities as a list, followed by the environment.
+fn entry_body(experience: &Experience) -> String {
+    let mut body = String::new();
+    if !experience.responsibilities.is_empty() {
+        body.push_str("\\begin{itemize}\n");
+        for responsibility in &experience.responsibilities {
+            body.push_str(&format!("\\item {}\n", escape(&responsibility.bullet())));
+        }
+        body.push_str("\\end{itemize}");
+    }
+    if !experience.environment.is_empty() {
+        let names: Vec<String> = experience.environment.iter().map(|s| escape(&s.name)).collect();
+        body.push_str(&format!("\n\\textit{{Environment:}} {}", names.join(", ")));
+    }
+    body
+}
+
+fn period(experience: &Experience, date_format: &str) -> String {
+    format!(
+        "{}--{}",
+        escape(&format_date(&experience.start_date, date_format)),
+        escape(&format_date(&experience.end_date, date_format))
+    )
+}
+
+fn paragraphs(text: &str) -> String {
+    text.split("\n\n")
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(escape)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn skill_list(skills: &[&Skill]) -> String {
+    skills.iter().map(|s| escape(&s.name)).collect::<Vec<_>>().join(", ")
+}
+
+// moderncv takes first and last name separately; everything before the last
+// word is the first name.
+pub(crate) fn split_name(name: &str) -> (&str, &str) {
+    match name.trim().rsplit_once(' ') {
+        Some((first, last)) => (first.trim(), last),
+        None => ("", name.trim()),
+    }
+}
+
+fn strip_scheme(url: &str) -> &str {
+    url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")).unwrap_or(url)
+}
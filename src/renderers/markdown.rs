@@ -0,0 +1,174 @@
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::{check_date_format, format_period, group_by_category, DEFAULT_DATE_FORMAT};
+
+/// Options for [`render_markdown`].
+#[derive(Debug, Clone)]
+pub struct MarkdownOptions {
+    /// Level of the name heading; sections and entries use the next two.
+    pub heading_level: u8,
+    /// strftime pattern used for experience dates.
+    pub date_format: String,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        MarkdownOptions { heading_level: 1, date_format: DEFAULT_DATE_FORMAT.to_string() }
+    }
+}
+
+impl MarkdownOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !(1..=4).contains(&self.heading_level) {
+            return Err(AppError::ValidationError("Heading level must be between 1 and 4".to_string()));
+        }
+        check_date_format(&self.date_format)
+    }
+}
+
+/// Renders a resolved resume as CommonMark, following the resume's section
+/// layout.
+pub fn render_markdown(resume: &ResolvedResume, options: &MarkdownOptions) -> String {
+    let level = options.heading_level as usize;
+    let profile = &resume.profile;
+    let mut out = String::new();
+
+    heading(&mut out, level, &escape(&profile.name));
+    if let Some(label) = &profile.label {
+        paragraph(&mut out, &format!("**{}**", escape(label)));
+    }
+
+    let mut contact: Vec<String> = Vec::new();
+    if let Some(email) = &profile.email {
+        contact.push(format!("[{}](mailto:{})", escape(email), link_target(email)));
+    }
+    if let Some(phone) = &profile.phone {
+        contact.push(escape(phone));
+    }
+    if let Some(url) = &profile.url {
+        contact.push(format!("<{}>", link_target(url)));
+    }
+    if let Some(location) = &profile.location {
+        contact.push(escape(location));
+    }
+    contact.extend(profile.links.iter().map(|link| format!("[{}]({})", escape(&link.network), link_target(&link.url))));
+    if !contact.is_empty() {
+        paragraph(&mut out, &contact.join(" · "));
+    }
+
+    for section in &resume.sections {
+        match section.kind {
+            SectionKind::Summary => {
+                if let Some(summary) = &resume.summary {
+                    heading(&mut out, level + 1, &escape(section.heading()));
+                    for text in summary.split("\n\n").map(str::trim).filter(|t| !t.is_empty()) {
+                        paragraph(&mut out, &escape_block(text));
+                    }
+                }
+            }
+            SectionKind::Experience => {
+                if !resume.experiences.is_empty() {
+                    heading(&mut out, level + 1, &escape(section.heading()));
+                    for experience in &resume.experiences {
+                        render_experience_into(&mut out, experience, level + 2, &options.date_format);
+                    }
+                }
+            }
+            SectionKind::Skills => {
+                if !resume.skills.is_empty() {
+                    heading(&mut out, level + 1, &escape(section.heading()));
+                    for group in group_by_category(&resume.skills) {
+                        let names: Vec<String> = group.skills.iter().map(|s| escape(&s.name)).collect();
+                        out.push_str(&format!("- **{}:** {}\n", escape(&group.category), names.join(", ")));
+                    }
+                    out.push('\n');
+                }
+            }
+        }
+    }
+
+    format!("{}\n", out.trim_end())
+}
+
+/// Renders a single experience with its heading at `level`.
+pub fn render_experience(experience: &Experience, level: u8, date_format: &str) -> String {
+    let mut out = String::new();
+    render_experience_into(&mut out, experience, level as usize, date_format);
+    format!("{}\n", out.trim_end())
+}
+
+fn render_experience_into(out: &mut String, experience: &Experience, level: usize, date_format: &str) {
+    heading(out, level, &format!("{} — {}", escape(&experience.job_title), escape(&experience.company)));
+    paragraph(out, &format!(
+        "*{} · {}*",
+        escape(&experience.location),
+        format_period(&experience.start_date, &experience.end_date, date_format)
+    ));
+    if !experience.responsibilities.is_empty() {
+        for responsibility in &experience.responsibilities {
+            out.push_str(&format!("- {}\n", escape(&responsibility.bullet())));
+        }
+        out.push('\n');
+    }
+    if !experience.environment.is_empty() {
+        let names: Vec<String> = experience.environment.iter().map(|s| escape(&s.name)).collect();
+        paragraph(out, &format!("*Environment:* {}", names.join(", ")));
+    }
+}
+
+fn heading(out: &mut String, level: usize, text: &str) {
+    out.push_str(&format!("{} {}\n\n", "#".repeat(level.min(6)), text));
+}
+
+fn paragraph(out: &mut String, text: &str) {
+    out.push_str(text);
+    out.push_str("\n\n");
+}
+
+/// Escapes characters with inline meaning in CommonMark.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.split_whitespace().collect::<Vec<_>>().join(" ").chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// Percent-encodes the characters that would end a link destination or
+// autolink early, so that a URL cannot break out of its link.
+fn link_target(url: &str) -> String {
+    let mut encoded = String::with_capacity(url.len());
+    for c in url.trim().chars() {
+        if c.is_whitespace() || c.is_control() || matches!(c, '<' | '>' | '(' | ')' | '\\') {
+            let mut bytes = [0; 4];
+            for byte in c.encode_utf8(&mut bytes).bytes() {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            encoded.push(c);
+        }
+    }
+    encoded
+}
+
+// Also escapes text that would start a list, quote or heading when it
+// begins a paragraph.
+fn escape_block(text: &str) -> String {
+    let escaped = escape(text);
+    let digits = escaped.chars().take_while(|c| c.is_ascii_digit()).count();
+    let marker = escaped[digits..].chars().next();
+    let starts_block = match marker {
+        Some('.') | Some(')') => digits > 0,
+        Some('-') | Some('+') | Some('=') => digits == 0,
+        _ => false,
+    };
+    if starts_block {
+        format!("{}\\{}", &escaped[..digits], &escaped[digits..])
+    } else {
+        escaped
+    }
+}
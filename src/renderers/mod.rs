@@ -0,0 +1,175 @@
+pub mod docx;
+pub mod europass;
+pub mod experience_csv;
+pub mod html;
+pub mod json_ld;
+pub mod json_resume;
+pub mod latex;
+pub mod markdown;
+pub mod negotiation;
+pub mod pdf;
+pub mod qr;
+pub mod site;
+pub mod template;
+pub mod text;
+pub mod vcard;
+pub mod yaml;
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use chrono::NaiveDate;
+use chrono::format::{Item, StrftimeItems};
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::resume::{ResolvedResume, ResumeSection, SectionKind};
+use crate::models::skill::Skill;
+
+/// Heading used for skills that have no category.
+pub const UNCATEGORIZED: &str = "Other";
+
+/// Date format used by renderers unless the caller picks another one.
+pub const DEFAULT_DATE_FORMAT: &str = "%b %Y";
+
+/// Output formats offered by `GET /api/resumes/{id}/export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    JsonResume,
+    Markdown,
+    Pdf,
+    Latex,
+    Docx,
+    Text,
+    Europass,
+}
+
+impl ExportFormat {
+    pub const SUPPORTED: &'static [&'static str] = &["jsonresume", "markdown", "pdf", "latex", "docx", "text", "europass"];
+}
+
+impl FromStr for ExportFormat {
+    type Err = AppError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_ascii_lowercase().as_str() {
+            "jsonresume" | "json-resume" => Ok(ExportFormat::JsonResume),
+            "markdown" | "md" => Ok(ExportFormat::Markdown),
+            "pdf" => Ok(ExportFormat::Pdf),
+            "latex" | "tex" => Ok(ExportFormat::Latex),
+            "docx" | "word" => Ok(ExportFormat::Docx),
+            "text" | "txt" | "ats" => Ok(ExportFormat::Text),
+            "europass" => Ok(ExportFormat::Europass),
+            other => Err(AppError::ValidationError(format!(
+                "Unsupported export format '{}'. Supported formats: {}",
+                other,
+                ExportFormat::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+/// File name for a downloaded export, built from the profile and resume
+/// names, e.g. `ada-lovelace-backend.pdf`.
+pub fn file_name(resume: &ResolvedResume, extension: &str) -> String {
+    let stem = slug(&format!("{} {}", resume.profile.name, resume.name));
+    format!("{}.{}", if stem.is_empty() { "resume" } else { &stem }, extension)
+}
+
+/// A copy of a stored experience with its responsibilities in the order
+/// given by their `next` links, which renderers list as they come.
+pub fn ordered_experience(experience: &Experience) -> Experience {
+    Experience { responsibilities: experience.ordered_responsibilities(), ..experience.clone() }
+}
+
+/// A one-section document holding a single experience, titled with its job
+/// title and company, for renderers that only take whole resumes.
+pub fn experience_resume(experience: &Experience) -> ResolvedResume {
+    let title = format!("{} at {}", experience.job_title, experience.company);
+    ResolvedResume {
+        resume_id: None,
+        name: title.clone(),
+        profile: Profile { name: title, ..Profile::default() },
+        summary: None,
+        sections: vec![ResumeSection::new(SectionKind::Experience)],
+        experiences: vec![ordered_experience(experience)],
+        skills: vec![],
+    }
+}
+
+/// Lower-case ASCII letters and digits of `text`, with every other run of
+/// characters replaced by a single dash, e.g. `ada-lovelace`.
+pub fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Skills sharing a category, in the order they appear on the resume.
+#[derive(Debug)]
+pub struct CategoryGroup<'a> {
+    pub category: String,
+    pub skills: Vec<&'a Skill>,
+}
+
+/// Groups skills by category name, keeping the first-seen order of both
+/// categories and skills. A skill with several categories is listed under
+/// each of them.
+pub fn group_by_category(skills: &[Skill]) -> Vec<CategoryGroup<'_>> {
+    let mut groups: Vec<CategoryGroup> = Vec::new();
+    for skill in skills {
+        let categories: Vec<&str> = if skill.categories.is_empty() {
+            vec![UNCATEGORIZED]
+        } else {
+            skill.categories.iter().map(|c| c.name.as_str()).collect()
+        };
+        for category in categories {
+            match groups.iter_mut().find(|g| g.category == category) {
+                Some(group) => group.skills.push(skill),
+                None => groups.push(CategoryGroup { category: category.to_string(), skills: vec![skill] }),
+            }
+        }
+    }
+    groups
+}
+
+/// Checks that `format` is a valid chrono strftime pattern for a date, so
+/// rendering dates with it cannot fail. Time of day (`%H`, `%M`, `%p`) and
+/// offset (`%z`) specifiers are rejected: a date has neither.
+pub fn check_date_format(format: &str) -> Result<(), AppError> {
+    let invalid = || AppError::ValidationError(format!("Invalid date format '{}'", format));
+    if format.is_empty() || StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(invalid());
+    }
+    let sample = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    write_date(sample, format).map_err(|_| invalid())?;
+    Ok(())
+}
+
+/// Formats a `YYYY-MM-DD` date with a strftime pattern already checked by
+/// [`check_date_format`]. Dates that do not parse, or that the pattern
+/// cannot format, are returned unchanged.
+pub fn format_date(date: &str, format: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|parsed| write_date(parsed, format).ok())
+        .unwrap_or_else(|| date.to_string())
+}
+
+// Unlike `to_string`, which panics, reports a pattern the date cannot
+// satisfy as an error.
+fn write_date(date: NaiveDate, format: &str) -> Result<String, fmt::Error> {
+    let mut formatted = String::new();
+    write!(formatted, "{}", date.format(format))?;
+    Ok(formatted)
+}
+
+/// Start and end date joined by an en dash, e.g. `Mar 2021 – Feb 2024`.
+pub fn format_period(start: &str, end: &str, format: &str) -> String {
+    format!("{} – {}", format_date(start, format), format_date(end, format))
+}
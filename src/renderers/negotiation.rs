@@ -0,0 +1,110 @@
+use crate::errors::AppError;
+
+/// Representations offered through the `Accept` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    Json,
+    Yaml,
+    Markdown,
+    Text,
+    Pdf,
+}
+
+impl MediaType {
+    /// Every negotiable type, JSON first as the default.
+    pub const ALL: &'static [MediaType] = &[MediaType::Json, MediaType::Yaml, MediaType::Markdown, MediaType::Text, MediaType::Pdf];
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            MediaType::Json => "application/json",
+            MediaType::Yaml => "application/yaml",
+            MediaType::Markdown => "text/markdown",
+            MediaType::Text => "text/plain",
+            MediaType::Pdf => "application/pdf",
+        }
+    }
+
+    // Other names clients use for the same type.
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            MediaType::Yaml => &["application/x-yaml", "text/yaml", "text/x-yaml"],
+            MediaType::Markdown => &["text/x-markdown"],
+            _ => &[],
+        }
+    }
+}
+
+/// Picks the representation of `offered` that best fits an `Accept`
+/// header: the highest quality value wins, a specific type taking its
+/// quality over a `type/*` or `*/*` range, and ties go to the order of
+/// `offered`. No header, or an empty one, means the first offered type.
+/// Returns [`AppError::NotAcceptable`] listing the offered types when none
+/// is acceptable.
+pub fn negotiate(accept: Option<&str>, offered: &[MediaType]) -> Result<MediaType, AppError> {
+    let ranges: Vec<(String, f32)> = accept.unwrap_or_default()
+        .split(',')
+        .filter_map(media_range)
+        .collect();
+    if ranges.is_empty() {
+        if let Some(first) = offered.first() {
+            return Ok(*first);
+        }
+    }
+
+    let mut best: Option<(MediaType, f32)> = None;
+    for media in offered {
+        let quality = quality(*media, &ranges);
+        if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+            best = Some((*media, quality));
+        }
+    }
+    best.map(|(media, _)| media).ok_or_else(|| {
+        let supported: Vec<&str> = offered.iter().map(MediaType::mime_type).collect();
+        AppError::NotAcceptable(format!(
+            "No representation matches '{}'. Supported types: {}",
+            accept.unwrap_or_default(),
+            supported.join(", ")
+        ))
+    })
+}
+
+// `type/subtype;q=0.5` as the lower-cased range and its quality; ranges
+// with an unreadable quality are ignored.
+fn media_range(range: &str) -> Option<(String, f32)> {
+    let mut parts = range.split(';');
+    let name = parts.next()?.trim().to_ascii_lowercase();
+    if !name.contains('/') {
+        return None;
+    }
+    let mut quality = 1.0;
+    for parameter in parts {
+        if let Some((key, value)) = parameter.split_once('=') {
+            if key.trim().eq_ignore_ascii_case("q") {
+                quality = value.trim().parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q))?;
+            }
+        }
+    }
+    Some((name, quality))
+}
+
+// Quality of the most specific range matching the type; 0 when none does.
+// Aliases only match exactly, so `text/*` does not take in YAML.
+fn quality(media: MediaType, ranges: &[(String, f32)]) -> f32 {
+    let names: Vec<&str> = std::iter::once(media.mime_type()).chain(media.aliases().iter().copied()).collect();
+    let mut best: Option<(u8, f32)> = None;
+    for (range, quality) in ranges {
+        let specificity = if names.contains(&range.as_str()) {
+            2
+        } else if range.strip_suffix("/*").is_some_and(|kind| media.mime_type().split('/').next() == Some(kind)) {
+            1
+        } else if range == "*/*" {
+            0
+        } else {
+            continue;
+        };
+        if best.is_none_or(|(s, _)| specificity > s) {
+            best = Some((specificity, *quality));
+        }
+    }
+    best.map(|(_, quality)| quality).unwrap_or(0.0)
+}
@@ -0,0 +1,441 @@
+use std::io::Cursor;
+use std::str::FromStr;
+use lazy_static::lazy_static;
+use printpdf::{Actions, BorderArray, IndirectFontRef, LinkAnnotation, Mm, PdfDocument, Rect};
+use ttf_parser::Face;
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::{check_date_format, format_period, group_by_category, DEFAULT_DATE_FORMAT};
+
+const REGULAR_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const BOLD_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+lazy_static! {
+    static ref REGULAR: Face<'static> = Face::parse(REGULAR_FONT, 0).expect("bundled regular font parses");
+    static ref BOLD: Face<'static> = Face::parse(BOLD_FONT, 0).expect("bundled bold font parses");
+}
+
+const PT_TO_MM: f32 = 0.352_778;
+const MARGIN: f32 = 18.0;
+const LINE_SPACING: f32 = 1.35;
+const BULLET_INDENT: f32 = 5.0;
+const SKILL_COLUMN: f32 = 38.0;
+// Bullets that must follow an experience header on the same page.
+const KEEP_WITH_HEADER: usize = 2;
+
+const NAME_SIZE: f32 = 20.0;
+const LABEL_SIZE: f32 = 12.0;
+const HEADING_SIZE: f32 = 12.0;
+const ENTRY_SIZE: f32 = 11.0;
+const BODY_SIZE: f32 = 10.0;
+const DETAIL_SIZE: f32 = 9.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PageSize {
+    pub const SUPPORTED: &'static [&'static str] = &["a4", "letter"];
+
+    /// Width and height in millimetres.
+    pub fn dimensions(&self) -> (f32, f32) {
+        match self {
+            PageSize::A4 => (210.0, 297.0),
+            PageSize::Letter => (215.9, 279.4),
+        }
+    }
+}
+
+impl FromStr for PageSize {
+    type Err = AppError;
+
+    fn from_str(size: &str) -> Result<Self, Self::Err> {
+        match size.to_ascii_lowercase().as_str() {
+            "a4" => Ok(PageSize::A4),
+            "letter" => Ok(PageSize::Letter),
+            other => Err(AppError::ValidationError(format!(
+                "Unsupported page size '{}'. Supported sizes: {}",
+                other,
+                PageSize::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+/// Options for [`render_pdf`].
+#[derive(Debug, Clone)]
+pub struct PdfOptions {
+    pub page_size: PageSize,
+    /// strftime pattern used for experience dates.
+    pub date_format: String,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        PdfOptions { page_size: PageSize::default(), date_format: DEFAULT_DATE_FORMAT.to_string() }
+    }
+}
+
+impl PdfOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        check_date_format(&self.date_format)
+    }
+}
+
+/// Something placed on a page. Coordinates are in millimetres from the
+/// top-left corner; `y` is the text baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element {
+    Text { x: f32, y: f32, size: f32, bold: bool, text: String },
+    Link { x: f32, y: f32, width: f32, height: f32, uri: String },
+    Rule { x: f32, y: f32, width: f32 },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PageLayout {
+    pub elements: Vec<Element>,
+}
+
+impl PageLayout {
+    /// Text of the page, one element per line, for inspection.
+    pub fn lines(&self) -> Vec<&str> {
+        self.elements
+            .iter()
+            .filter_map(|element| match element {
+                Element::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Lays the resume out on pages without producing a PDF. Text is wrapped to
+/// the page width and an experience header never ends a page: it moves to
+/// the next page together with its first bullets.
+pub fn layout_pdf(resume: &ResolvedResume, options: &PdfOptions) -> Vec<PageLayout> {
+    let mut writer = Writer::new(options.page_size);
+    let profile = &resume.profile;
+
+    writer.text_block(&profile.name, NAME_SIZE, true, 0.0);
+    if let Some(label) = &profile.label {
+        writer.text_block(label, LABEL_SIZE, false, 0.0);
+    }
+
+    let mut contact: Vec<(String, Option<String>)> = Vec::new();
+    if let Some(email) = &profile.email {
+        contact.push((email.clone(), Some(format!("mailto:{}", email))));
+    }
+    if let Some(phone) = &profile.phone {
+        contact.push((phone.clone(), None));
+    }
+    if let Some(url) = &profile.url {
+        contact.push((url.clone(), Some(url.clone())));
+    }
+    if let Some(location) = &profile.location {
+        contact.push((location.clone(), None));
+    }
+    contact.extend(profile.links.iter().map(|link| (link.network.clone(), Some(link.url.clone()))));
+    writer.inline_items(&contact, DETAIL_SIZE);
+
+    for section in &resume.sections {
+        match section.kind {
+            SectionKind::Summary => {
+                if let Some(summary) = &resume.summary {
+                    let paragraphs: Vec<&str> = summary.split("\n\n").map(str::trim).filter(|p| !p.is_empty()).collect();
+                    let first = paragraphs.first().map(|p| writer.text_height(p, BODY_SIZE, false, 0.0)).unwrap_or(0.0);
+                    writer.heading(section.heading(), first);
+                    for paragraph in paragraphs {
+                        writer.text_block(paragraph, BODY_SIZE, false, 0.0);
+                        writer.gap(1.5);
+                    }
+                }
+            }
+            SectionKind::Experience => {
+                if let Some(first) = resume.experiences.first() {
+                    let keep = experience_head_height(&writer, first, &options.date_format);
+                    writer.heading(section.heading(), keep);
+                    for experience in &resume.experiences {
+                        writer.experience(experience, &options.date_format);
+                    }
+                }
+            }
+            SectionKind::Skills => {
+                let groups = group_by_category(&resume.skills);
+                if !groups.is_empty() {
+                    writer.heading(section.heading(), line_height(BODY_SIZE));
+                    for group in groups {
+                        let names: Vec<&str> = group.skills.iter().map(|s| s.name.as_str()).collect();
+                        writer.labelled_row(&group.category, &names.join(", "), BODY_SIZE);
+                    }
+                }
+            }
+        }
+    }
+    writer.finish()
+}
+
+/// Renders a resolved resume as a PDF with embedded fonts, clickable
+/// contact links and document metadata.
+pub fn render_pdf(resume: &ResolvedResume, options: &PdfOptions) -> Result<Vec<u8>, AppError> {
+    let (width, height) = options.page_size.dimensions();
+    let profile = &resume.profile;
+    let title = format!("{} – {}", profile.name, resume.name);
+    let keywords: Vec<String> = resume.skills.iter().map(|s| s.name.clone()).collect();
+
+    let (document, page, layer) = PdfDocument::new(&title, Mm(width), Mm(height), "Layer 1");
+    let document = document
+        .with_author(&profile.name)
+        .with_subject(profile.label.clone().unwrap_or_else(|| resume.name.clone()))
+        .with_keywords(keywords)
+        .with_creator("rust-server")
+        .with_producer("rust-server");
+
+    let regular = add_font(&document, REGULAR_FONT)?;
+    let bold = add_font(&document, BOLD_FONT)?;
+
+    for (index, layout) in layout_pdf(resume, options).iter().enumerate() {
+        let layer = if index == 0 {
+            document.get_page(page).get_layer(layer)
+        } else {
+            let (page, layer) = document.add_page(Mm(width), Mm(height), "Layer 1");
+            document.get_page(page).get_layer(layer)
+        };
+        for element in &layout.elements {
+            match element {
+                Element::Text { x, y, size, bold: is_bold, text } => {
+                    let font = if *is_bold { &bold } else { &regular };
+                    layer.use_text(text.as_str(), *size, Mm(*x), Mm(height - y), font);
+                }
+                Element::Link { x, y, width: w, height: h, uri } => {
+                    layer.add_link_annotation(LinkAnnotation::new(
+                        Rect::new(Mm(*x), Mm(height - y - h), Mm(x + w), Mm(height - y)),
+                        Some(BorderArray::Solid([0.0, 0.0, 0.0])),
+                        None,
+                        Actions::uri(uri.clone()),
+                        None,
+                    ));
+                }
+                Element::Rule { x, y, width: w } => {
+                    layer.add_rect(Rect::new(Mm(*x), Mm(height - y - 0.2), Mm(x + w), Mm(height - y)));
+                }
+            }
+        }
+    }
+
+    document.save_to_bytes()
+        .map_err(|err| AppError::InternalServerError(format!("Failed to write PDF: {}", err)))
+}
+
+fn add_font(document: &printpdf::PdfDocumentReference, data: &[u8]) -> Result<IndirectFontRef, AppError> {
+    document.add_external_font(Cursor::new(data))
+        .map_err(|err| AppError::InternalServerError(format!("Failed to embed font: {}", err)))
+}
+
+// Height of an experience header plus the bullets kept with it.
+fn experience_head_height(writer: &Writer, experience: &Experience, date_format: &str) -> f32 {
+    let mut height = writer.text_height(&entry_title(experience), ENTRY_SIZE, true, 0.0)
+        + writer.text_height(&entry_meta(experience, date_format), DETAIL_SIZE, false, 0.0);
+    for responsibility in experience.responsibilities.iter().take(KEEP_WITH_HEADER) {
+        height += writer.text_height(&responsibility.bullet(), BODY_SIZE, false, BULLET_INDENT);
+    }
+    height
+}
+
+fn entry_title(experience: &Experience) -> String {
+    format!("{} — {}", experience.job_title, experience.company)
+}
+
+fn entry_meta(experience: &Experience, date_format: &str) -> String {
+    format!("{} · {}", experience.location, format_period(&experience.start_date, &experience.end_date, date_format))
+}
+
+fn line_height(size: f32) -> f32 {
+    size * LINE_SPACING * PT_TO_MM
+}
+
+/// Width of `text` in millimetres.
+fn text_width(text: &str, size: f32, bold: bool) -> f32 {
+    let face: &Face = if bold { &BOLD } else { &REGULAR };
+    let fallback = face.glyph_index(' ').and_then(|g| face.glyph_hor_advance(g)).unwrap_or(0);
+    let units: u32 = text.chars()
+        .map(|c| face.glyph_index(c).and_then(|g| face.glyph_hor_advance(g)).unwrap_or(fallback) as u32)
+        .sum();
+    units as f32 / face.units_per_em() as f32 * size * PT_TO_MM
+}
+
+/// Greedy word wrap; a word longer than the line is split between
+/// characters.
+fn wrap(text: &str, size: f32, bold: bool, max_width: f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+        if text_width(&candidate, size, bold) <= max_width {
+            line = candidate;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        for c in word.chars() {
+            line.push(c);
+            if text_width(&line, size, bold) > max_width && line.chars().count() > 1 {
+                line.pop();
+                lines.push(std::mem::replace(&mut line, c.to_string()));
+            }
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+// Places content top to bottom, starting a new page when the next block
+// does not fit.
+struct Writer {
+    width: f32,
+    height: f32,
+    y: f32,
+    pages: Vec<PageLayout>,
+}
+
+impl Writer {
+    fn new(size: PageSize) -> Self {
+        let (width, height) = size.dimensions();
+        Writer { width, height, y: MARGIN, pages: vec![PageLayout::default()] }
+    }
+
+    fn content_width(&self) -> f32 {
+        self.width - 2.0 * MARGIN
+    }
+
+    fn at_top(&self) -> bool {
+        self.y <= MARGIN
+    }
+
+    fn ensure(&mut self, height: f32) {
+        if self.y + height > self.height - MARGIN && !self.at_top() {
+            self.pages.push(PageLayout::default());
+            self.y = MARGIN;
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        if !self.at_top() {
+            self.y += height;
+        }
+    }
+
+    fn push(&mut self, element: Element) {
+        self.pages.last_mut().expect("writer has a page").elements.push(element);
+    }
+
+    fn text_height(&self, text: &str, size: f32, bold: bool, indent: f32) -> f32 {
+        wrap(text, size, bold, self.content_width() - indent).len() as f32 * line_height(size)
+    }
+
+    // Writes wrapped text, line by line, so a long paragraph may continue
+    // on the next page.
+    fn text_block(&mut self, text: &str, size: f32, bold: bool, indent: f32) {
+        for line in wrap(text, size, bold, self.content_width() - indent) {
+            self.line(line, size, bold, MARGIN + indent);
+        }
+    }
+
+    // Moves to the next line, on a new page if needed, and returns its
+    // baseline.
+    fn advance(&mut self, size: f32) -> f32 {
+        let height = line_height(size);
+        self.ensure(height);
+        self.y += height;
+        self.y - (LINE_SPACING - 1.0) * size * PT_TO_MM
+    }
+
+    fn line(&mut self, text: String, size: f32, bold: bool, x: f32) {
+        let y = self.advance(size);
+        self.push(Element::Text { x, y, size, bold, text });
+    }
+
+    // Section heading with a rule under it, kept with `keep` millimetres of
+    // the content that follows.
+    fn heading(&mut self, title: &str, keep: f32) {
+        self.gap(4.0);
+        let height = line_height(HEADING_SIZE) + 1.5;
+        self.ensure(height + keep);
+        self.line(title.to_uppercase(), HEADING_SIZE, true, MARGIN);
+        self.y += 0.5;
+        self.push(Element::Rule { x: MARGIN, y: self.y, width: self.content_width() });
+        self.y += 1.0;
+    }
+
+    fn experience(&mut self, experience: &Experience, date_format: &str) {
+        self.gap(2.5);
+        self.ensure(experience_head_height(self, experience, date_format));
+        self.text_block(&entry_title(experience), ENTRY_SIZE, true, 0.0);
+        self.text_block(&entry_meta(experience, date_format), DETAIL_SIZE, false, 0.0);
+        for responsibility in &experience.responsibilities {
+            let lines = wrap(&responsibility.bullet(), BODY_SIZE, false, self.content_width() - BULLET_INDENT);
+            for (index, line) in lines.into_iter().enumerate() {
+                let y = self.advance(BODY_SIZE);
+                if index == 0 {
+                    self.push(Element::Text { x: MARGIN + 1.0, y, size: BODY_SIZE, bold: false, text: "•".to_string() });
+                }
+                self.push(Element::Text { x: MARGIN + BULLET_INDENT, y, size: BODY_SIZE, bold: false, text: line });
+            }
+        }
+        if !experience.environment.is_empty() {
+            let names: Vec<&str> = experience.environment.iter().map(|s| s.name.as_str()).collect();
+            self.text_block(&format!("Environment: {}", names.join(", ")), DETAIL_SIZE, false, 0.0);
+        }
+    }
+
+    // Bold label in a fixed left column, value wrapped beside it.
+    fn labelled_row(&mut self, label: &str, value: &str, size: f32) {
+        let lines = wrap(value, size, false, self.content_width() - SKILL_COLUMN);
+        for (index, line) in lines.into_iter().enumerate() {
+            let y = self.advance(size);
+            if index == 0 {
+                self.push(Element::Text { x: MARGIN, y, size, bold: true, text: label.to_string() });
+            }
+            self.push(Element::Text { x: MARGIN + SKILL_COLUMN, y, size, bold: false, text: line });
+        }
+    }
+
+    // Items on one line separated by dots, wrapping as needed; items with a
+    // URI get a link area over their text.
+    fn inline_items(&mut self, items: &[(String, Option<String>)], size: f32) {
+        let padding = text_width("  ", size, false);
+        let separator_width = 2.0 * padding + text_width("·", size, false);
+        let mut x = MARGIN;
+        let mut baseline = None;
+        for (text, uri) in items {
+            let width = text_width(text, size, false);
+            let baseline = match baseline {
+                Some(y) if x + separator_width + width <= self.width - MARGIN => {
+                    self.push(Element::Text { x: x + padding, y, size, bold: false, text: "·".to_string() });
+                    x += separator_width;
+                    y
+                }
+                _ => {
+                    x = MARGIN;
+                    *baseline.insert(self.advance(size))
+                }
+            };
+            self.push(Element::Text { x, y: baseline, size, bold: false, text: text.clone() });
+            if let Some(uri) = uri {
+                let top = baseline - size * PT_TO_MM;
+                self.push(Element::Link { x, y: top, width, height: line_height(size), uri: uri.clone() });
+            }
+            x += width;
+        }
+    }
+
+    fn finish(self) -> Vec<PageLayout> {
+        self.pages
+    }
+}
@@ -0,0 +1,132 @@
+use std::str::FromStr;
+use qrcode::types::{Color, QrError};
+use qrcode::{EcLevel, QrCode};
+use crate::errors::AppError;
+
+pub const MAX_SCALE: u32 = 16;
+pub const MAX_BORDER: u32 = 16;
+
+/// What a profile QR code encodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrContent {
+    /// The profile's contact card, without its summary.
+    #[default]
+    Vcard,
+    /// The profile's public URL.
+    Url,
+}
+
+impl QrContent {
+    pub const SUPPORTED: &'static [&'static str] = &["vcard", "url"];
+}
+
+impl FromStr for QrContent {
+    type Err = AppError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        match content.to_ascii_lowercase().as_str() {
+            "vcard" => Ok(QrContent::Vcard),
+            "url" => Ok(QrContent::Url),
+            other => Err(AppError::ValidationError(format!(
+                "Unsupported QR code content '{}'. Supported contents: {}",
+                other,
+                QrContent::SUPPORTED.join(", ")
+            ))),
+        }
+    }
+}
+
+/// Options for [`render_qr_svg`] and [`render_qr_png`].
+#[derive(Debug, Clone)]
+pub struct QrOptions {
+    /// Size of one module, in pixels for PNG and in user units for SVG.
+    pub scale: u32,
+    /// Width of the light quiet zone around the code, in modules.
+    pub border: u32,
+}
+
+impl Default for QrOptions {
+    fn default() -> Self {
+        QrOptions { scale: 8, border: 4 }
+    }
+}
+
+impl QrOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !(1..=MAX_SCALE).contains(&self.scale) {
+            return Err(AppError::ValidationError(format!("Scale must be between 1 and {}", MAX_SCALE)));
+        }
+        if self.border > MAX_BORDER {
+            return Err(AppError::ValidationError(format!("Border must be between 0 and {}", MAX_BORDER)));
+        }
+        Ok(())
+    }
+}
+
+/// Renders `data` as a QR code in SVG: one path of dark modules over a
+/// white background, sized to `scale` units per module.
+pub fn render_qr_svg(data: &str, options: &QrOptions) -> Result<String, AppError> {
+    let (width, dark) = dark_modules(data)?;
+    let size = width + 2 * options.border;
+
+    let mut path = String::new();
+    for (x, y) in dark {
+        path.push_str(&format!("M{},{}h1v1h-1z", x + options.border, y + options.border));
+    }
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {1} {1}\" shape-rendering=\"crispEdges\">\n\
+         <rect width=\"{1}\" height=\"{1}\" fill=\"#ffffff\"/>\n\
+         <path fill=\"#000000\" d=\"{2}\"/>\n\
+         </svg>\n",
+        size * options.scale,
+        size,
+        path
+    ))
+}
+
+/// Renders `data` as a QR code in an 8-bit greyscale PNG.
+pub fn render_qr_png(data: &str, options: &QrOptions) -> Result<Vec<u8>, AppError> {
+    let (width, dark) = dark_modules(data)?;
+    let size = (width + 2 * options.border) * options.scale;
+
+    let mut pixels = vec![0xFFu8; (size * size) as usize];
+    for (x, y) in dark {
+        let left = (x + options.border) * options.scale;
+        let top = (y + options.border) * options.scale;
+        for y in top..top + options.scale {
+            let row = (y * size) as usize;
+            pixels[row + left as usize..row + (left + options.scale) as usize].fill(0);
+        }
+    }
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, size, size);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let failed = |err: png::EncodingError| AppError::InternalServerError(format!("Failed to encode QR code: {}", err));
+    let mut writer = encoder.write_header().map_err(failed)?;
+    writer.write_image_data(&pixels).map_err(failed)?;
+    writer.finish().map_err(failed)?;
+    Ok(png)
+}
+
+// Width of the code in modules and the coordinates of its dark modules.
+// Medium error correction survives a worn or creased printout.
+fn dark_modules(data: &str) -> Result<(u32, Vec<(u32, u32)>), AppError> {
+    let code = QrCode::with_error_correction_level(data.as_bytes(), EcLevel::M).map_err(|err| match err {
+        QrError::DataTooLong => AppError::ValidationError(format!(
+            "Content of {} bytes is too long for a QR code",
+            data.len()
+        )),
+        err => AppError::InternalServerError(format!("Failed to encode QR code: {}", err)),
+    })?;
+    let width = code.width() as u32;
+    let dark = code.to_colors()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, color)| *color == Color::Dark)
+        .map(|(index, _)| (index as u32 % width, index as u32 / width))
+        .collect();
+    Ok((width, dark))
+}
@@ -0,0 +1,208 @@
+use std::collections::HashSet;
+use lazy_static::lazy_static;
+use quick_xml::escape::escape;
+use serde::Serialize;
+use tera::{Context, Tera};
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::resume::ResolvedResume;
+use crate::models::skill::Skill;
+use crate::renderers::html::{render_html, ExperienceView, ProfileView, Theme};
+use crate::renderers::json_ld::{person, to_script};
+use crate::renderers::{check_date_format, format_date, slug, DEFAULT_DATE_FORMAT};
+
+lazy_static! {
+    static ref SITE: Tera = {
+        let mut tera = Tera::default();
+        tera.add_raw_templates(vec![
+            ("macros.html", include_str!("../../templates/themes/macros.html")),
+            ("layout.html", include_str!("../../templates/site/layout.html")),
+            ("index.html", include_str!("../../templates/site/index.html")),
+            ("project.html", include_str!("../../templates/site/project.html")),
+        ])
+        .expect("bundled site templates compile");
+        tera
+    };
+}
+
+const STYLESHEET: &str = include_str!("../../templates/site/site.css");
+
+/// Directory assets are copied to, relative to the site root.
+pub const ASSETS_DIR: &str = "assets";
+
+/// A file of the generated site, with its path relative to the site root
+/// (always `/`-separated).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SiteFile {
+    pub path: String,
+    pub content: Vec<u8>,
+}
+
+/// Everything a portfolio is built from: the profile, all of its
+/// experiences (one project page each), its resolved resumes (one printable
+/// page each) and the files to copy under `assets/`.
+#[derive(Debug, Clone)]
+pub struct Portfolio {
+    pub profile: Profile,
+    pub experiences: Vec<Experience>,
+    pub resumes: Vec<ResolvedResume>,
+    pub assets: Vec<SiteFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SiteOptions {
+    /// Absolute URL the site is hosted at, used for canonical links and the
+    /// sitemap.
+    pub base_url: String,
+    /// Theme of the printable resume pages.
+    pub theme: Theme,
+    pub date_format: String,
+}
+
+impl Default for SiteOptions {
+    fn default() -> Self {
+        SiteOptions {
+            base_url: String::new(),
+            theme: Theme::default(),
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+        }
+    }
+}
+
+impl SiteOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let scheme = self.base_url.starts_with("https://") || self.base_url.starts_with("http://");
+        // Pages link to the URL unescaped
+        if !scheme || self.base_url.contains(|c: char| c.is_whitespace() || "\"'<>&".contains(c)) {
+            return Err(AppError::ValidationError(format!(
+                "Base URL '{}' must be an absolute http or https URL", self.base_url
+            )));
+        }
+        check_date_format(&self.date_format)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ProjectLink {
+    path: String,
+    job_title: String,
+    company: String,
+    start: String,
+    end: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ResumeLink {
+    path: String,
+    name: String,
+}
+
+/// Builds a self-contained static site: `index.html` introducing the
+/// profile and linking every page, `projects/<slug>.html` for each
+/// experience (most recent first), `resumes/<slug>.html` with each resume
+/// in the chosen theme, ready to print, `sitemap.xml` and `robots.txt`, and
+/// `assets/` with the site stylesheet and the portfolio's assets. Every
+/// page embeds its schema.org description as JSON-LD, and links between
+/// pages are relative so the site works from any directory.
+pub fn render_site(portfolio: &Portfolio, options: &SiteOptions) -> Result<Vec<SiteFile>, AppError> {
+    options.validate()?;
+    let base_url = options.base_url.trim_end_matches('/');
+    let mut experiences = portfolio.experiences.clone();
+    experiences.sort_by(|a, b| b.start_date.cmp(&a.start_date));
+
+    let mut taken = HashSet::new();
+    let projects: Vec<ProjectLink> = experiences.iter()
+        .map(|e| ProjectLink {
+            path: format!("projects/{}.html", unique_slug(&format!("{} {}", e.company, e.job_title), "project", &mut taken)),
+            job_title: e.job_title.clone(),
+            company: e.company.clone(),
+            start: format_date(&e.start_date, &options.date_format),
+            end: format_date(&e.end_date, &options.date_format),
+        })
+        .collect();
+    let mut taken = HashSet::new();
+    let resumes: Vec<ResumeLink> = portfolio.resumes.iter()
+        .map(|r| ResumeLink { path: format!("resumes/{}.html", unique_slug(&r.name, "resume", &mut taken)), name: r.name.clone() })
+        .collect();
+
+    let page = |path: &str| -> Context {
+        let mut context = Context::new();
+        context.insert("profile", &ProfileView::from(&portfolio.profile));
+        context.insert("resumes", &resumes);
+        context.insert("root", &"../".repeat(path.matches('/').count()));
+        context.insert("canonical", &page_url(base_url, path));
+        context
+    };
+    let render = |template: &str, context: &Context| {
+        SITE.render(template, context)
+            .map_err(|err| AppError::InternalServerError(format!("Failed to render {}: {}", template, err)))
+    };
+
+    let mut files = Vec::new();
+    let skills: Vec<&Skill> = experiences.iter().flat_map(|e| &e.environment).collect();
+    let mut context = page("index.html");
+    context.insert("summary", &paragraphs(portfolio.profile.summary.as_deref()));
+    context.insert("projects", &projects);
+    context.insert("json_ld", &to_script(&person(&portfolio.profile, portfolio.profile.summary.as_deref(), &experiences, &skills)));
+    files.push(SiteFile { path: "index.html".to_string(), content: render("index.html", &context)?.into_bytes() });
+
+    for (experience, project) in experiences.iter().zip(&projects) {
+        let skills: Vec<&Skill> = experience.environment.iter().collect();
+        let mut context = page(&project.path);
+        context.insert("experience", &ExperienceView::new(experience, &options.date_format));
+        context.insert("json_ld", &to_script(&person(&portfolio.profile, None, std::slice::from_ref(experience), &skills)));
+        files.push(SiteFile { path: project.path.clone(), content: render("project.html", &context)?.into_bytes() });
+    }
+    for (resume, link) in portfolio.resumes.iter().zip(&resumes) {
+        let html = render_html(resume, options.theme, &options.date_format)?;
+        files.push(SiteFile { path: link.path.clone(), content: html.into_bytes() });
+    }
+
+    let pages: Vec<String> = files.iter().map(|file| file.path.clone()).collect();
+    files.push(SiteFile { path: "sitemap.xml".to_string(), content: sitemap(base_url, &pages).into_bytes() });
+    files.push(SiteFile {
+        path: "robots.txt".to_string(),
+        content: format!("User-agent: *\nAllow: /\nSitemap: {}/sitemap.xml\n", base_url).into_bytes(),
+    });
+    files.push(SiteFile { path: format!("{}/site.css", ASSETS_DIR), content: STYLESHEET.as_bytes().to_vec() });
+    files.extend(portfolio.assets.iter().map(|asset| SiteFile {
+        path: format!("{}/{}", ASSETS_DIR, asset.path),
+        content: asset.content.clone(),
+    }));
+    Ok(files)
+}
+
+fn sitemap(base_url: &str, pages: &[String]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for page in pages {
+        xml.push_str(&format!("  <url><loc>{}</loc></url>\n", escape(&page_url(base_url, page))));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+// Absolute URL of a page; the index is served as the directory itself.
+fn page_url(base_url: &str, path: &str) -> String {
+    format!("{}/{}", base_url, path.strip_suffix("index.html").unwrap_or(path))
+}
+
+fn paragraphs(text: Option<&str>) -> Vec<String> {
+    text.map(|text| text.split("\n\n").map(str::trim).filter(|p| !p.is_empty()).map(String::from).collect())
+        .unwrap_or_default()
+}
+
+// Slug of `text`, numbered when an earlier page took it (`acme-2`).
+fn unique_slug(text: &str, fallback: &str, taken: &mut HashSet<String>) -> String {
+    let base = match slug(text) {
+        slug if slug.is_empty() => fallback.to_string(),
+        slug => slug,
+    };
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{}-{}", base, n);
+        n += 1;
+    }
+    candidate
+}
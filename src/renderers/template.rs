@@ -0,0 +1,380 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error as _;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::Value;
+use tera::ast::{Expr, ExprVal, FunctionCall, Node, WS};
+use tera::{Context, Tera};
+use crate::errors::AppError;
+use crate::models::resume::ResolvedResume;
+use crate::models::template::Template;
+use crate::renderers::html::ResumeView;
+use crate::renderers::json_ld::{resume_json_ld, to_script};
+
+/// Largest template source accepted, in bytes.
+pub const TEMPLATE_SOURCE_LIMIT: usize = 256 * 1024;
+/// Largest total size of a template's decoded assets, in bytes.
+pub const TEMPLATE_ASSETS_LIMIT: usize = 2 * 1024 * 1024;
+/// Most items `range` may produce, so that loops stay bounded.
+pub const RANGE_LIMIT: usize = 10_000;
+/// Most renders running at once.
+pub const MAX_CONCURRENT_RENDERS: usize = 8;
+
+static RUNNING_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+// Name the source is registered under; the suffix turns on HTML escaping.
+const TEMPLATE_NAME: &str = "template.html";
+// Function called at the start of every loop iteration to enforce the deadline.
+const DEADLINE_FUNCTION: &str = "__check_deadline";
+
+lazy_static! {
+    static ref MIME_REGEX: Regex = Regex::new(r"^[A-Za-z0-9][\w.+-]*/[A-Za-z0-9][\w.+-]*$").unwrap();
+    static ref LOCATION_REGEX: Regex = Regex::new(r"-->\s*(\d+):(\d+)").unwrap();
+}
+
+/// Bounds on a single render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderLimits {
+    pub timeout: Duration,
+    pub output_bytes: usize,
+}
+
+impl Default for RenderLimits {
+    fn default() -> Self {
+        RenderLimits { timeout: Duration::from_secs(2), output_bytes: 5 * 1024 * 1024 }
+    }
+}
+
+/// A user template ready to render. It runs in its own Tera instance that
+/// knows no other template and has no file loader; `include`, `extends` and
+/// `import` are rejected, `get_env` is disabled and `range` is capped at
+/// [`RANGE_LIMIT`] items. Macros, `filter` sections and `set` values built
+/// from other `set` variables are rejected, so that nothing grows in memory
+/// outside the output limit, and every loop iteration checks the deadline.
+pub struct CompiledTemplate {
+    tera: Tera,
+    assets: BTreeMap<String, String>,
+    deadline: Arc<Mutex<Instant>>,
+}
+
+/// Checks a template's size and assets and compiles its source. Syntax
+/// errors report the line and column they were found at.
+pub fn compile_template(template: &Template) -> Result<CompiledTemplate, AppError> {
+    if template.source.len() > TEMPLATE_SOURCE_LIMIT {
+        return Err(AppError::ValidationError(format!(
+            "Template source is {} bytes; the limit is {} bytes", template.source.len(), TEMPLATE_SOURCE_LIMIT
+        )));
+    }
+
+    let mut names = HashSet::new();
+    let mut size = 0;
+    let mut assets = BTreeMap::new();
+    for asset in &template.assets {
+        if !names.insert(asset.name.as_str()) {
+            return Err(AppError::ValidationError(format!("Asset '{}' is listed more than once", asset.name)));
+        }
+        if !MIME_REGEX.is_match(&asset.content_type) {
+            return Err(AppError::ValidationError(format!(
+                "Asset '{}' has an invalid content type '{}'", asset.name, asset.content_type
+            )));
+        }
+        size += STANDARD.decode(&asset.data)
+            .map_err(|e| AppError::ValidationError(format!("Asset '{}' is not valid base64: {}", asset.name, e)))?
+            .len();
+        assets.insert(asset.name.clone(), format!("data:{};base64,{}", asset.content_type, asset.data));
+    }
+    if size > TEMPLATE_ASSETS_LIMIT {
+        return Err(AppError::ValidationError(format!(
+            "Template assets are {} bytes; the limit is {} bytes", size, TEMPLATE_ASSETS_LIMIT
+        )));
+    }
+
+    let mut tera = Tera::default();
+    tera.add_raw_template(TEMPLATE_NAME, &template.source).map_err(syntax_error)?;
+    check_growth(tera.get_template(TEMPLATE_NAME).map_err(syntax_error)?)?;
+    if let Some(compiled) = tera.templates.get_mut(TEMPLATE_NAME) {
+        add_deadline_checks(&mut compiled.ast);
+        for definitions in compiled.blocks_definitions.values_mut() {
+            definitions.iter_mut().for_each(|(_, block)| add_deadline_checks(&mut block.body));
+        }
+    }
+    tera.register_function("get_env", |_: &HashMap<String, Value>| {
+        Err(tera::Error::msg("Function `get_env` is not available in templates"))
+    });
+    let deadline = Arc::new(Mutex::new(Instant::now()));
+    let clock = deadline.clone();
+    tera.register_function("range", move |args: &HashMap<String, Value>| {
+        if Instant::now() > *clock.lock().unwrap() {
+            return Err(tera::Error::msg("Time limit exceeded"));
+        }
+        bounded_range(args)
+    });
+    let clock = deadline.clone();
+    tera.register_function(DEADLINE_FUNCTION, move |_: &HashMap<String, Value>| {
+        if Instant::now() > *clock.lock().unwrap() {
+            return Err(tera::Error::msg("Time limit exceeded"));
+        }
+        Ok(Value::String(String::new()))
+    });
+    Ok(CompiledTemplate { tera, assets, deadline })
+}
+
+impl CompiledTemplate {
+    /// Renders the resume with the view model of the bundled themes, plus
+    /// `json_ld` and the `assets` map of `data:` URLs. Rendering fails once
+    /// the output exceeds its limit or the deadline passes.
+    pub fn render(&self, resume: &ResolvedResume, date_format: &str, limits: RenderLimits) -> Result<String, AppError> {
+        let mut context = Context::from_serialize(ResumeView::new(resume, date_format))
+            .map_err(|err| AppError::SerializationError(err.to_string()))?;
+        context.insert("json_ld", &to_script(&resume_json_ld(resume)));
+        context.insert("assets", &self.assets);
+
+        let _slot = RenderSlot::acquire()?;
+        let deadline = Instant::now() + limits.timeout;
+        *self.deadline.lock().unwrap() = deadline;
+        let mut output = LimitedWriter { output: Vec::new(), limit: limits.output_bytes, deadline, overflowed: false };
+        match self.tera.render_to(TEMPLATE_NAME, &context, &mut output) {
+            Ok(()) => String::from_utf8(output.output).map_err(|e| AppError::InternalServerError(e.to_string())),
+            Err(_) if output.overflowed => Err(AppError::ValidationError(format!(
+                "Template output exceeds the limit of {} bytes", limits.output_bytes
+            ))),
+            Err(_) if Instant::now() > deadline => Err(AppError::ValidationError(format!(
+                "Template rendering exceeds the time limit of {} ms", limits.timeout.as_millis()
+            ))),
+            Err(err) => Err(AppError::ValidationError(format!("Template failed to render: {}", describe(&err)))),
+        }
+    }
+}
+
+// One of the MAX_CONCURRENT_RENDERS slots, given back when dropped.
+struct RenderSlot;
+
+impl RenderSlot {
+    fn acquire() -> Result<Self, AppError> {
+        if RUNNING_RENDERS.fetch_add(1, Ordering::SeqCst) >= MAX_CONCURRENT_RENDERS {
+            RUNNING_RENDERS.fetch_sub(1, Ordering::SeqCst);
+            return Err(AppError::InternalServerError("Too many templates are rendering; try again later".to_string()));
+        }
+        Ok(RenderSlot)
+    }
+}
+
+impl Drop for RenderSlot {
+    fn drop(&mut self) {
+        RUNNING_RENDERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Collects the output and fails the render once it grows past the limit
+// or the deadline passes.
+struct LimitedWriter {
+    output: Vec<u8>,
+    limit: usize,
+    deadline: Instant,
+    overflowed: bool,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.output.len() + buf.len() > self.limit {
+            self.overflowed = true;
+            return Err(io::Error::other("output limit exceeded"));
+        }
+        if Instant::now() > self.deadline {
+            return Err(io::Error::other("time limit exceeded"));
+        }
+        self.output.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Tera's `range`, refusing ranges longer than RANGE_LIMIT.
+fn bounded_range(args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let number = |name: &str, default: Option<usize>| match args.get(name) {
+        Some(value) => value.as_u64()
+            .map(|n| n as usize)
+            .ok_or_else(|| tera::Error::msg(format!("Function `range` received {}={} but `{}` can only be a number", name, value, name))),
+        None => default.ok_or_else(|| tera::Error::msg(format!("Function `range` was called without a `{}` argument", name))),
+    };
+    let start = number("start", Some(0))?;
+    let step_by = number("step_by", Some(1))?;
+    let end = number("end", None)?;
+    if step_by == 0 {
+        return Err(tera::Error::msg("Function `range` received step_by=0"));
+    }
+    if start > end {
+        return Err(tera::Error::msg("Function `range` was called with a `start` argument greater than the `end` one"));
+    }
+    if (end - start).div_ceil(step_by) > RANGE_LIMIT {
+        return Err(tera::Error::msg(format!("Function `range` is limited to {} items", RANGE_LIMIT)));
+    }
+    Ok(Value::from((start..end).step_by(step_by).collect::<Vec<_>>()))
+}
+
+// Rejects what Tera builds in memory rather than in the output: macros and
+// `filter` sections render their body to a string, and a `set` value built
+// from a `set` variable can double on every statement or loop iteration.
+// `include` and `extends` are rejected too: the only template they can
+// reach is this one, and including itself recurses until the stack overflows.
+fn check_growth(template: &tera::Template) -> Result<(), AppError> {
+    fn assigned<'a>(nodes: &'a [Node], keys: &mut HashSet<&'a str>) {
+        for node in nodes {
+            match node {
+                Node::Set(_, set) => {
+                    keys.insert(set.key.as_str());
+                }
+                _ => children(node).into_iter().for_each(|body| assigned(body, keys)),
+            }
+        }
+    }
+    fn check(nodes: &[Node], keys: &HashSet<&str>) -> Result<(), String> {
+        for node in nodes {
+            match node {
+                Node::MacroDefinition(..) | Node::ImportMacro(..) => return Err("macros are not supported".to_string()),
+                Node::Include(..) => return Err("`include` is not supported".to_string()),
+                Node::Extends(..) => return Err("`extends` is not supported".to_string()),
+                Node::FilterSection(..) => return Err("`filter` sections are not supported".to_string()),
+                Node::Set(_, set) => {
+                    let mut reused = None;
+                    visit_expr(&set.value, &mut |value| if let ExprVal::Ident(name) = value {
+                        let root = name.split(['.', '[']).next().unwrap_or(name);
+                        reused = reused.or(keys.get(root).copied());
+                    });
+                    if let Some(name) = reused {
+                        return Err(format!("`set {}` cannot be built from `{}`, which is assigned with `set`", set.key, name));
+                    }
+                }
+                _ => {}
+            }
+            let mut macro_call = false;
+            for expr in expressions(node) {
+                visit_expr(expr, &mut |value| macro_call |= matches!(value, ExprVal::MacroCall(_)));
+            }
+            if macro_call {
+                return Err("macros are not supported".to_string());
+            }
+            for body in children(node) {
+                check(body, keys)?;
+            }
+        }
+        Ok(())
+    }
+
+    if !template.macros.is_empty() {
+        return Err(AppError::ValidationError("Invalid template: macros are not supported".to_string()));
+    }
+    let mut keys = HashSet::new();
+    assigned(&template.ast, &mut keys);
+    check(&template.ast, &keys).map_err(|reason| AppError::ValidationError(format!("Invalid template: {}", reason)))
+}
+
+// Starts every loop iteration with a call to DEADLINE_FUNCTION, so that
+// loops writing nothing still stop at the deadline.
+fn add_deadline_checks(nodes: &mut [Node]) {
+    for node in nodes {
+        if let Node::Forloop(_, for_loop, _) = node {
+            let call = FunctionCall { name: DEADLINE_FUNCTION.to_string(), args: HashMap::new() };
+            for_loop.body.insert(0, Node::VariableBlock(WS::default(), Expr::new(ExprVal::FunctionCall(call))));
+        }
+        children_mut(node).into_iter().for_each(|body| add_deadline_checks(body));
+    }
+}
+
+fn children(node: &Node) -> Vec<&[Node]> {
+    match node {
+        Node::Block(_, block, _) => vec![&block.body],
+        Node::FilterSection(_, section, _) => vec![&section.body],
+        Node::MacroDefinition(_, definition, _) => vec![&definition.body],
+        Node::Forloop(_, for_loop, _) => std::iter::once(&for_loop.body).chain(&for_loop.empty_body).map(Vec::as_slice).collect(),
+        Node::If(condition, _) => condition.conditions.iter().map(|(_, _, body)| body)
+            .chain(condition.otherwise.iter().map(|(_, body)| body))
+            .map(Vec::as_slice)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+// Bodies that can hold loops once macros and `filter` sections are rejected.
+fn children_mut(node: &mut Node) -> Vec<&mut Vec<Node>> {
+    match node {
+        Node::Block(_, block, _) => vec![&mut block.body],
+        Node::Forloop(_, for_loop, _) => std::iter::once(&mut for_loop.body).chain(for_loop.empty_body.as_mut()).collect(),
+        Node::If(condition, _) => condition.conditions.iter_mut().map(|(_, _, body)| body)
+            .chain(condition.otherwise.iter_mut().map(|(_, body)| body))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn expressions(node: &Node) -> Vec<&Expr> {
+    match node {
+        Node::VariableBlock(_, expr) => vec![expr],
+        Node::Set(_, set) => vec![&set.value],
+        Node::Forloop(_, for_loop, _) => vec![&for_loop.container],
+        Node::If(condition, _) => condition.conditions.iter().map(|(_, expr, _)| expr).collect(),
+        _ => vec![],
+    }
+}
+
+// Calls `f` on every value of the expression, its operands and arguments.
+fn visit_expr(expr: &Expr, f: &mut impl FnMut(&ExprVal)) {
+    visit_value(&expr.val, f);
+    for filter in &expr.filters {
+        filter.args.values().for_each(|arg| visit_expr(arg, f));
+    }
+}
+
+fn visit_value(value: &ExprVal, f: &mut impl FnMut(&ExprVal)) {
+    f(value);
+    match value {
+        ExprVal::Math(math) => [&math.lhs, &math.rhs].into_iter().for_each(|e| visit_expr(e, f)),
+        ExprVal::Logic(logic) => [&logic.lhs, &logic.rhs].into_iter().for_each(|e| visit_expr(e, f)),
+        ExprVal::In(within) => [&within.lhs, &within.rhs].into_iter().for_each(|e| visit_expr(e, f)),
+        ExprVal::Test(test) => {
+            f(&ExprVal::Ident(test.ident.clone()));
+            test.args.iter().for_each(|e| visit_expr(e, f));
+        }
+        ExprVal::MacroCall(call) => call.args.values().for_each(|e| visit_expr(e, f)),
+        ExprVal::FunctionCall(call) => call.args.values().for_each(|e| visit_expr(e, f)),
+        ExprVal::Array(items) => items.iter().for_each(|e| visit_expr(e, f)),
+        ExprVal::StringConcat(concat) => concat.values.iter().for_each(|v| visit_value(v, f)),
+        _ => {}
+    }
+}
+
+// Turns a Tera parse error into `line L, column C: expected ...`. Errors
+// found after parsing, such as a missing parent template, have no position.
+fn syntax_error(err: tera::Error) -> AppError {
+    let detail = describe(&err);
+    let message = match LOCATION_REGEX.captures(&detail) {
+        Some(location) => {
+            let reason = detail.lines()
+                .find_map(|line| line.trim().strip_prefix("= "))
+                .unwrap_or("syntax error");
+            format!("line {}, column {}: {}", &location[1], &location[2], reason)
+        }
+        None => detail,
+    };
+    AppError::ValidationError(format!("Invalid template: {}", message))
+}
+
+// Tera nests the useful message in the error's sources.
+fn describe(err: &tera::Error) -> String {
+    let mut messages = vec![err.to_string()];
+    let mut source = err.source();
+    while let Some(err) = source {
+        messages.push(err.to_string());
+        source = err.source();
+    }
+    messages.join(": ")
+}
@@ -0,0 +1,248 @@
+use crate::errors::AppError;
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::renderers::{format_date, group_by_category};
+
+/// The one date format used in plain-text output, e.g. `03/2021`.
+pub const TEXT_DATE_FORMAT: &str = "%m/%Y";
+
+pub const MIN_LINE_WIDTH: usize = 40;
+pub const MAX_LINE_WIDTH: usize = 200;
+
+/// Options for [`render_text`].
+#[derive(Debug, Clone)]
+pub struct TextOptions {
+    /// Maximum number of characters per line.
+    pub line_width: usize,
+    /// Replace every non-ASCII character with an ASCII equivalent.
+    pub ascii: bool,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        TextOptions { line_width: 80, ascii: true }
+    }
+}
+
+impl TextOptions {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !(MIN_LINE_WIDTH..=MAX_LINE_WIDTH).contains(&self.line_width) {
+            return Err(AppError::ValidationError(format!(
+                "Line width must be between {} and {}",
+                MIN_LINE_WIDTH, MAX_LINE_WIDTH
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Renders a resolved resume as plain text for applicant tracking systems:
+/// fixed upper-case headings (SUMMARY, EXPERIENCE, EDUCATION, SKILLS)
+/// whatever the section titles, one date format, no decoration, and lines
+/// wrapped to the configured width.
+pub fn render_text(resume: &ResolvedResume, options: &TextOptions) -> String {
+    let mut out = TextWriter { text: String::new(), options };
+    let profile = &resume.profile;
+
+    out.line(&profile.name.to_uppercase(), "");
+    if let Some(label) = &profile.label {
+        out.line(label, "");
+    }
+    let contact: Vec<&str> = [&profile.email, &profile.phone, &profile.url, &profile.location]
+        .into_iter()
+        .filter_map(|field| field.as_deref())
+        .collect();
+    if !contact.is_empty() {
+        out.line(&contact.join(" | "), "");
+    }
+    for link in &profile.links {
+        out.line(&format!("{}: {}", link.network, link.url), "");
+    }
+
+    let mut education_done = false;
+    for section in &resume.sections {
+        match section.kind {
+            SectionKind::Summary => {
+                if let Some(summary) = &resume.summary {
+                    out.heading("SUMMARY");
+                    for (index, text) in summary.split("\n\n").map(str::trim).filter(|t| !t.is_empty()).enumerate() {
+                        if index > 0 {
+                            out.blank();
+                        }
+                        out.line(text, "");
+                    }
+                }
+            }
+            SectionKind::Experience => {
+                if !resume.experiences.is_empty() {
+                    out.heading("EXPERIENCE");
+                    for (index, experience) in resume.experiences.iter().enumerate() {
+                        if index > 0 {
+                            out.blank();
+                        }
+                        out.experience(experience);
+                    }
+                }
+                // Education follows experience, as most ATS parsers expect
+                out.education(&profile.education);
+                education_done = true;
+            }
+            SectionKind::Skills => {
+                if !resume.skills.is_empty() {
+                    out.heading("SKILLS");
+                    for group in group_by_category(&resume.skills) {
+                        let names: Vec<&str> = group.skills.iter().map(|s| s.name.as_str()).collect();
+                        out.line(&format!("{}: {}", group.category, names.join(", ")), "  ");
+                    }
+                }
+            }
+        }
+    }
+    if !education_done {
+        out.education(&profile.education);
+    }
+
+    format!("{}\n", out.text.trim_end())
+}
+
+/// Renders a single experience the way [`render_text`] lists it.
+pub fn render_experience_text(experience: &Experience, options: &TextOptions) -> String {
+    let mut out = TextWriter { text: String::new(), options };
+    out.experience(experience);
+    format!("{}\n", out.text.trim_end())
+}
+
+struct TextWriter<'a> {
+    text: String,
+    options: &'a TextOptions,
+}
+
+impl TextWriter<'_> {
+    // Writes `text` wrapped to the line width; the ASCII fallback is applied
+    // first so replacements cannot push a line past the width.
+    fn line(&mut self, text: &str, indent: &str) {
+        let text = if self.options.ascii { to_ascii(text) } else { text.to_string() };
+        for line in wrap(&text, self.options.line_width, indent) {
+            self.text.push_str(&line);
+            self.text.push('\n');
+        }
+    }
+
+    fn blank(&mut self) {
+        self.text.push('\n');
+    }
+
+    fn heading(&mut self, title: &str) {
+        self.blank();
+        self.text.push_str(title);
+        self.text.push('\n');
+    }
+
+    fn experience(&mut self, experience: &Experience) {
+        self.line(&experience.job_title, "");
+        self.line(&format!("{} | {}", experience.company, experience.location), "");
+        self.line(&period(Some(&experience.start_date), Some(&experience.end_date)), "");
+        for responsibility in &experience.responsibilities {
+            self.line(&format!("- {}", responsibility.bullet()), "  ");
+        }
+        if !experience.environment.is_empty() {
+            let names: Vec<&str> = experience.environment.iter().map(|s| s.name.as_str()).collect();
+            self.line(&format!("Environment: {}", names.join(", ")), "  ");
+        }
+    }
+
+    fn education(&mut self, education: &[Education]) {
+        if education.is_empty() {
+            return;
+        }
+        self.heading("EDUCATION");
+        for (index, entry) in education.iter().enumerate() {
+            if index > 0 {
+                self.blank();
+            }
+            let degree: Vec<&str> = [entry.study_type.as_deref(), entry.area.as_deref()].into_iter().flatten().collect();
+            if !degree.is_empty() {
+                self.line(&degree.join(", "), "");
+            }
+            self.line(&entry.institution, "");
+            if entry.start_date.is_some() || entry.end_date.is_some() {
+                self.line(&period(entry.start_date.as_ref(), entry.end_date.as_ref()), "");
+            }
+            if let Some(score) = &entry.score {
+                self.line(&format!("Grade: {}", score), "");
+            }
+        }
+    }
+}
+
+fn period(start: Option<&String>, end: Option<&String>) -> String {
+    let date = |date: Option<&String>| date.map(|d| format_date(d, TEXT_DATE_FORMAT));
+    match (date(start), date(end)) {
+        (Some(start), Some(end)) => format!("{} - {}", start, end),
+        (Some(start), None) => start,
+        (None, Some(end)) => end,
+        (None, None) => String::new(),
+    }
+}
+
+/// Wraps `text` on word boundaries so no line exceeds `width` characters;
+/// continuation lines start with `indent`. Words longer than a line are
+/// split.
+pub fn wrap(text: &str, width: usize, indent: &str) -> Vec<String> {
+    let indent_width = indent.chars().count();
+    let available = |lines: &Vec<String>| if lines.is_empty() { width } else { width - indent_width };
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut chars: Vec<char> = word.chars().collect();
+        if !line.is_empty() {
+            if line.chars().count() + 1 + chars.len() <= available(&lines) {
+                line.push(' ');
+                line.push_str(word);
+                continue;
+            }
+            lines.push(std::mem::take(&mut line));
+        }
+        while chars.len() > available(&lines) {
+            let rest = chars.split_off(available(&lines));
+            lines.push(chars.into_iter().collect());
+            chars = rest;
+        }
+        line = chars.into_iter().collect();
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines.into_iter()
+        .enumerate()
+        .map(|(index, line)| if index == 0 { line } else { format!("{}{}", indent, line) })
+        .collect()
+}
+
+/// Replaces typographic characters with their usual ASCII spelling and
+/// transliterates anything else outside ASCII.
+pub fn to_ascii(text: &str) -> String {
+    let mut ascii = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            c if c.is_ascii() => ascii.push(c),
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => ascii.push('\''),
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' | '\u{00AB}' | '\u{00BB}' => ascii.push('"'),
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => ascii.push('-'),
+            '\u{2026}' => ascii.push_str("..."),
+            '\u{2022}' | '\u{00B7}' | '\u{2023}' | '\u{25CF}' => ascii.push('*'),
+            '\u{00A0}' | '\u{2002}'..='\u{200A}' | '\u{202F}' => ascii.push(' '),
+            '\u{200B}' | '\u{FEFF}' => {}
+            '\u{2192}' => ascii.push_str("->"),
+            '\u{00D7}' => ascii.push('x'),
+            '\u{00A9}' => ascii.push_str("(c)"),
+            '\u{00AE}' => ascii.push_str("(R)"),
+            '\u{2122}' => ascii.push_str("(TM)"),
+            '\u{20AC}' => ascii.push_str("EUR"),
+            '\u{00A3}' => ascii.push_str("GBP"),
+            c => ascii.push_str(deunicode::deunicode_char(c).unwrap_or("?")),
+        }
+    }
+    ascii
+}
@@ -0,0 +1,110 @@
+use crate::models::profile::Profile;
+use crate::renderers::latex::split_name;
+
+pub const VCARD_MIME: &str = "text/vcard";
+
+// RFC 6350 limits lines to 75 octets, excluding the line break.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Renders a profile's contact details as a vCard 4.0 (RFC 6350): name,
+/// label as `TITLE`, email, phone as a `tel:` URI, location as `ADR`,
+/// homepage as `URL`, summary as `NOTE` and profile links as
+/// `X-SOCIALPROFILE`, which address books read as social profiles. Lines
+/// end with CRLF and are folded at 75 octets.
+pub fn render_vcard(profile: &Profile) -> String {
+    let (first, last) = split_name(&profile.name);
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:4.0".to_string(),
+        "PRODID:-//rust-server//vCard//EN".to_string(),
+        format!("FN:{}", escape(&profile.name)),
+        format!("N:{};{};;;", escape_component(last), escape_component(first)),
+    ];
+    if let Some(label) = &profile.label {
+        lines.push(format!("TITLE:{}", escape(label)));
+    }
+    if let Some(email) = &profile.email {
+        lines.push(format!("EMAIL;TYPE=home:{}", escape(email)));
+    }
+    if let Some(phone) = &profile.phone {
+        lines.push(format!("TEL;VALUE=uri;TYPE=cell,voice:tel:{}", tel_uri(phone)));
+    }
+    if let Some(location) = &profile.location {
+        // "City, Country" is the convention used across our models
+        let (locality, country) = match location.split_once(',') {
+            Some((locality, country)) => (locality.trim(), country.trim()),
+            None => (location.trim(), ""),
+        };
+        lines.push(format!("ADR;TYPE=home:;;;{};;;{}", escape_component(locality), escape_component(country)));
+    }
+    if let Some(url) = &profile.url {
+        lines.push(format!("URL:{}", url));
+    }
+    for link in &profile.links {
+        lines.push(format!("X-SOCIALPROFILE;TYPE={}:{}", parameter(&link.network), link.url));
+    }
+    if let Some(summary) = &profile.summary {
+        lines.push(format!("NOTE:{}", escape(summary)));
+    }
+    lines.push("END:VCARD".to_string());
+
+    lines.iter().map(|line| fold(line)).collect::<Vec<_>>().join("")
+}
+
+// Text values escape backslashes, commas, semicolons and line breaks.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ',' => escaped.push_str("\\,"),
+            ';' => escaped.push_str("\\;"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_component(text: &str) -> String {
+    escape(text.trim())
+}
+
+// Parameter values are limited to letters, digits and dashes here.
+fn parameter(text: &str) -> String {
+    let value: String = text.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '-').collect();
+    if value.is_empty() { "other".to_string() } else { value.to_ascii_lowercase() }
+}
+
+// `+44 20 7946 0000` becomes `+44-20-7946-0000`: spaces, dots and brackets
+// are not allowed in a tel URI, so each run of them becomes a dash.
+fn tel_uri(phone: &str) -> String {
+    let mut uri = String::new();
+    for c in phone.trim().chars() {
+        if c.is_ascii_digit() || (c == '+' && uri.is_empty()) {
+            uri.push(c);
+        } else if !uri.is_empty() && !uri.ends_with(['-', '+']) {
+            uri.push('-');
+        }
+    }
+    uri.trim_end_matches('-').to_string()
+}
+
+// Folds a content line into chunks of at most 75 octets, never splitting a
+// character; continuation lines start with a space.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut octets = 0;
+    for c in line.chars() {
+        let width = c.len_utf8();
+        if octets + width > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += width;
+    }
+    folded.push_str("\r\n");
+    folded
+}
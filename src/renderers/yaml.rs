@@ -0,0 +1,30 @@
+use serde::Serialize;
+use serde_json::Value;
+use crate::errors::AppError;
+
+pub const YAML_MIME: &str = "application/yaml";
+
+/// Serializes a record as YAML, the way it reads as JSON but with ids as
+/// plain hex strings and without empty values.
+pub fn render_yaml<T: Serialize>(record: &T) -> Result<String, AppError> {
+    let mut document = serde_json::to_value(record)?;
+    simplify(&mut document);
+    serde_yaml::to_string(&document).map_err(|e| AppError::SerializationError(e.to_string()))
+}
+
+/// Replaces `{"$oid": "..."}` with the hex string and drops nulls, which
+/// formats such as TOML cannot represent.
+pub(crate) fn simplify(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(hex)) = object.get("$oid").filter(|_| object.len() == 1) {
+                *value = Value::String(hex.clone());
+                return;
+            }
+            object.retain(|_, value| !value.is_null());
+            object.values_mut().for_each(simplify);
+        }
+        Value::Array(items) => items.iter_mut().for_each(simplify),
+        _ => {}
+    }
+}
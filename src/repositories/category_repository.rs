@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use mongodb::{Client, bson::doc, bson::oid::ObjectId, Collection};
+use crate::errors::AppError;
+use crate::repositories::repository::Repository;
+use futures::TryStreamExt;
+
+#[derive(Clone)]
+pub struct CategoryRepository<'a, T> where T: Send + Sync {
+    pub collection: Collection<T>,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+#[async_trait]
+impl<'a, T> Repository<'a, T> for CategoryRepository<'a, T> 
+where 
+    T: Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned + Unpin,
+    'a: 'static {
+    async fn create(&self, item: T) -> Result<(), AppError> {
+        self.collection.insert_one(item)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to create category: {}", e
+            )))?;
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<T>, AppError> {
+        let filter = doc! {};
+        let mut cursor = self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch categories: {}", e
+            )))?;
+        
+        let mut categories = Vec::new();
+        while let Some(category) = cursor.try_next()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process category cursor: {}", e
+            )))?
+        {
+            categories.push(category);
+        }
+        
+        if categories.is_empty() {
+            return Err(AppError::NotFound(
+                "No categories found".to_string()
+            ));
+        }
+        
+        Ok(categories)
+    }
+
+    async fn get(&self, id: &ObjectId) -> Result<Option<T>, AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.find_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch category with id {}: {}", id, e
+            )))
+    }
+
+    async fn update(&self, id: &ObjectId, item: T) -> Result<(), AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.replace_one(filter, item)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to update category with id {}: {}", id, e
+            )))?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ObjectId) -> Result<(), AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.delete_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to delete category with id {}: {}", id, e
+            )))?;
+        Ok(())
+    }
+
+}
+
+impl<'a, T> CategoryRepository<'a, T> where T: Send + Sync {
+    pub async fn find_by_ids(&self, ids: &[ObjectId]) -> Result<Vec<T>, AppError>
+    where
+        T: serde::de::DeserializeOwned + Unpin,
+    {
+        let filter = doc! { "_id": { "$in": ids } };
+        self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch categories: {}", e
+            )))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process category cursor: {}", e
+            )))
+    }
+
+    #[allow(dead_code)]
+    pub fn new(client: &Client, db_name: &str, collection_name: &str) -> CategoryRepository<'static, T> {
+        let db = client.database(db_name);
+        let collection = db.collection(collection_name);
+        CategoryRepository { 
+            collection,
+            _marker: std::marker::PhantomData
+        }
+    }
+}
@@ -83,6 +83,41 @@ where
 }
 
 impl<'a, T> ExperienceRepository<'a, T> where T: Send + Sync {
+    pub async fn find_by_ids(&self, ids: &[ObjectId]) -> Result<Vec<T>, AppError>
+    where
+        T: serde::de::DeserializeOwned + Unpin,
+    {
+        let filter = doc! { "_id": { "$in": ids } };
+        self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch experiences: {}", e
+            )))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process experience cursor: {}", e
+            )))
+    }
+
+    pub async fn find_by_profile(&self, profile_id: &ObjectId) -> Result<Vec<T>, AppError>
+    where
+        T: serde::de::DeserializeOwned + Unpin,
+    {
+        let filter = doc! { "profile_id": profile_id };
+        self.collection.find(filter)
+            .sort(doc! { "start_date": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch experiences of profile {}: {}", profile_id, e
+            )))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process experience cursor: {}", e
+            )))
+    }
+
     pub async fn add_responsibility(&self, id: &ObjectId, responsibility: crate::models::responsibility::Responsibility) -> Result<(), AppError> {
         let filter = doc! { "_id": id };
         let update = doc! { "$push": { "responsibilities": mongodb::bson::to_bson(&responsibility)
@@ -1,2 +1,8 @@
 pub mod experience_repository;
+pub mod profile_repository;
+pub mod resume_repository;
+pub mod skill_repository;
+pub mod category_repository;
+pub mod snapshot_repository;
+pub mod template_repository;
 pub mod repository;
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use mongodb::{Client, bson::doc, bson::oid::ObjectId, Collection};
+use crate::errors::AppError;
+use crate::repositories::repository::Repository;
+use futures::TryStreamExt;
+
+#[derive(Clone)]
+pub struct ResumeRepository<'a, T> where T: Send + Sync {
+    pub collection: Collection<T>,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+#[async_trait]
+impl<'a, T> Repository<'a, T> for ResumeRepository<'a, T> 
+where 
+    T: Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned + Unpin,
+    'a: 'static {
+    async fn create(&self, item: T) -> Result<(), AppError> {
+        self.collection.insert_one(item)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to create resume: {}", e
+            )))?;
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<T>, AppError> {
+        let filter = doc! {};
+        let mut cursor = self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch resumes: {}", e
+            )))?;
+        
+        let mut resumes = Vec::new();
+        while let Some(resume) = cursor.try_next()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process resume cursor: {}", e
+            )))?
+        {
+            resumes.push(resume);
+        }
+        
+        if resumes.is_empty() {
+            return Err(AppError::NotFound(
+                "No resumes found".to_string()
+            ));
+        }
+        
+        Ok(resumes)
+    }
+
+    async fn get(&self, id: &ObjectId) -> Result<Option<T>, AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.find_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch resume with id {}: {}", id, e
+            )))
+    }
+
+    async fn update(&self, id: &ObjectId, item: T) -> Result<(), AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.replace_one(filter, item)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to update resume with id {}: {}", id, e
+            )))?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ObjectId) -> Result<(), AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.delete_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to delete resume with id {}: {}", id, e
+            )))?;
+        Ok(())
+    }
+
+}
+
+impl<'a, T> ResumeRepository<'a, T> where T: Send + Sync {
+    pub async fn find_by_ids(&self, ids: &[ObjectId]) -> Result<Vec<T>, AppError>
+    where
+        T: serde::de::DeserializeOwned + Unpin,
+    {
+        let filter = doc! { "_id": { "$in": ids } };
+        self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch resumes: {}", e
+            )))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process resume cursor: {}", e
+            )))
+    }
+
+    pub async fn find_by_parent(&self, parent_id: &ObjectId) -> Result<Vec<T>, AppError>
+    where
+        T: serde::de::DeserializeOwned + Unpin,
+    {
+        let filter = doc! { "parent_id": parent_id };
+        self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch variants of resume {}: {}", parent_id, e
+            )))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process resume cursor: {}", e
+            )))
+    }
+
+    #[allow(dead_code)]
+    pub fn new(client: &Client, db_name: &str, collection_name: &str) -> ResumeRepository<'static, T> {
+        let db = client.database(db_name);
+        let collection = db.collection(collection_name);
+        ResumeRepository { 
+            collection,
+            _marker: std::marker::PhantomData
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use mongodb::{Client, bson::doc, bson::oid::ObjectId, Collection};
+use crate::errors::AppError;
+use crate::models::snapshot::ResumeSnapshot;
+use futures::TryStreamExt;
+
+/// Append-only store of resume snapshots: there is deliberately no update
+/// or delete, so a snapshot always reflects what was sent.
+#[derive(Clone)]
+pub struct SnapshotRepository {
+    pub collection: Collection<ResumeSnapshot>,
+}
+
+impl SnapshotRepository {
+    pub fn new(client: &Client, db_name: &str, collection_name: &str) -> SnapshotRepository {
+        let db = client.database(db_name);
+        let collection = db.collection(collection_name);
+        SnapshotRepository { collection }
+    }
+
+    pub async fn create(&self, snapshot: ResumeSnapshot) -> Result<(), AppError> {
+        self.collection.insert_one(snapshot)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to create snapshot: {}", e
+            )))?;
+        Ok(())
+    }
+
+    pub async fn find_by_resume(&self, resume_id: &ObjectId) -> Result<Vec<ResumeSnapshot>, AppError> {
+        let filter = doc! { "resume_id": resume_id };
+        self.collection.find(filter)
+            .sort(doc! { "created_at": 1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch snapshots of resume {}: {}", resume_id, e
+            )))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process snapshot cursor: {}", e
+            )))
+    }
+
+    pub async fn get(&self, resume_id: &ObjectId, id: &ObjectId) -> Result<Option<ResumeSnapshot>, AppError> {
+        let filter = doc! { "_id": id, "resume_id": resume_id };
+        self.collection.find_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch snapshot with id {}: {}", id, e
+            )))
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use mongodb::{Client, bson::doc, bson::oid::ObjectId, Collection};
+use crate::errors::AppError;
+use crate::repositories::repository::Repository;
+use futures::TryStreamExt;
+
+#[derive(Clone)]
+pub struct TemplateRepository<'a, T> where T: Send + Sync {
+    pub collection: Collection<T>,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+#[async_trait]
+impl<'a, T> Repository<'a, T> for TemplateRepository<'a, T> 
+where 
+    T: Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned + Unpin,
+    'a: 'static {
+    async fn create(&self, item: T) -> Result<(), AppError> {
+        self.collection.insert_one(item)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to create template: {}", e
+            )))?;
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<T>, AppError> {
+        let filter = doc! {};
+        let mut cursor = self.collection.find(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch templates: {}", e
+            )))?;
+        
+        let mut templates = Vec::new();
+        while let Some(template) = cursor.try_next()
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to process template cursor: {}", e
+            )))?
+        {
+            templates.push(template);
+        }
+        
+        if templates.is_empty() {
+            return Err(AppError::NotFound(
+                "No templates found".to_string()
+            ));
+        }
+        
+        Ok(templates)
+    }
+
+    async fn get(&self, id: &ObjectId) -> Result<Option<T>, AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.find_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to fetch template with id {}: {}", id, e
+            )))
+    }
+
+    async fn update(&self, id: &ObjectId, item: T) -> Result<(), AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.replace_one(filter, item)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to update template with id {}: {}", id, e
+            )))?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ObjectId) -> Result<(), AppError> {
+        let filter = doc! { "_id": id };
+        self.collection.delete_one(filter)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!(
+                "Failed to delete template with id {}: {}", id, e
+            )))?;
+        Ok(())
+    }
+
+}
+
+impl<'a, T> TemplateRepository<'a, T> where T: Send + Sync {
+    #[allow(dead_code)]
+    pub fn new(client: &Client, db_name: &str, collection_name: &str) -> TemplateRepository<'static, T> {
+        let db = client.database(db_name);
+        let collection = db.collection(collection_name);
+        TemplateRepository { 
+            collection,
+            _marker: std::marker::PhantomData
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, HashSet};
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::models::ats::{AtsReport, Finding, KeywordCoverage, Severity};
+use crate::models::resume::{ResolvedResume, SectionKind};
+use crate::services::relevance::{extract_keywords, tokenize};
+
+pub const CONTACT_MISSING: &str = "contact-missing";
+pub const SECTION_MISSING: &str = "section-missing";
+pub const BULLETS_MISSING: &str = "bullets-missing";
+pub const BULLET_TOO_LONG: &str = "bullet-too-long";
+pub const NON_ASCII: &str = "non-ascii";
+pub const DATE_FORMAT_MIXED: &str = "date-format-mixed";
+pub const DATE_RANGE_INVALID: &str = "date-range-invalid";
+pub const KEYWORD_MISSING: &str = "keyword-missing";
+
+/// Longest bullet, in characters, that reads well once parsed.
+pub const MAX_BULLET_LENGTH: usize = 200;
+
+// Most points a single rule can take off the score.
+const MAX_RULE_PENALTY: u32 = 20;
+
+lazy_static! {
+    static ref DATE_FORMATS: Vec<(&'static str, Regex)> = vec![
+        ("YYYY-MM", Regex::new(r"\b\d{4}-\d{2}(?:-\d{2})?\b").unwrap()),
+        ("MM/YYYY", Regex::new(r"\b\d{1,2}/(?:\d{1,2}/)?\d{4}\b").unwrap()),
+        ("Month YYYY", Regex::new(r"(?i)\b(?:jan|feb|mar|apr|may|jun|jul|aug|sep|sept|oct|nov|dec)[a-z]*\.? \d{4}\b").unwrap()),
+    ];
+}
+
+/// Checks how well a resolved resume survives an applicant tracking
+/// system: contact details and standard sections, bullets, dates written
+/// in the text, characters parsers mangle and, given a job description,
+/// how many of its keywords appear.
+///
+/// The score starts at 100 and loses 15 points per error, 5 per warning
+/// and 2 per note, at most 20 per rule. With a job description, it is 60%
+/// that and 40% the keyword coverage.
+pub fn ats_report(resume: &ResolvedResume, job_description: Option<&str>) -> AtsReport {
+    let mut findings = Vec::new();
+    check_sections(resume, &mut findings);
+
+    let mut dates: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut texts: Vec<(String, String)> = Vec::new();
+    texts.extend(resume.profile.label.iter().map(|label| ("profile.label".to_string(), label.clone())));
+    texts.extend(resume.summary.iter().map(|summary| ("summary".to_string(), summary.clone())));
+    for (i, experience) in resume.experiences.iter().enumerate() {
+        let at = format!("experiences[{}]", i);
+        if experience.end_date < experience.start_date {
+            findings.push(finding(DATE_RANGE_INVALID, Severity::Error, Some(&at), format!(
+                "{} at {} ends ({}) before it starts ({})",
+                experience.job_title, experience.company, experience.end_date, experience.start_date
+            )));
+        }
+        texts.push((format!("{}.job_title", at), experience.job_title.clone()));
+        texts.push((format!("{}.company", at), experience.company.clone()));
+        if experience.responsibilities.is_empty() {
+            findings.push(finding(BULLETS_MISSING, Severity::Warning, Some(&at), format!(
+                "{} at {} has no bullets; add what you did and achieved", experience.job_title, experience.company
+            )));
+        }
+        for (j, responsibility) in experience.responsibilities.iter().enumerate() {
+            let at = format!("{}.responsibilities[{}]", at, j);
+            let bullet = responsibility.bullet();
+            let length = bullet.chars().count();
+            if length > MAX_BULLET_LENGTH {
+                findings.push(finding(BULLET_TOO_LONG, Severity::Warning, Some(&at), format!(
+                    "Bullet is {} characters long; keep it under {} or split it", length, MAX_BULLET_LENGTH
+                )));
+            }
+            texts.push((at, bullet));
+        }
+    }
+    for (i, education) in resume.profile.education.iter().enumerate() {
+        if let (Some(start), Some(end)) = (&education.start_date, &education.end_date) {
+            if end < start {
+                findings.push(finding(DATE_RANGE_INVALID, Severity::Error, Some(&format!("profile.education[{}]", i)), format!(
+                    "{} ends ({}) before it starts ({})", education.institution, end, start
+                )));
+            }
+        }
+    }
+    texts.extend(resume.skills.iter().enumerate().map(|(i, skill)| (format!("skills[{}]", i), skill.name.clone())));
+
+    for (at, text) in &texts {
+        for (format, pattern) in DATE_FORMATS.iter() {
+            for date in pattern.find_iter(text) {
+                dates.entry(format).or_default().push(format!("'{}' ({})", date.as_str(), at));
+            }
+        }
+        let mut seen = HashSet::new();
+        let odd: Vec<char> = text.chars()
+            .filter(|c| !c.is_ascii() && !c.is_alphanumeric() && seen.insert(*c))
+            .collect();
+        if !odd.is_empty() {
+            let replacements: Vec<String> = odd.iter()
+                .map(|c| format!("'{}' with '{}'", c, deunicode::deunicode_char(*c).unwrap_or("").trim()))
+                .collect();
+            findings.push(finding(NON_ASCII, Severity::Warning, Some(at), format!(
+                "Replace {}; some parsers drop or garble these characters", replacements.join(", ")
+            )));
+        }
+    }
+    if dates.len() > 1 {
+        let examples: Vec<String> = dates.iter()
+            .map(|(format, found)| format!("{} as in {}", format, found[0]))
+            .collect();
+        findings.push(finding(DATE_FORMAT_MIXED, Severity::Warning, None, format!(
+            "Dates are written in {} formats ({}); use one format throughout", dates.len(), examples.join("; ")
+        )));
+    }
+
+    let keywords = job_description.map(|description| {
+        let coverage = keyword_coverage(&texts, resume, description);
+        if !coverage.missing.is_empty() {
+            findings.push(finding(KEYWORD_MISSING, Severity::Warning, None, format!(
+                "The job description mentions {} not found in the resume; add those you have experience with",
+                coverage.missing.iter().take(10).map(|k| format!("'{}'", k)).collect::<Vec<_>>().join(", ")
+            )));
+        }
+        coverage
+    });
+
+    findings.sort_by_key(|finding| finding.severity);
+    AtsReport { score: score(&findings, keywords.as_ref()), keywords, findings }
+}
+
+fn check_sections(resume: &ResolvedResume, findings: &mut Vec<Finding>) {
+    let profile = &resume.profile;
+    if profile.email.is_none() {
+        findings.push(finding(CONTACT_MISSING, Severity::Error, Some("profile.email"), "Add an email address so recruiters can reach you".to_string()));
+    }
+    if profile.phone.is_none() {
+        findings.push(finding(CONTACT_MISSING, Severity::Warning, Some("profile.phone"), "Add a phone number".to_string()));
+    }
+    let has = |kind: SectionKind| resume.sections.iter().any(|section| section.kind == kind);
+    if !has(SectionKind::Experience) || resume.experiences.is_empty() {
+        findings.push(finding(SECTION_MISSING, Severity::Error, Some("experiences"), "Add an experience section with at least one job".to_string()));
+    }
+    if !has(SectionKind::Summary) || resume.summary.as_deref().is_none_or(|s| s.trim().is_empty()) {
+        findings.push(finding(SECTION_MISSING, Severity::Warning, Some("summary"), "Add a summary section".to_string()));
+    }
+    if !has(SectionKind::Skills) || resume.skills.is_empty() {
+        findings.push(finding(SECTION_MISSING, Severity::Warning, Some("skills"), "Add a skills section".to_string()));
+    }
+    if profile.education.is_empty() {
+        findings.push(finding(SECTION_MISSING, Severity::Warning, Some("profile.education"), "Add your education".to_string()));
+    }
+}
+
+// The job description's technology terms and the words it repeats (all
+// of its words when it has neither), looked up in the resume's text.
+fn keyword_coverage(texts: &[(String, String)], resume: &ResolvedResume, description: &str) -> KeywordCoverage {
+    let all = extract_keywords(description);
+    let significant: Vec<_> = all.iter().filter(|k| k.tech || k.count > 1).cloned().collect();
+    let keywords = if significant.is_empty() { all } else { significant };
+
+    let mut text: Vec<&str> = texts.iter().map(|(_, text)| text.as_str()).collect();
+    let environment = resume.experiences.iter().flat_map(|e| &e.environment).map(|s| s.name.as_str());
+    text.extend(environment);
+    text.extend(resume.profile.certificates.iter().map(|c| c.name.as_str()));
+    text.extend(resume.profile.education.iter().filter_map(|e| e.area.as_deref()));
+    let found: HashSet<String> = tokenize(&text.join("\n")).into_iter().map(|token| token.key).collect();
+
+    let (matched, missing): (Vec<_>, Vec<_>) = keywords.into_iter().partition(|k| found.contains(&k.key));
+    let total = matched.len() + missing.len();
+    KeywordCoverage {
+        coverage: if total == 0 { 1.0 } else { (matched.len() as f64 / total as f64 * 1000.0).round() / 1000.0 },
+        matched: matched.into_iter().map(|k| k.term).collect(),
+        missing: missing.into_iter().map(|k| k.term).collect(),
+    }
+}
+
+fn score(findings: &[Finding], keywords: Option<&KeywordCoverage>) -> u8 {
+    let mut penalties: BTreeMap<&str, u32> = BTreeMap::new();
+    for finding in findings {
+        let points = match finding.severity {
+            Severity::Error => 15,
+            Severity::Warning => 5,
+            Severity::Info => 2,
+        };
+        *penalties.entry(&finding.rule).or_default() += points;
+    }
+    let penalty: u32 = penalties.values().map(|points| (*points).min(MAX_RULE_PENALTY)).sum();
+    let structure = 100.0 - penalty.min(100) as f64;
+    let score = match keywords {
+        Some(keywords) => 0.6 * structure + 0.4 * keywords.coverage * 100.0,
+        None => structure,
+    };
+    score.round() as u8
+}
+
+fn finding(rule: &str, severity: Severity, location: Option<&str>, message: String) -> Finding {
+    Finding { rule: rule.to_string(), severity, message, location: location.map(str::to_string) }
+}
@@ -0,0 +1,26 @@
+use mongodb::bson::oid::ObjectId;
+use crate::models::category::Category;
+use crate::repositories::category_repository::CategoryRepository;
+use crate::repositories::repository::Repository;
+use crate::errors::AppError;
+
+#[derive(Clone)]
+pub struct CategoryService {
+    repository: CategoryRepository<'static, Category>,
+}
+
+impl CategoryService {
+    pub fn new(repository: CategoryRepository<'static, Category>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn create_category(&self, mut category: Category) -> Result<Category, AppError> {
+        category.id.get_or_insert_with(ObjectId::new);
+        self.repository.create(category.clone()).await?;
+        Ok(category)
+    }
+
+    pub async fn get_categories(&self) -> Result<Vec<Category>, AppError> {
+        self.repository.find_all().await
+    }
+}
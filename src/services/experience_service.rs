@@ -1,6 +1,8 @@
 use mongodb::bson::oid::ObjectId;
+use crate::models::experience::Experience;
 use crate::models::responsibility::Responsibility;
 use crate::models::skill::Skill;
+use crate::models::story::Story;
 use crate::repositories::experience_repository::ExperienceRepository;
 use crate::repositories::repository::Repository;
 use crate::errors::AppError;
@@ -46,13 +48,35 @@ where
         self.repository.delete(&object_id).await
     }
 
-    pub async fn add_responsibility(&self, id: &str, responsibility: Responsibility) -> Result<(), AppError> {
+    pub async fn add_responsibility(&self, id: &str, mut responsibility: Responsibility) -> Result<(), AppError> {
         let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        // Resumes select responsibilities by id, so every stored one needs one
+        responsibility.id.get_or_insert_with(ObjectId::new);
         self.repository.add_responsibility(&object_id, responsibility).await
     }
 
+    pub async fn get_experiences_by_ids(&self, ids: &[ObjectId]) -> Result<Vec<T>, AppError> {
+        self.repository.find_by_ids(ids).await
+    }
+
+    /// Experiences of a profile, most recent first.
+    pub async fn get_experiences_by_profile(&self, profile_id: &ObjectId) -> Result<Vec<T>, AppError> {
+        self.repository.find_by_profile(profile_id).await
+    }
+
     pub async fn add_environment(&self, id: &str, environment: Skill) -> Result<(), AppError> {
         let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
         self.repository.add_environment(&object_id, environment).await
     }
 }
+
+impl ExperienceService<'static, Experience> {
+    pub async fn get_stories(&self, skill: Option<&str>) -> Result<Vec<Story>, AppError> {
+        let experiences = match self.repository.find_all().await {
+            Ok(experiences) => experiences,
+            Err(AppError::NotFound(_)) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(Story::collect(&experiences, skill))
+    }
+}
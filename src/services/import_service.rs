@@ -0,0 +1,413 @@
+use chrono::Utc;
+use mongodb::bson::oid::ObjectId;
+use serde_json::Value;
+use crate::importers::{EntityKind, ImportReport, ImportedEntity, MappedImport};
+use crate::importers::europass::map_europass;
+use crate::importers::experience_csv::{map_experience_csv, CsvImportMode};
+use crate::importers::json_resume::map_json_resume;
+use crate::importers::linkedin::{map_linkedin, same_position, LinkedInImport, CERTIFICATIONS_FILE, EDUCATION_FILE, PROFILE_FILE};
+use crate::models::category::Category;
+use crate::models::certificate::Certificate;
+use crate::models::education::Education;
+use crate::models::experience::Experience;
+use crate::models::ordering::relink;
+use crate::models::profile::Profile;
+use crate::models::responsibility::Responsibility;
+use crate::models::skill::Skill;
+use crate::services::category_service::CategoryService;
+use crate::services::experience_service::ExperienceService;
+use crate::services::profile_service::ProfileService;
+use crate::services::skill_service::SkillService;
+use crate::errors::AppError;
+
+/// Stores records mapped by the importers through the regular services.
+#[derive(Clone)]
+pub struct ImportService {
+    profile_service: ProfileService,
+    experience_service: ExperienceService<'static, Experience>,
+    skill_service: SkillService,
+    category_service: CategoryService,
+}
+
+impl ImportService {
+    pub fn new(
+        profile_service: ProfileService,
+        experience_service: ExperienceService<'static, Experience>,
+        skill_service: SkillService,
+        category_service: CategoryService,
+    ) -> Self {
+        Self { profile_service, experience_service, skill_service, category_service }
+    }
+
+    /// Imports a JSON Resume document. With `dry_run` nothing is stored and
+    /// the report lists what would have been created.
+    pub async fn import_json_resume(&self, document: &Value, dry_run: bool) -> Result<ImportReport, AppError> {
+        let import = map_json_resume(document, Utc::now().date_naive());
+        self.store(import, "basics", dry_run).await
+    }
+
+    /// Imports a Europass CV, like [`ImportService::import_json_resume`].
+    pub async fn import_europass(&self, xml: &str, dry_run: bool) -> Result<ImportReport, AppError> {
+        let import = map_europass(xml, Utc::now().date_naive());
+        self.store(import, "LearnerInfo.Identification", dry_run).await
+    }
+
+    /// Imports a LinkedIn data export into the profile `profile_id`, or else
+    /// into the profile of `Profile.csv`, reusing a stored profile with the
+    /// same name. Rows already present are reported as existing instead of
+    /// being stored twice: skills by name, the profile's experiences by
+    /// company, title and start date, education by institution, degree and start date and
+    /// certificates by name and issuer.
+    pub async fn import_linkedin(&self, archive: &[u8], profile_id: Option<&str>, dry_run: bool) -> Result<ImportReport, AppError> {
+        let LinkedInImport { mapped, education, certificates } = map_linkedin(archive, Utc::now().date_naive());
+        let mut report = mapped.report;
+        report.dry_run = dry_run;
+
+        self.store_skills(mapped.skills, &mut report).await?;
+
+        let target = match (profile_id, mapped.profile) {
+            (Some(id), imported) => {
+                let profile = self.profile_service.get_profile(id)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound(format!("Profile {} not found", id)))?;
+                if imported.is_some() {
+                    report.warnings.push(format!("{}: ignored, importing into profile {}", PROFILE_FILE, id));
+                }
+                Some((profile, false))
+            }
+            (None, Some(imported)) => {
+                let profiles = or_empty(self.profile_service.get_profiles().await)?;
+                match profiles.into_iter().find(|p| p.name.eq_ignore_ascii_case(&imported.name)) {
+                    Some(existing) => {
+                        push_existing(&mut report, EntityKind::Profile, existing.id, &existing.name, PROFILE_FILE);
+                        Some((existing, false))
+                    }
+                    None => Some((imported, true)),
+                }
+            }
+            (None, None) => None,
+        };
+
+        let profile_id = match target {
+            Some((mut profile, created)) => {
+                let added = merge_education(&mut profile, education, &mut report)
+                    + merge_certificates(&mut profile, certificates, &mut report);
+                if created {
+                    Some(self.store_profile(profile, PROFILE_FILE, &mut report).await?)
+                } else {
+                    let id = profile.id.ok_or_else(|| AppError::InternalServerError("Stored profile has no id".to_string()))?;
+                    if added > 0 && !dry_run {
+                        self.profile_service.update_profile(&id.to_hex(), profile).await?;
+                    }
+                    Some(id)
+                }
+            }
+            None => {
+                if !education.is_empty() {
+                    report.warnings.push(format!("{}: skipped because no profile was imported", EDUCATION_FILE));
+                }
+                if !certificates.is_empty() {
+                    report.warnings.push(format!("{}: skipped because no profile was imported", CERTIFICATIONS_FILE));
+                }
+                None
+            }
+        };
+
+        // Only the target profile's roles count as already imported
+        let mut known = match profile_id {
+            Some(id) => self.experience_service.get_experiences_by_profile(&id).await?,
+            None => or_empty(self.experience_service.get_experiences().await)?
+                .into_iter()
+                .filter(|e| e.profile_id.is_none())
+                .collect(),
+        };
+        for (source, mut experience) in mapped.experiences {
+            experience.profile_id = profile_id;
+            if let Some(existing) = known.iter().find(|e| same_position(e, &experience)) {
+                let name = format!("{} at {}", existing.job_title, existing.company);
+                push_existing(&mut report, EntityKind::Experience, existing.id, &name, &source);
+                continue;
+            }
+            let id = self.store_experience(experience.clone(), &source, &mut report).await?;
+            experience.id = Some(id);
+            known.push(experience);
+        }
+        Ok(report)
+    }
+
+    /// Imports an experience spreadsheet. A row whose `id`, or else company,
+    /// title and start date, matches a stored experience updates it, keeping
+    /// the stored responsibilities (and their stories) whose name is
+    /// unchanged; other rows create experiences. Skills are looked up by
+    /// name and created when missing. In [`CsvImportMode::AllOrNothing`]
+    /// nothing is stored if any row fails.
+    pub async fn import_experience_csv(&self, text: &str, mode: CsvImportMode, dry_run: bool) -> Result<ImportReport, AppError> {
+        let mut report = ImportReport { dry_run, ..ImportReport::default() };
+        let rows = map_experience_csv(text, &mut report);
+        let mut known = or_empty(self.experience_service.get_experiences().await)?;
+
+        let mut matched = Vec::new();
+        for row in rows {
+            let index = match row.experience.id {
+                Some(id) => match known.iter().position(|e| e.id == Some(id)) {
+                    Some(index) => Some(index),
+                    None => {
+                        report.fail(&row.source, vec![format!("id: no experience with id {}", id)]);
+                        continue;
+                    }
+                },
+                None => known.iter().position(|e| {
+                    e.company.eq_ignore_ascii_case(&row.experience.company)
+                        && e.job_title.eq_ignore_ascii_case(&row.experience.job_title)
+                        && e.start_date == row.experience.start_date
+                }),
+            };
+            matched.push((row, index));
+        }
+        if mode == CsvImportMode::AllOrNothing && !report.failures.is_empty() {
+            return Ok(report);
+        }
+
+        let mut names: Vec<(String, Skill)> = Vec::new();
+        for (row, _) in &matched {
+            for name in &row.skills {
+                if !names.iter().any(|(_, skill)| skill.name.eq_ignore_ascii_case(name)) {
+                    let skill = Skill { id: None, name: name.clone(), description: name.clone(), categories: vec![], next: None };
+                    names.push((row.source.clone(), skill));
+                }
+            }
+        }
+        let skills = self.store_skills(names, &mut report).await?;
+
+        for (row, index) in matched {
+            let mut experience = row.experience;
+            experience.environment = row.skills.iter()
+                .filter_map(|name| skills.iter().find(|skill| skill.name.eq_ignore_ascii_case(name)).cloned())
+                .collect();
+            match index {
+                Some(index) => {
+                    let stored = &mut known[index];
+                    let id = stored.id.ok_or_else(|| AppError::InternalServerError("Stored experience has no id".to_string()))?;
+                    experience.id = Some(id);
+                    if experience.profile_id.is_none() {
+                        experience.profile_id = stored.profile_id;
+                    }
+                    experience.responsibilities = keep_responsibilities(&stored.responsibilities, experience.responsibilities);
+                    report.updated.push(ImportedEntity {
+                        entity: EntityKind::Experience,
+                        id: Some(id),
+                        name: format!("{} at {}", experience.job_title, experience.company),
+                        source: Some(row.source),
+                    });
+                    if !dry_run {
+                        self.experience_service.update_experience(&id.to_hex(), experience.clone()).await?;
+                    }
+                    *stored = experience;
+                }
+                None => {
+                    let id = self.store_experience(experience.clone(), &row.source, &mut report).await?;
+                    experience.id = Some(id);
+                    known.push(experience);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    // Stores skills first so experiences and the profile can be linked as
+    // they are created.
+    async fn store(&self, import: MappedImport, profile_source: &str, dry_run: bool) -> Result<ImportReport, AppError> {
+        let mut report = import.report;
+        report.dry_run = dry_run;
+
+        self.store_skills(import.skills, &mut report).await?;
+
+        let profile_id = match import.profile {
+            Some(profile) => Some(self.store_profile(profile, profile_source, &mut report).await?),
+            None => None,
+        };
+
+        for (source, mut experience) in import.experiences {
+            experience.profile_id = profile_id;
+            self.store_experience(experience, &source, &mut report).await?;
+        }
+        Ok(report)
+    }
+
+    pub(crate) async fn store_profile(
+        &self,
+        mut profile: Profile,
+        source: &str,
+        report: &mut ImportReport,
+    ) -> Result<ObjectId, AppError> {
+        let id = *profile.id.get_or_insert_with(ObjectId::new);
+        record(report, EntityKind::Profile, id, &profile.name, source);
+        if !report.dry_run {
+            self.profile_service.create_profile(profile).await?;
+        }
+        Ok(id)
+    }
+
+    pub(crate) async fn store_experience(
+        &self,
+        mut experience: Experience,
+        source: &str,
+        report: &mut ImportReport,
+    ) -> Result<ObjectId, AppError> {
+        let id = *experience.id.get_or_insert_with(ObjectId::new);
+        let name = format!("{} at {}", experience.job_title, experience.company);
+        record(report, EntityKind::Experience, id, &name, source);
+        if !report.dry_run {
+            self.experience_service.create_experience(experience).await?;
+        }
+        Ok(id)
+    }
+
+    /// Stores skills, reusing skills and categories that already exist with
+    /// the same name (case-insensitive). Returns the stored or reused skills.
+    pub(crate) async fn store_skills(
+        &self,
+        skills: Vec<(String, Skill)>,
+        report: &mut ImportReport,
+    ) -> Result<Vec<Skill>, AppError> {
+        let mut known_categories = or_empty(self.category_service.get_categories().await)?;
+        let mut known_skills = or_empty(self.skill_service.get_skills().await)?;
+        let mut stored = Vec::new();
+
+        for (source, mut skill) in skills {
+            if let Some(existing) = known_skills.iter().find(|s| s.name.eq_ignore_ascii_case(&skill.name)) {
+                push_existing(report, EntityKind::Skill, existing.id, &existing.name, &source);
+                stored.push(existing.clone());
+                continue;
+            }
+
+            let mut categories = Vec::new();
+            for category in skill.categories {
+                match known_categories.iter().find(|c| c.name.eq_ignore_ascii_case(&category.name)) {
+                    Some(existing) => {
+                        if !report.existing.iter().any(|e| e.entity == EntityKind::Category && e.id == existing.id) {
+                            push_existing(report, EntityKind::Category, existing.id, &existing.name, &source);
+                        }
+                        categories.push(existing.clone());
+                    }
+                    None => {
+                        let created = Category { id: Some(ObjectId::new()), name: category.name };
+                        record(report, EntityKind::Category, created.id.unwrap(), &created.name, &source);
+                        if !report.dry_run {
+                            self.category_service.create_category(created.clone()).await?;
+                        }
+                        known_categories.push(created.clone());
+                        categories.push(created);
+                    }
+                }
+            }
+
+            skill.categories = categories;
+            let id = *skill.id.get_or_insert_with(ObjectId::new);
+            record(report, EntityKind::Skill, id, &skill.name, &source);
+            if !report.dry_run {
+                self.skill_service.create_skill(skill.clone()).await?;
+            }
+            known_skills.push(skill.clone());
+            stored.push(skill);
+        }
+        Ok(stored)
+    }
+}
+
+// Responsibilities as listed in an import, reusing the stored one of the
+// same name so that its id and story survive, and relinked in the new order.
+fn keep_responsibilities(stored: &[Responsibility], imported: Vec<Responsibility>) -> Vec<Responsibility> {
+    let mut unused: Vec<&Responsibility> = stored.iter().collect();
+    let mut kept: Vec<Responsibility> = imported.into_iter()
+        .map(|responsibility| match unused.iter().position(|r| r.name == responsibility.name) {
+            Some(index) => unused.remove(index).clone(),
+            None => responsibility,
+        })
+        .collect();
+    relink(&mut kept);
+    kept
+}
+
+// Adds the education entries the profile does not have yet; returns how
+// many were added.
+fn merge_education(profile: &mut Profile, education: Vec<(String, Education)>, report: &mut ImportReport) -> usize {
+    let mut added = 0;
+    for (source, entry) in education {
+        let name = match &entry.study_type {
+            Some(study_type) => format!("{} at {}", study_type, entry.institution),
+            None => entry.institution.clone(),
+        };
+        let same = |e: &Education| e.institution.eq_ignore_ascii_case(&entry.institution)
+            && same_text(e.study_type.as_deref(), entry.study_type.as_deref())
+            && e.start_date == entry.start_date;
+        if profile.education.iter().any(same) {
+            push_existing(report, EntityKind::Education, None, &name, &source);
+        } else {
+            record_embedded(report, EntityKind::Education, &name, &source);
+            profile.education.push(entry);
+            added += 1;
+        }
+    }
+    added
+}
+
+fn merge_certificates(profile: &mut Profile, certificates: Vec<(String, Certificate)>, report: &mut ImportReport) -> usize {
+    let mut added = 0;
+    for (source, certificate) in certificates {
+        let same = |c: &Certificate| c.name.eq_ignore_ascii_case(&certificate.name)
+            && same_text(c.issuer.as_deref(), certificate.issuer.as_deref());
+        if profile.certificates.iter().any(same) {
+            push_existing(report, EntityKind::Certificate, None, &certificate.name, &source);
+        } else {
+            record_embedded(report, EntityKind::Certificate, &certificate.name, &source);
+            profile.certificates.push(certificate);
+            added += 1;
+        }
+    }
+    added
+}
+
+fn same_text(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (a, b) => a == b,
+    }
+}
+
+// Collections report an empty listing as NotFound; importers treat it as empty.
+pub(crate) fn or_empty<T>(result: Result<Vec<T>, AppError>) -> Result<Vec<T>, AppError> {
+    match result {
+        Err(AppError::NotFound(_)) => Ok(Vec::new()),
+        other => other,
+    }
+}
+
+fn record(report: &mut ImportReport, entity: EntityKind, id: ObjectId, name: &str, source: &str) {
+    report.created.push(ImportedEntity {
+        entity,
+        // Nothing is stored on a dry run, so there is no id to point to
+        id: Some(id).filter(|_| !report.dry_run),
+        name: name.to_string(),
+        source: Some(source.to_string()),
+    });
+}
+
+// Entries embedded in a profile have no id of their own.
+fn record_embedded(report: &mut ImportReport, entity: EntityKind, name: &str, source: &str) {
+    report.created.push(ImportedEntity {
+        entity,
+        id: None,
+        name: name.to_string(),
+        source: Some(source.to_string()),
+    });
+}
+
+fn push_existing(report: &mut ImportReport, entity: EntityKind, id: Option<ObjectId>, name: &str, source: &str) {
+    report.existing.push(ImportedEntity {
+        entity,
+        id,
+        name: name.to_string(),
+        source: Some(source.to_string()),
+    });
+}
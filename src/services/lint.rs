@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+use chrono::NaiveDate;
+use lazy_static::lazy_static;
+use regex::{Match, Regex};
+use crate::models::ats::Severity;
+use crate::models::experience::Experience;
+use crate::models::lint::{LintFinding, LintReport, ResponsibilityLint, Span};
+use crate::errors::AppError;
+
+pub const WEAK_OPENING: &str = "weak-opening";
+pub const ACTION_VERB_MISSING: &str = "action-verb-missing";
+pub const FIRST_PERSON: &str = "first-person";
+pub const PASSIVE_VOICE: &str = "passive-voice";
+pub const NO_NUMBERS: &str = "no-numbers";
+pub const PRESENT_TENSE: &str = "present-tense";
+
+pub const RULES: [&str; 6] = [WEAK_OPENING, ACTION_VERB_MISSING, FIRST_PERSON, PASSIVE_VOICE, NO_NUMBERS, PRESENT_TENSE];
+
+// Irregular past participles that differ from the simple past.
+const PARTICIPLES: &[&str] = &[
+    "begun", "chosen", "done", "driven", "given", "grown", "known", "run", "seen", "shown", "taken", "written",
+    "made", "held", "kept", "sent", "set", "put", "paid", "sold", "found", "brought",
+];
+
+lazy_static! {
+    // Base form to simple past
+    static ref VERBS: HashMap<String, String> = {
+        let mut verbs = HashMap::new();
+        for line in include_str!("../../assets/dictionaries/action_verbs.txt").lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((base, past)) = line.split_once('|') {
+                verbs.insert(base.trim().to_string(), past.trim().to_string());
+            }
+        }
+        verbs
+    };
+    static ref PAST: HashSet<String> = VERBS.values().cloned().collect();
+    static ref WORD_REGEX: Regex = Regex::new(r"[\p{L}\p{N}][\p{L}\p{N}'’-]*").unwrap();
+    static ref WEAK_OPENING_REGEX: Regex = Regex::new(
+        r"(?i)^\W*(responsible for|duties includ(?:e|ed)|tasked with|in charge of|involved in|participated in|worked (?:on|with)|helped(?: to)?|assisted(?: with| in)?|handled)\b"
+    ).unwrap();
+    static ref FIRST_PERSON_REGEX: Regex = Regex::new(
+        r"(?i)\b(?:i|me|my|mine|myself|we|us|our|ours|ourselves)\b"
+    ).unwrap();
+    static ref PASSIVE_REGEX: Regex = Regex::new(
+        r"(?i)\b(?:am|is|are|was|were|be|been|being)\s+(?:\w+ly\s+)?(\w+)\b"
+    ).unwrap();
+}
+
+enum Tense {
+    Past,
+    // Present or `-ing` form, with the verb's simple past
+    Present(String),
+}
+
+/// Checks responsibility bullets for phrasing that weakens a resume. Rules
+/// can be switched off by id; all are on by default.
+#[derive(Debug, Clone, Default)]
+pub struct Linter {
+    disabled: HashSet<&'static str>,
+}
+
+impl Linter {
+    /// A linter with the given rules disabled, rejecting unknown rule ids.
+    pub fn new(disabled: &[String]) -> Result<Self, AppError> {
+        let disabled = disabled.iter()
+            .map(|rule| RULES.iter().copied().find(|known| known == rule).ok_or_else(|| AppError::ValidationError(format!(
+                "Unsupported lint rule '{}'. Supported lint rules: {}", rule, RULES.join(", ")
+            ))))
+            .collect::<Result<_, _>>()?;
+        Ok(Linter { disabled })
+    }
+
+    pub fn enabled(&self, rule: &str) -> bool {
+        !self.disabled.contains(rule)
+    }
+
+    /// Lints one bullet, ordered by where each finding starts. `past_role`
+    /// enables the present tense check.
+    pub fn lint(&self, text: &str, past_role: bool) -> Vec<LintFinding> {
+        let mut findings = Vec::new();
+        let weak = WEAK_OPENING_REGEX.captures(text).and_then(|captures| captures.get(1));
+        if let Some(phrase) = weak {
+            self.push(&mut findings, WEAK_OPENING, Severity::Warning, text, phrase, format!(
+                "'{}' describes a duty rather than what you achieved; open with an action verb", phrase.as_str()
+            ));
+        }
+        if let Some(first) = WORD_REGEX.find(text).filter(|_| weak.is_none()) {
+            match tense(first.as_str()) {
+                None => self.push(&mut findings, ACTION_VERB_MISSING, Severity::Warning, text, first, format!(
+                    "Start with an action verb, such as 'Built', 'Led' or 'Reduced', instead of '{}'", first.as_str()
+                )),
+                Some(Tense::Present(past)) if past_role => self.push(&mut findings, PRESENT_TENSE, Severity::Warning, text, first, format!(
+                    "This role has ended; write '{}' instead of '{}'", capitalize(&past), first.as_str()
+                )),
+                Some(_) => {}
+            }
+        }
+        for pronoun in FIRST_PERSON_REGEX.find_iter(text) {
+            // `US` is the country and `I/O` input/output
+            if pronoun.as_str() == "US" || text[pronoun.end()..].starts_with('/') {
+                continue;
+            }
+            self.push(&mut findings, FIRST_PERSON, Severity::Warning, text, pronoun, format!(
+                "Leave out '{}'; resume bullets are written without pronouns", pronoun.as_str()
+            ));
+        }
+        for captures in PASSIVE_REGEX.captures_iter(text) {
+            let verb = captures[1].to_lowercase();
+            if (verb.ends_with("ed") && verb.len() > 4) || PAST.contains(&verb) || PARTICIPLES.contains(&verb.as_str()) {
+                let phrase = captures.get(0).unwrap();
+                self.push(&mut findings, PASSIVE_VOICE, Severity::Info, text, phrase, format!(
+                    "'{}' is in the passive voice; say what you did", phrase.as_str()
+                ));
+            }
+        }
+        if !text.chars().any(|c| c.is_ascii_digit()) && self.enabled(NO_NUMBERS) {
+            findings.push(LintFinding {
+                rule: NO_NUMBERS.to_string(),
+                severity: Severity::Info,
+                message: "Add a number, such as a percentage, amount or team size, to show the impact".to_string(),
+                span: Span { start: 0, end: text.chars().count() },
+            });
+        }
+        findings.sort_by_key(|finding| finding.span.start);
+        findings
+    }
+
+    /// Lints each responsibility of an experience as its resume bullet, in
+    /// the order they were given. Roles that ended before `today` are past.
+    pub fn lint_experience(&self, experience: &Experience, today: NaiveDate) -> LintReport {
+        let past_role = experience.end_date < today.format("%Y-%m-%d").to_string();
+        let responsibilities = experience.responsibilities.iter().enumerate()
+            .filter_map(|(i, responsibility)| {
+                let text = responsibility.bullet();
+                let findings = self.lint(&text, past_role);
+                (!findings.is_empty()).then(|| ResponsibilityLint {
+                    responsibility_id: responsibility.id,
+                    location: format!("responsibilities[{}]", i),
+                    text,
+                    findings,
+                })
+            })
+            .collect();
+        LintReport { responsibilities }
+    }
+
+    fn push(&self, findings: &mut Vec<LintFinding>, rule: &str, severity: Severity, text: &str, at: Match, message: String) {
+        if self.enabled(rule) {
+            findings.push(LintFinding {
+                rule: rule.to_string(),
+                severity,
+                message,
+                span: Span { start: text[..at.start()].chars().count(), end: text[..at.end()].chars().count() },
+            });
+        }
+    }
+}
+
+fn tense(word: &str) -> Option<Tense> {
+    let word = word.to_lowercase();
+    if PAST.contains(&word) || (word.ends_with("ed") && word.len() > 4) {
+        return Some(Tense::Past);
+    }
+    let mut bases = vec![word.clone()];
+    if let Some(stem) = word.strip_suffix("ies") {
+        bases.push(format!("{}y", stem));
+    }
+    bases.extend(word.strip_suffix("es").map(String::from));
+    bases.extend(word.strip_suffix('s').map(String::from));
+    if let Some(stem) = word.strip_suffix("ing") {
+        bases.push(stem.to_string());
+        bases.push(format!("{}e", stem));
+        // `shipping`, `running`
+        if let Some((last, c)) = stem.char_indices().last() {
+            if stem[..last].ends_with(c) {
+                bases.push(stem[..last].to_string());
+            }
+        }
+    }
+    bases.iter().find_map(|base| VERBS.get(base)).map(|past| Tense::Present(past.clone()))
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
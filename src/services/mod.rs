@@ -1,3 +1,21 @@
 
 
 pub mod experience_service;
+pub mod profile_service;
+pub mod skill_service;
+pub mod category_service;
+pub mod resume_service;
+pub mod resume_resolver;
+pub mod resume_inheritance;
+pub mod resume_diff;
+pub mod snapshot_service;
+pub mod import_service;
+pub mod resume_sync;
+pub mod sync_service;
+pub mod template_service;
+pub mod site_service;
+pub mod relevance;
+pub mod tailor_service;
+pub mod ats;
+pub mod lint;
+pub mod timeline;
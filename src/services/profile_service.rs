@@ -0,0 +1,42 @@
+use mongodb::bson::oid::ObjectId;
+use crate::models::profile::Profile;
+use crate::repositories::profile_repository::ProfileRepository;
+use crate::repositories::repository::Repository;
+use crate::errors::AppError;
+
+#[derive(Clone)]
+pub struct ProfileService {
+    repository: ProfileRepository<'static, Profile>,
+}
+
+impl ProfileService {
+    pub fn new(repository: ProfileRepository<'static, Profile>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn create_profile(&self, mut profile: Profile) -> Result<Profile, AppError> {
+        profile.id.get_or_insert_with(ObjectId::new);
+        self.repository.create(profile.clone()).await?;
+        Ok(profile)
+    }
+
+    pub async fn get_profiles(&self) -> Result<Vec<Profile>, AppError> {
+        self.repository.find_all().await
+    }
+
+    pub async fn get_profile(&self, id: &str) -> Result<Option<Profile>, AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.get(&object_id).await
+    }
+
+    pub async fn update_profile(&self, id: &str, mut profile: Profile) -> Result<(), AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        profile.id = Some(object_id);
+        self.repository.update(&object_id, profile).await
+    }
+
+    pub async fn delete_profile(&self, id: &str) -> Result<(), AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.delete(&object_id).await
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet};
+use lazy_static::lazy_static;
+use serde::Serialize;
+
+/// BM25 term-frequency saturation.
+const K1: f64 = 1.2;
+/// BM25 document-length normalization.
+const B: f64 = 0.75;
+/// Weight of a dictionary term relative to an ordinary word.
+pub const TECH_TERM_BOOST: f64 = 2.0;
+
+// Longest phrase in the dictionary, in words.
+const MAX_PHRASE: usize = 4;
+
+const STOPWORDS: &[&str] = &[
+    "a", "about", "above", "across", "after", "all", "also", "an", "and", "any", "are", "as", "at", "be", "been",
+    "being", "both", "but", "by", "can", "could", "do", "does", "each", "etc", "for", "from", "had", "has", "have",
+    "having", "he", "her", "his", "how", "i", "if", "in", "into", "is", "it", "its", "just", "like", "may", "me",
+    "more", "most", "must", "my", "no", "not", "of", "on", "one", "or", "other", "our", "out", "over", "own", "per",
+    "plus", "preferred", "required", "she", "should", "so", "some", "such", "than", "that", "the", "their", "them",
+    "then", "there", "these", "they", "this", "those", "through", "to", "under", "up", "us", "using", "very", "was",
+    "we", "well", "were", "what", "when", "where", "which", "while", "who", "why", "will", "with", "within", "would",
+    "year", "years", "you", "your",
+];
+
+lazy_static! {
+    /// Lower-cased term or alias to canonical term.
+    static ref TECH_TERMS: HashMap<String, String> = {
+        let mut terms = HashMap::new();
+        for line in include_str!("../../assets/dictionaries/tech_terms.txt").lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let names: Vec<String> = line.split('|').map(|name| words(&name.to_lowercase()).join(" ")).collect();
+            for name in &names {
+                terms.insert(name.clone(), names[0].clone());
+            }
+        }
+        terms
+    };
+    static ref STOPWORD_SET: HashSet<&'static str> = STOPWORDS.iter().copied().collect();
+}
+
+/// A term of the text being analysed: a dictionary term under its
+/// canonical name, or a word reduced to its stem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    /// What terms are compared by.
+    pub key: String,
+    /// The term as written (or canonical name, for dictionary terms).
+    pub text: String,
+    pub tech: bool,
+}
+
+/// A term of a job description, with how often it occurs.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Keyword {
+    pub term: String,
+    #[serde(skip)]
+    pub key: String,
+    pub count: usize,
+    /// Whether the term comes from the bundled technology dictionary.
+    pub tech: bool,
+}
+
+impl Keyword {
+    fn weight(&self) -> f64 {
+        let boost = if self.tech { TECH_TERM_BOOST } else { 1.0 };
+        boost * (1.0 + (self.count as f64).ln())
+    }
+}
+
+/// How well one document matches the keywords.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relevance {
+    pub score: f64,
+    /// Keywords found in the document, most significant first.
+    pub matched: Vec<String>,
+}
+
+/// Splits text into terms. Dictionary terms and their aliases, including
+/// phrases such as `machine learning`, become one token under their
+/// canonical name; other words are lower-cased and stemmed, and stopwords,
+/// numbers and single letters are dropped.
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let words = words(&text.to_lowercase());
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        let phrase = (1..=MAX_PHRASE.min(words.len() - i)).rev()
+            .find_map(|n| TECH_TERMS.get(&words[i..i + n].join(" ")).map(|term| (n, term)));
+        if let Some((n, term)) = phrase {
+            tokens.push(Token { key: term.clone(), text: term.clone(), tech: true });
+            i += n;
+            continue;
+        }
+        let word = &words[i];
+        i += 1;
+        // Compound words such as `front-end` or `read/write` count as their parts
+        for part in word.split(['.', '/', '-', '+', '#']).filter(|part| !part.is_empty()) {
+            if part.chars().count() > 1 && part.chars().any(char::is_alphabetic) && !STOPWORD_SET.contains(part) {
+                tokens.push(Token { key: stem(part), text: part.to_string(), tech: false });
+            }
+        }
+    }
+    tokens
+}
+
+/// Distinct terms of a job description, most frequent first, then in the
+/// order they first appear. Words sharing a stem are counted together and
+/// shown as first written.
+pub fn extract_keywords(text: &str) -> Vec<Keyword> {
+    let mut keywords: Vec<Keyword> = Vec::new();
+    for token in tokenize(text) {
+        match keywords.iter_mut().find(|k| k.key == token.key) {
+            Some(keyword) => keyword.count += 1,
+            None => keywords.push(Keyword { term: token.text, key: token.key, count: 1, tech: token.tech }),
+        }
+    }
+    keywords.sort_by_key(|keyword| std::cmp::Reverse(keyword.count));
+    keywords
+}
+
+/// Okapi BM25 over a set of documents.
+pub struct Bm25 {
+    documents: Vec<HashMap<String, usize>>,
+    lengths: Vec<usize>,
+    average_length: f64,
+    frequencies: HashMap<String, usize>,
+}
+
+impl Bm25 {
+    pub fn new<S: AsRef<str>>(documents: &[S]) -> Self {
+        let documents: Vec<HashMap<String, usize>> = documents.iter()
+            .map(|document| {
+                let mut counts = HashMap::new();
+                for token in tokenize(document.as_ref()) {
+                    *counts.entry(token.key).or_insert(0) += 1;
+                }
+                counts
+            })
+            .collect();
+        let lengths: Vec<usize> = documents.iter().map(|counts| counts.values().sum()).collect();
+        let average_length = if lengths.is_empty() { 0.0 } else { lengths.iter().sum::<usize>() as f64 / lengths.len() as f64 };
+        let mut frequencies = HashMap::new();
+        for counts in &documents {
+            for key in counts.keys() {
+                *frequencies.entry(key.clone()).or_insert(0) += 1;
+            }
+        }
+        Bm25 { documents, lengths, average_length, frequencies }
+    }
+
+    /// Scores document `index` against the keywords. Each keyword adds its
+    /// BM25 weight, scaled up for dictionary terms and for keywords the job
+    /// description repeats. Scores are rounded to three decimals.
+    pub fn score(&self, index: usize, keywords: &[Keyword]) -> Relevance {
+        let counts = &self.documents[index];
+        let length = self.lengths[index] as f64;
+        let total = self.documents.len() as f64;
+        let mut contributions: Vec<(f64, &str)> = keywords.iter()
+            .filter_map(|keyword| {
+                let tf = *counts.get(&keyword.key)? as f64;
+                let df = self.frequencies[&keyword.key] as f64;
+                let idf = (1.0 + (total - df + 0.5) / (df + 0.5)).ln();
+                let norm = tf * (K1 + 1.0) / (tf + K1 * (1.0 - B + B * length / self.average_length.max(1.0)));
+                Some((idf * norm * keyword.weight(), keyword.term.as_str()))
+            })
+            .collect();
+        contributions.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let score = contributions.iter().map(|(contribution, _)| contribution).sum::<f64>();
+        Relevance {
+            score: (score * 1000.0).round() / 1000.0,
+            matched: contributions.into_iter().map(|(_, term)| term.to_string()).collect(),
+        }
+    }
+}
+
+// Runs of letters, digits and the `+#./-` that occur inside technology
+// names, with sentence punctuation trimmed off; a leading dot is kept for
+// names such as `.net`.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || "+#./-".contains(c)))
+        .map(|word| word.trim_start_matches(['-', '/']).trim_end_matches(['.', '-', '/']))
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+// A light suffix stripper: enough for `scaled`, `scales` and `scaling` to
+// meet, without a full stemmer.
+fn stem(word: &str) -> String {
+    let mut stem = word;
+    for suffix in ["ing", "ed", "es", "s"] {
+        if let Some(rest) = stem.strip_suffix(suffix) {
+            if rest.chars().count() >= 3 && !(suffix == "s" && rest.ends_with('s')) {
+                stem = rest;
+                break;
+            }
+        }
+    }
+    if stem.chars().count() >= 4 {
+        stem = stem.strip_suffix('e').unwrap_or(stem);
+    }
+    stem.to_string()
+}
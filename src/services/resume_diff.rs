@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use similar::{ChangeTag, TextDiff};
+use crate::models::experience::Experience;
+use crate::models::profile::Profile;
+use crate::models::resume::{ResolvedResume, ResumeSection};
+use crate::models::responsibility::Responsibility;
+use crate::models::skill::Skill;
+use crate::models::snapshot::{
+    BulletDiff, ChangeKind, ExperienceDiff, FieldChange, ResumeDiff, SkillDiff, WordChange, WordOp,
+};
+
+/// Computes what changed between two resolved resumes. Experiences,
+/// responsibilities and skills are matched by id (by position when an entry
+/// has none); changed text carries a word-level diff.
+pub fn diff_resumes(from: &ResolvedResume, to: &ResolvedResume) -> ResumeDiff {
+    let mut fields = Vec::new();
+    push_field(&mut fields, "name", Some(&from.name), Some(&to.name));
+    push_field(&mut fields, "summary", from.summary.as_ref(), to.summary.as_ref());
+    push_field(&mut fields, "sections", Some(&layout(&from.sections)), Some(&layout(&to.sections)));
+    diff_profile(&mut fields, &from.profile, &to.profile);
+
+    let experiences = diff_experiences(&mut fields, &from.experiences, &to.experiences);
+    let skills = diff_skills(&mut fields, &from.skills, &to.skills);
+
+    ResumeDiff {
+        identical: fields.is_empty() && experiences.is_empty() && skills.is_empty(),
+        fields,
+        experiences,
+        skills,
+    }
+}
+
+/// Word-level diff of two strings, with consecutive words of the same
+/// operation merged into one change.
+pub fn diff_words(before: &str, after: &str) -> Vec<WordChange> {
+    let mut changes: Vec<WordChange> = Vec::new();
+    for change in TextDiff::from_words(before, after).iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => WordOp::Equal,
+            ChangeTag::Insert => WordOp::Insert,
+            ChangeTag::Delete => WordOp::Delete,
+        };
+        match changes.last_mut() {
+            Some(last) if last.op == op => last.text.push_str(change.value()),
+            _ => changes.push(WordChange { op, text: change.value().to_string() }),
+        }
+    }
+    changes
+}
+
+fn diff_profile(fields: &mut Vec<FieldChange>, from: &Profile, to: &Profile) {
+    push_field(fields, "profile.name", Some(&from.name), Some(&to.name));
+    push_field(fields, "profile.label", from.label.as_ref(), to.label.as_ref());
+    push_field(fields, "profile.email", from.email.as_ref(), to.email.as_ref());
+    push_field(fields, "profile.phone", from.phone.as_ref(), to.phone.as_ref());
+    push_field(fields, "profile.url", from.url.as_ref(), to.url.as_ref());
+    push_field(fields, "profile.location", from.location.as_ref(), to.location.as_ref());
+    push_field(fields, "profile.summary", from.summary.as_ref(), to.summary.as_ref());
+    let links = |profile: &Profile| profile.links.iter().map(|l| l.url.clone()).collect::<Vec<_>>().join(", ");
+    push_field(fields, "profile.links", Some(&links(from)), Some(&links(to)));
+    let education = |profile: &Profile| profile.education.iter()
+        .map(|e| [Some(e.institution.as_str()), e.study_type.as_deref(), e.area.as_deref()].into_iter().flatten().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join(", ");
+    push_field(fields, "profile.education", Some(&education(from)), Some(&education(to)));
+    let languages = |profile: &Profile| profile.languages.iter()
+        .map(|l| if l.native { format!("{} (native)", l.name) } else { [Some(l.name.as_str()), l.level.as_deref()].into_iter().flatten().collect::<Vec<_>>().join(" ") })
+        .collect::<Vec<_>>()
+        .join(", ");
+    push_field(fields, "profile.languages", Some(&languages(from)), Some(&languages(to)));
+    let certificates = |profile: &Profile| profile.certificates.iter()
+        .map(|c| [Some(c.name.as_str()), c.issuer.as_deref()].into_iter().flatten().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join(", ");
+    push_field(fields, "profile.certificates", Some(&certificates(from)), Some(&certificates(to)));
+}
+
+fn diff_experiences(fields: &mut Vec<FieldChange>, from: &[Experience], to: &[Experience]) -> Vec<ExperienceDiff> {
+    let from_keys = keys(from.iter().map(|e| e.id));
+    let to_keys = keys(to.iter().map(|e| e.id));
+    push_order(fields, "experiences.order", &from_keys, &to_keys);
+    let to_index = index(&to_keys);
+
+    let mut diffs = Vec::new();
+    for (key, before) in from_keys.iter().zip(from) {
+        match to_index.get(key) {
+            None => diffs.push(experience_entry(ChangeKind::Removed, before, vec![], vec![])),
+            Some(&i) => {
+                let after = &to[i];
+                let mut changed = Vec::new();
+                push_field(&mut changed, "job_title", Some(&before.job_title), Some(&after.job_title));
+                push_field(&mut changed, "company", Some(&before.company), Some(&after.company));
+                push_field(&mut changed, "location", Some(&before.location), Some(&after.location));
+                push_field(&mut changed, "start_date", Some(&before.start_date), Some(&after.start_date));
+                push_field(&mut changed, "end_date", Some(&before.end_date), Some(&after.end_date));
+                let environment = |e: &Experience| e.environment.iter().map(|s| s.name.clone()).collect::<Vec<_>>().join(", ");
+                push_field(&mut changed, "environment", Some(&environment(before)), Some(&environment(after)));
+                let bullets = diff_bullets(&mut changed, &before.responsibilities, &after.responsibilities);
+                if !changed.is_empty() || !bullets.is_empty() {
+                    diffs.push(experience_entry(ChangeKind::Changed, after, changed, bullets));
+                }
+            }
+        }
+    }
+
+    let from_index = index(&from_keys);
+    for (key, after) in to_keys.iter().zip(to) {
+        if !from_index.contains_key(key) {
+            diffs.push(experience_entry(ChangeKind::Added, after, vec![], vec![]));
+        }
+    }
+    diffs
+}
+
+fn diff_bullets(fields: &mut Vec<FieldChange>, from: &[Responsibility], to: &[Responsibility]) -> Vec<BulletDiff> {
+    let from_keys = keys(from.iter().map(|r| r.id));
+    let to_keys = keys(to.iter().map(|r| r.id));
+    push_order(fields, "responsibilities.order", &from_keys, &to_keys);
+    let to_index = index(&to_keys);
+
+    let mut diffs = Vec::new();
+    for (key, before) in from_keys.iter().zip(from) {
+        let before_text = before.bullet();
+        match to_index.get(key) {
+            None => diffs.push(BulletDiff {
+                change: ChangeKind::Removed,
+                before: Some(before_text),
+                after: None,
+                words: vec![],
+            }),
+            Some(&i) => {
+                let after_text = to[i].bullet();
+                if before_text != after_text {
+                    diffs.push(BulletDiff {
+                        change: ChangeKind::Changed,
+                        words: diff_words(&before_text, &after_text),
+                        before: Some(before_text),
+                        after: Some(after_text),
+                    });
+                }
+            }
+        }
+    }
+
+    let from_index = index(&from_keys);
+    for (key, after) in to_keys.iter().zip(to) {
+        if !from_index.contains_key(key) {
+            diffs.push(BulletDiff {
+                change: ChangeKind::Added,
+                before: None,
+                after: Some(after.bullet()),
+                words: vec![],
+            });
+        }
+    }
+    diffs
+}
+
+fn diff_skills(fields: &mut Vec<FieldChange>, from: &[Skill], to: &[Skill]) -> Vec<SkillDiff> {
+    let from_keys = keys(from.iter().map(|s| s.id));
+    let to_keys = keys(to.iter().map(|s| s.id));
+    push_order(fields, "skills.order", &from_keys, &to_keys);
+    let to_index = index(&to_keys);
+
+    let mut diffs = Vec::new();
+    for (key, before) in from_keys.iter().zip(from) {
+        match to_index.get(key) {
+            None => diffs.push(skill_entry(ChangeKind::Removed, before, vec![])),
+            Some(&i) => {
+                let after = &to[i];
+                let mut changed = Vec::new();
+                push_field(&mut changed, "name", Some(&before.name), Some(&after.name));
+                push_field(&mut changed, "description", Some(&before.description), Some(&after.description));
+                let categories = |s: &Skill| s.categories.iter().map(|c| c.name.clone()).collect::<Vec<_>>().join(", ");
+                push_field(&mut changed, "categories", Some(&categories(before)), Some(&categories(after)));
+                if !changed.is_empty() {
+                    diffs.push(skill_entry(ChangeKind::Changed, after, changed));
+                }
+            }
+        }
+    }
+
+    let from_index = index(&from_keys);
+    for (key, after) in to_keys.iter().zip(to) {
+        if !from_index.contains_key(key) {
+            diffs.push(skill_entry(ChangeKind::Added, after, vec![]));
+        }
+    }
+    diffs
+}
+
+fn experience_entry(
+    change: ChangeKind,
+    experience: &Experience,
+    fields: Vec<FieldChange>,
+    responsibilities: Vec<BulletDiff>,
+) -> ExperienceDiff {
+    ExperienceDiff {
+        change,
+        experience_id: experience.id,
+        job_title: experience.job_title.clone(),
+        company: experience.company.clone(),
+        fields,
+        responsibilities,
+    }
+}
+
+fn skill_entry(change: ChangeKind, skill: &Skill, fields: Vec<FieldChange>) -> SkillDiff {
+    SkillDiff { change, skill_id: skill.id, name: skill.name.clone(), fields }
+}
+
+fn push_field(fields: &mut Vec<FieldChange>, field: &str, before: Option<&String>, after: Option<&String>) {
+    if before == after {
+        return;
+    }
+    let words = match (before, after) {
+        (Some(before), Some(after)) => diff_words(before, after),
+        _ => vec![],
+    };
+    fields.push(FieldChange {
+        field: field.to_string(),
+        before: before.cloned(),
+        after: after.cloned(),
+        words,
+    });
+}
+
+// Reports a reordering of the entries present on both sides.
+fn push_order(fields: &mut Vec<FieldChange>, field: &str, from: &[String], to: &[String]) {
+    let from_index = index(from);
+    let to_index = index(to);
+    let common_from: Vec<&String> = from.iter().filter(|k| to_index.contains_key(*k)).collect();
+    let common_to: Vec<&String> = to.iter().filter(|k| from_index.contains_key(*k)).collect();
+    if common_from != common_to {
+        fields.push(FieldChange {
+            field: field.to_string(),
+            before: Some(common_from.iter().map(|k| k.as_str()).collect::<Vec<_>>().join(", ")),
+            after: Some(common_to.iter().map(|k| k.as_str()).collect::<Vec<_>>().join(", ")),
+            words: vec![],
+        });
+    }
+}
+
+fn layout(sections: &[ResumeSection]) -> String {
+    sections.iter().map(|s| s.heading()).collect::<Vec<_>>().join(", ")
+}
+
+fn keys(ids: impl Iterator<Item = Option<mongodb::bson::oid::ObjectId>>) -> Vec<String> {
+    ids.enumerate()
+        .map(|(i, id)| id.map(|id| id.to_hex()).unwrap_or_else(|| format!("#{}", i)))
+        .collect()
+}
+
+fn index(keys: &[String]) -> HashMap<&String, usize> {
+    keys.iter().enumerate().map(|(i, key)| (key, i)).collect()
+}
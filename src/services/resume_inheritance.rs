@@ -0,0 +1,113 @@
+use mongodb::bson::oid::ObjectId;
+use crate::models::resume::{ExperienceOverrides, Resume, ResumeExperience};
+
+/// Applies a variant on top of its (already flattened) parent and returns
+/// the effective resume. Identity fields (`id`, `name`, `profile_id`,
+/// `parent_id`) always come from the variant.
+pub fn merge_variant(parent: &Resume, variant: &Resume) -> Resume {
+    let mut experiences: Vec<ResumeExperience> = parent.experiences
+        .iter()
+        .filter(|entry| !variant.excluded_experiences.contains(&entry.experience_id))
+        .map(|inherited| match find_entry(&variant.experiences, &inherited.experience_id) {
+            Some(own) => merge_entry(inherited, own),
+            None => inherited.clone(),
+        })
+        .collect();
+    for own in &variant.experiences {
+        if find_entry(&parent.experiences, &own.experience_id).is_none() {
+            experiences.push(own.clone());
+        }
+    }
+
+    Resume {
+        id: variant.id,
+        name: variant.name.clone(),
+        profile_id: variant.profile_id,
+        parent_id: variant.parent_id,
+        summary: variant.summary.clone().or_else(|| parent.summary.clone()),
+        sections: if variant.sections.is_empty() { parent.sections.clone() } else { variant.sections.clone() },
+        experiences,
+        skills: if variant.skills.is_empty() { parent.skills.clone() } else { variant.skills.clone() },
+        excluded_experiences: vec![],
+    }
+}
+
+/// Flattens an inheritance chain ordered from the root base resume down to
+/// the variant itself.
+pub fn flatten_chain(chain: &[Resume]) -> Option<Resume> {
+    let (root, rest) = chain.split_first()?;
+    Some(rest.iter().fold(root.clone(), |effective, variant| merge_variant(&effective, variant)))
+}
+
+/// Lists the fields `variant` overrides relative to its flattened parent,
+/// as paths such as `summary` or `experiences[<id>].job_title`.
+pub fn overridden_fields(parent: &Resume, variant: &Resume) -> Vec<String> {
+    let mut fields = Vec::new();
+    if variant.summary.is_some() {
+        fields.push("summary".to_string());
+    }
+    if !variant.sections.is_empty() {
+        fields.push("sections".to_string());
+    }
+    if !variant.skills.is_empty() {
+        fields.push("skills".to_string());
+    }
+    for id in &variant.excluded_experiences {
+        if find_entry(&parent.experiences, id).is_some() {
+            fields.push(format!("excluded_experiences[{}]", id));
+        }
+    }
+
+    for own in &variant.experiences {
+        let path = format!("experiences[{}]", own.experience_id);
+        if find_entry(&parent.experiences, &own.experience_id).is_none() {
+            fields.push(path);
+            continue;
+        }
+        if own.responsibilities.is_some() {
+            fields.push(format!("{}.responsibilities", path));
+        }
+        let overrides = &own.overrides;
+        for (name, value) in [
+            ("job_title", &overrides.job_title),
+            ("company", &overrides.company),
+            ("location", &overrides.location),
+            ("start_date", &overrides.start_date),
+            ("end_date", &overrides.end_date),
+        ] {
+            if value.is_some() {
+                fields.push(format!("{}.{}", path, name));
+            }
+        }
+        for replacement in &own.responsibility_overrides {
+            fields.push(format!("{}.responsibility_overrides[{}]", path, replacement.responsibility_id));
+        }
+    }
+    fields
+}
+
+fn merge_entry(inherited: &ResumeExperience, own: &ResumeExperience) -> ResumeExperience {
+    let (base, mine) = (&inherited.overrides, &own.overrides);
+    let mut responsibility_overrides = inherited.responsibility_overrides.clone();
+    for replacement in &own.responsibility_overrides {
+        responsibility_overrides.retain(|r| r.responsibility_id != replacement.responsibility_id);
+        responsibility_overrides.push(replacement.clone());
+    }
+
+    ResumeExperience {
+        experience_id: inherited.experience_id,
+        responsibilities: own.responsibilities.clone().or_else(|| inherited.responsibilities.clone()),
+        overrides: ExperienceOverrides {
+            job_title: mine.job_title.clone().or_else(|| base.job_title.clone()),
+            company: mine.company.clone().or_else(|| base.company.clone()),
+            location: mine.location.clone().or_else(|| base.location.clone()),
+            start_date: mine.start_date.clone().or_else(|| base.start_date.clone()),
+            end_date: mine.end_date.clone().or_else(|| base.end_date.clone()),
+        },
+        responsibility_overrides,
+    }
+}
+
+fn find_entry<'a>(entries: &'a [ResumeExperience], id: &ObjectId) -> Option<&'a ResumeExperience> {
+    entries.iter().find(|entry| &entry.experience_id == id)
+}
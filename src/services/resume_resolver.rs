@@ -0,0 +1,148 @@
+use mongodb::bson::oid::ObjectId;
+use crate::errors::AppError;
+use crate::models::experience::Experience;
+use crate::models::ordering::relink;
+use crate::models::profile::Profile;
+use crate::models::resume::{ResolvedResume, Resume, ResumeExperience, ResumeSection};
+use crate::models::skill::Skill;
+
+/// Materializes `resume` from the given pool of experiences and skills.
+/// Every id referenced by the resume must be present in the pool, and the
+/// experiences must belong to the resume's profile.
+pub fn resolve_resume(
+    resume: &Resume,
+    profile: Profile,
+    experiences: &[Experience],
+    skills: &[Skill],
+) -> Result<ResolvedResume, AppError> {
+    let resolved_experiences = resume.experiences
+        .iter()
+        .map(|selection| resolve_experience(selection, &resume.profile_id, experiences))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut resolved_skills = resume.skills
+        .iter()
+        .map(|id| {
+            skills.iter()
+                .find(|skill| skill.id.as_ref() == Some(id))
+                .cloned()
+                .ok_or_else(|| AppError::NotFound(format!("Skill {} referenced by resume not found", id)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Resolved lists are already ordered; rewrite the `next` links so they agree
+    relink(&mut resolved_skills);
+
+    let sections = if resume.sections.is_empty() {
+        ResumeSection::default_layout()
+    } else {
+        resume.sections.clone()
+    };
+
+    Ok(ResolvedResume {
+        resume_id: resume.id,
+        name: resume.name.clone(),
+        summary: resume.summary.clone().or_else(|| profile.summary.clone()),
+        profile,
+        sections,
+        experiences: resolved_experiences,
+        skills: resolved_skills,
+    })
+}
+
+/// Checks the experiences `resume` selects itself against the pool: each
+/// must exist and belong to the resume's profile, and each responsibility
+/// override must target one of the experience's responsibilities, or one of
+/// the entry's `responsibilities` when it selects them.
+pub fn check_selection(resume: &Resume, experiences: &[Experience]) -> Result<(), AppError> {
+    for selection in &resume.experiences {
+        let source = experiences.iter()
+            .find(|experience| experience.id.as_ref() == Some(&selection.experience_id))
+            .ok_or_else(|| AppError::ValidationError(format!(
+                "Experience {} referenced by resume not found", selection.experience_id
+            )))?;
+        check_owner(source, &resume.profile_id)?;
+        for replacement in &selection.responsibility_overrides {
+            let id = &replacement.responsibility_id;
+            let selected = match &selection.responsibilities {
+                Some(ids) => ids.contains(id),
+                None => source.responsibilities.iter().any(|responsibility| responsibility.id.as_ref() == Some(id)),
+            };
+            if !selected {
+                return Err(AppError::ValidationError(format!(
+                    "Responsibility override {} does not match a selected responsibility of experience {}",
+                    id, selection.experience_id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_owner(experience: &Experience, profile_id: &ObjectId) -> Result<(), AppError> {
+    if experience.profile_id.as_ref() != Some(profile_id) {
+        return Err(AppError::ValidationError(format!(
+            "Experience {} does not belong to profile {}",
+            experience.id.map(|id| id.to_hex()).unwrap_or_default(), profile_id
+        )));
+    }
+    Ok(())
+}
+
+fn resolve_experience(selection: &ResumeExperience, profile_id: &ObjectId, pool: &[Experience]) -> Result<Experience, AppError> {
+    let source = pool.iter()
+        .find(|experience| experience.id.as_ref() == Some(&selection.experience_id))
+        .ok_or_else(|| AppError::NotFound(format!(
+            "Experience {} referenced by resume not found", selection.experience_id
+        )))?;
+    // Experiences can be moved to another profile after the resume is saved
+    check_owner(source, profile_id)?;
+
+    let responsibilities = match &selection.responsibilities {
+        Some(ids) => ids.iter()
+            .map(|id| {
+                source.responsibilities.iter()
+                    .find(|responsibility| responsibility.id.as_ref() == Some(id))
+                    .cloned()
+                    .ok_or_else(|| AppError::NotFound(format!(
+                        "Responsibility {} not found in experience {}", id, selection.experience_id
+                    )))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => source.ordered_responsibilities(),
+    };
+
+    let responsibilities = responsibilities.into_iter()
+        .map(|mut responsibility| {
+            let replacement = selection.responsibility_overrides.iter()
+                .find(|o| responsibility.id.as_ref() == Some(&o.responsibility_id));
+            if let Some(replacement) = replacement {
+                // An explicit text override wins over the condensed STAR bullet
+                responsibility.name = replacement.name.clone();
+                responsibility.star = None;
+            }
+            responsibility
+        })
+        .collect();
+
+    let overrides = &selection.overrides;
+    let mut experience = source.clone();
+    if let Some(job_title) = &overrides.job_title {
+        experience.job_title = job_title.clone();
+    }
+    if let Some(company) = &overrides.company {
+        experience.company = company.clone();
+    }
+    if let Some(location) = &overrides.location {
+        experience.location = location.clone();
+    }
+    if let Some(start_date) = &overrides.start_date {
+        experience.start_date = start_date.clone();
+    }
+    if let Some(end_date) = &overrides.end_date {
+        experience.end_date = end_date.clone();
+    }
+    experience.responsibilities = responsibilities;
+    relink(&mut experience.responsibilities);
+    Ok(experience)
+}
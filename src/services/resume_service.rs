@@ -0,0 +1,192 @@
+use mongodb::bson::oid::ObjectId;
+use crate::models::experience::Experience;
+use crate::models::resume::{ResolvedResume, Resume, VariantOverrides};
+use crate::repositories::resume_repository::ResumeRepository;
+use crate::repositories::repository::Repository;
+use crate::services::experience_service::ExperienceService;
+use crate::services::profile_service::ProfileService;
+use crate::services::resume_inheritance::{flatten_chain, overridden_fields};
+use crate::services::resume_resolver::{check_selection, resolve_resume};
+use crate::services::skill_service::SkillService;
+use crate::errors::AppError;
+
+// Guards against runaway inheritance chains
+const MAX_INHERITANCE_DEPTH: usize = 16;
+
+#[derive(Clone)]
+pub struct ResumeService {
+    repository: ResumeRepository<'static, Resume>,
+    profile_service: ProfileService,
+    experience_service: ExperienceService<'static, Experience>,
+    skill_service: SkillService,
+}
+
+impl ResumeService {
+    pub fn new(
+        repository: ResumeRepository<'static, Resume>,
+        profile_service: ProfileService,
+        experience_service: ExperienceService<'static, Experience>,
+        skill_service: SkillService,
+    ) -> Self {
+        Self { repository, profile_service, experience_service, skill_service }
+    }
+
+    pub async fn create_resume(&self, mut resume: Resume) -> Result<Resume, AppError> {
+        resume.id.get_or_insert_with(ObjectId::new);
+        self.check_parent(&resume).await?;
+        self.check_experiences(&resume).await?;
+        self.repository.create(resume.clone()).await?;
+        Ok(resume)
+    }
+
+    pub async fn get_resumes(&self) -> Result<Vec<Resume>, AppError> {
+        self.repository.find_all().await
+    }
+
+    pub async fn get_resume(&self, id: &str) -> Result<Option<Resume>, AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.get(&object_id).await
+    }
+
+    pub async fn update_resume(&self, id: &str, mut resume: Resume) -> Result<(), AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        resume.id = Some(object_id);
+        self.check_parent(&resume).await?;
+        self.check_experiences(&resume).await?;
+        let variants = self.repository.find_by_parent(&object_id).await?;
+        if let Some(variant) = variants.iter().find(|variant| variant.profile_id != resume.profile_id) {
+            return Err(AppError::ValidationError(format!(
+                "Resume {} has variants of profile {}; its profile cannot change", id, variant.profile_id
+            )));
+        }
+        self.repository.update(&object_id, resume).await
+    }
+
+    /// Deletes a resume that no variant inherits from.
+    pub async fn delete_resume(&self, id: &str) -> Result<(), AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        let variants = self.repository.find_by_parent(&object_id).await?;
+        if !variants.is_empty() {
+            let names: Vec<&str> = variants.iter().map(|variant| variant.name.as_str()).collect();
+            return Err(AppError::ValidationError(format!(
+                "Resume {} still has variants ({}); delete them first", id, names.join(", ")
+            )));
+        }
+        self.repository.delete(&object_id).await
+    }
+
+    /// Loads the resume together with everything it references and returns
+    /// the materialized document. Variants are flattened onto their parents
+    /// first.
+    pub async fn resolve(&self, id: &str) -> Result<ResolvedResume, AppError> {
+        let resume = self.effective_resume(id).await?;
+
+        let profile = self.profile_service.get_profile(&resume.profile_id.to_hex())
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Profile {} not found", resume.profile_id)))?;
+
+        let experience_ids: Vec<ObjectId> = resume.experiences.iter().map(|e| e.experience_id).collect();
+        let experiences = self.experience_service.get_experiences_by_ids(&experience_ids).await?;
+        let skills = self.skill_service.get_skills_by_ids(&resume.skills).await?;
+
+        resolve_resume(&resume, profile, &experiences, &skills)
+    }
+
+    /// The resume with every inherited field filled in from its parents.
+    pub async fn effective_resume(&self, id: &str) -> Result<Resume, AppError> {
+        let resume = self.get_resume(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Resume {} not found", id)))?;
+        let chain = self.load_chain(resume).await?;
+        flatten_chain(&chain).ok_or_else(|| AppError::NotFound(format!("Resume {} not found", id)))
+    }
+
+    /// Fields each direct variant of the resume overrides.
+    pub async fn get_variants(&self, id: &str) -> Result<Vec<VariantOverrides>, AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        let base = self.effective_resume(id).await?;
+        let variants = self.repository.find_by_parent(&object_id).await?;
+        Ok(variants.iter().map(|variant| variant_overrides(&base, variant)).collect())
+    }
+
+    /// Fields the resume overrides relative to its parent; empty for a base resume.
+    pub async fn get_overrides(&self, id: &str) -> Result<VariantOverrides, AppError> {
+        let resume = self.get_resume(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Resume {} not found", id)))?;
+        match resume.parent_id {
+            Some(parent_id) => {
+                let parent = self.effective_resume(&parent_id.to_hex()).await?;
+                Ok(variant_overrides(&parent, &resume))
+            }
+            None => Ok(VariantOverrides {
+                resume_id: resume.id,
+                name: resume.name,
+                parent_id: None,
+                overrides: vec![],
+            }),
+        }
+    }
+
+    // Returns the inheritance chain ordered from the root down to `resume`.
+    async fn load_chain(&self, resume: Resume) -> Result<Vec<Resume>, AppError> {
+        let mut chain = vec![resume];
+        while let Some(parent_id) = chain.last().and_then(|r| r.parent_id) {
+            if chain.iter().any(|r| r.id == Some(parent_id)) {
+                return Err(AppError::ValidationError(format!(
+                    "Resume {} has a cyclic parent chain", parent_id
+                )));
+            }
+            if chain.len() > MAX_INHERITANCE_DEPTH {
+                return Err(AppError::ValidationError(format!(
+                    "Resume inheritance is limited to {} levels", MAX_INHERITANCE_DEPTH
+                )));
+            }
+            let parent = self.repository.get(&parent_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(format!("Parent resume {} not found", parent_id)))?;
+            chain.push(parent);
+        }
+        chain.reverse();
+        Ok(chain)
+    }
+
+    async fn check_experiences(&self, resume: &Resume) -> Result<(), AppError> {
+        let experience_ids: Vec<ObjectId> = resume.experiences.iter().map(|e| e.experience_id).collect();
+        let experiences = self.experience_service.get_experiences_by_ids(&experience_ids).await?;
+        check_selection(resume, &experiences)
+    }
+
+    async fn check_parent(&self, resume: &Resume) -> Result<(), AppError> {
+        let Some(parent_id) = resume.parent_id else {
+            return Ok(());
+        };
+        if resume.id == Some(parent_id) {
+            return Err(AppError::ValidationError("A resume cannot be its own parent".to_string()));
+        }
+        let parent = self.repository.get(&parent_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError(format!("Parent resume {} not found", parent_id)))?;
+        if parent.profile_id != resume.profile_id {
+            return Err(AppError::ValidationError(format!(
+                "Resume {} belongs to profile {}; a variant must use the same profile", parent_id, parent.profile_id
+            )));
+        }
+        let chain = self.load_chain(parent).await?;
+        if chain.iter().any(|r| r.id == resume.id) {
+            return Err(AppError::ValidationError(format!(
+                "Resume {} cannot inherit from one of its own variants", parent_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn variant_overrides(parent: &Resume, variant: &Resume) -> VariantOverrides {
+    VariantOverrides {
+        resume_id: variant.id,
+        name: variant.name.clone(),
+        parent_id: variant.parent_id,
+        overrides: overridden_fields(parent, variant),
+    }
+}
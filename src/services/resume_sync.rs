@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+use mongodb::bson::oid::ObjectId;
+use serde_json::Value;
+use crate::models::experience::Experience;
+use crate::models::ordering::relink;
+use crate::models::responsibility::Responsibility;
+use crate::models::resume_file::{SyncAction, SyncChange, SyncPlan, SyncTarget};
+
+/// Completes the experiences of a resume file before they are planned. An
+/// experience without an id gets a new one. A responsibility without an id
+/// takes the id of the stored responsibility of the same experience with
+/// the same name, or else a new one, so that a file written without ids
+/// plans the same changes on every sync. Responsibilities are then linked
+/// in the order they are listed.
+pub fn assign_ids(file: &mut [Experience], stored: &[Experience]) {
+    for experience in file {
+        let id = *experience.id.get_or_insert_with(ObjectId::new);
+        let listed: HashSet<ObjectId> = experience.responsibilities.iter().filter_map(|r| r.id).collect();
+        let mut unused: Vec<&Responsibility> = stored.iter()
+            .filter(|e| e.id == Some(id))
+            .flat_map(|e| &e.responsibilities)
+            .filter(|r| r.id.is_some_and(|id| !listed.contains(&id)))
+            .collect();
+        for responsibility in &mut experience.responsibilities {
+            if responsibility.id.is_none() {
+                responsibility.id = match unused.iter().position(|r| r.name == responsibility.name) {
+                    Some(index) => unused.remove(index).id,
+                    None => Some(ObjectId::new()),
+                };
+            }
+        }
+        relink(&mut experience.responsibilities);
+    }
+}
+
+/// Plans the changes that make the stored experiences match the
+/// experiences of a resume file. Records are matched by id: an experience
+/// of the file without an id, or with one the database does not have, is
+/// created; a stored experience missing from the file is deleted, unless
+/// it has no id to delete it by. For the
+/// other experiences, changed fields are one update, and each
+/// responsibility added, changed (name, story or order) or removed is a
+/// change of its own.
+pub fn plan_experiences(file: &[Experience], stored: &[Experience]) -> SyncPlan {
+    let mut changes = Vec::new();
+    for experience in file {
+        let Some(current) = stored.iter().find(|e| e.id.is_some() && e.id == experience.id) else {
+            changes.push(change(SyncAction::Create, SyncTarget::Experience, experience.id, None, title(experience), vec![]));
+            continue;
+        };
+        let fields = changed_fields(experience, current);
+        if !fields.is_empty() {
+            changes.push(change(SyncAction::Update, SyncTarget::Experience, experience.id, None, title(experience), fields));
+        }
+        changes.extend(plan_responsibilities(experience, current));
+    }
+
+    let kept: HashSet<ObjectId> = file.iter().filter_map(|e| e.id).collect();
+    for experience in stored.iter().filter(|e| e.id.is_some_and(|id| !kept.contains(&id))) {
+        changes.push(change(SyncAction::Delete, SyncTarget::Experience, experience.id, None, title(experience), vec![]));
+    }
+    SyncPlan { applied: false, changes }
+}
+
+fn plan_responsibilities(experience: &Experience, current: &Experience) -> Vec<SyncChange> {
+    let mut changes = Vec::new();
+    let find = |responsibilities: &[Responsibility], id: Option<ObjectId>| {
+        responsibilities.iter().find(|r| r.id.is_some() && r.id == id).cloned()
+    };
+    for responsibility in &experience.responsibilities {
+        let name = responsibility.name.clone();
+        match find(&current.responsibilities, responsibility.id) {
+            None => changes.push(change(SyncAction::Create, SyncTarget::Responsibility, experience.id, responsibility.id, name, vec![])),
+            Some(stored) => {
+                let mut fields = Vec::new();
+                if stored.name != responsibility.name {
+                    fields.push("name".to_string());
+                }
+                if stored.star != responsibility.star {
+                    fields.push("star".to_string());
+                }
+                if stored.next != responsibility.next {
+                    fields.push("order".to_string());
+                }
+                if !fields.is_empty() {
+                    changes.push(change(SyncAction::Update, SyncTarget::Responsibility, experience.id, responsibility.id, name, fields));
+                }
+            }
+        }
+    }
+    for responsibility in &current.responsibilities {
+        if find(&experience.responsibilities, responsibility.id).is_none() {
+            let name = responsibility.name.clone();
+            changes.push(change(SyncAction::Delete, SyncTarget::Responsibility, experience.id, responsibility.id, name, vec![]));
+        }
+    }
+    changes
+}
+
+// Fields of the experience other than its responsibilities, compared in
+// their serialized form.
+fn changed_fields(experience: &Experience, current: &Experience) -> Vec<String> {
+    let fields = |experience: &Experience| match serde_json::to_value(experience) {
+        Ok(Value::Object(mut fields)) => {
+            fields.remove("responsibilities");
+            fields
+        }
+        _ => Default::default(),
+    };
+    let (after, before) = (fields(experience), fields(current));
+    let mut names: Vec<&String> = after.keys().chain(before.keys()).collect();
+    names.sort();
+    names.dedup();
+    names.into_iter()
+        .filter(|name| after.get(*name) != before.get(*name))
+        .cloned()
+        .collect()
+}
+
+fn title(experience: &Experience) -> String {
+    format!("{} at {}", experience.job_title, experience.company)
+}
+
+fn change(
+    action: SyncAction,
+    target: SyncTarget,
+    experience_id: Option<ObjectId>,
+    responsibility_id: Option<ObjectId>,
+    name: String,
+    fields: Vec<String>,
+) -> SyncChange {
+    SyncChange { action, target, experience_id, responsibility_id, name, fields }
+}
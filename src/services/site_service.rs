@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::Path;
+use mongodb::bson::oid::ObjectId;
+use crate::models::experience::Experience;
+use crate::renderers::site::{Portfolio, SiteFile};
+use crate::services::experience_service::ExperienceService;
+use crate::services::import_service::or_empty;
+use crate::services::profile_service::ProfileService;
+use crate::services::resume_service::ResumeService;
+use crate::errors::AppError;
+
+/// Loads what a static portfolio site is built from.
+#[derive(Clone)]
+pub struct SiteService {
+    profile_service: ProfileService,
+    experience_service: ExperienceService<'static, Experience>,
+    resume_service: ResumeService,
+}
+
+impl SiteService {
+    pub fn new(
+        profile_service: ProfileService,
+        experience_service: ExperienceService<'static, Experience>,
+        resume_service: ResumeService,
+    ) -> Self {
+        Self { profile_service, experience_service, resume_service }
+    }
+
+    /// The profile with all of its experiences and its resumes, resolved.
+    pub async fn portfolio(&self, profile_id: &str) -> Result<Portfolio, AppError> {
+        let object_id = ObjectId::parse_str(profile_id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        let profile = self.profile_service.get_profile(profile_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Profile {} not found", profile_id)))?;
+        let experiences = self.experience_service.get_experiences_by_profile(&object_id).await?;
+
+        let mut resumes = Vec::new();
+        let stored = or_empty(self.resume_service.get_resumes().await)?;
+        for id in stored.iter().filter(|r| r.profile_id == object_id).filter_map(|r| r.id) {
+            resumes.push(self.resume_service.resolve(&id.to_hex()).await?);
+        }
+        Ok(Portfolio { profile, experiences, resumes, assets: vec![] })
+    }
+}
+
+/// Reads every file under `dir`, with paths relative to it.
+pub fn read_assets(dir: &Path) -> Result<Vec<SiteFile>, AppError> {
+    fn visit(dir: &Path, prefix: &str, files: &mut Vec<SiteFile>) -> Result<(), AppError> {
+        let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let path = format!("{}{}", prefix, name);
+            if entry.file_type()?.is_dir() {
+                visit(&entry.path(), &format!("{}/", path), files)?;
+            } else {
+                files.push(SiteFile { path, content: fs::read(entry.path())? });
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    visit(dir, "", &mut files)?;
+    Ok(files)
+}
+
+/// Writes the site under `dir`, creating directories as needed and
+/// replacing files already there.
+pub fn write_site(files: &[SiteFile], dir: &Path) -> Result<(), AppError> {
+    for file in files {
+        let path = file.path.split('/').fold(dir.to_path_buf(), |path, part| path.join(part));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &file.content)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use mongodb::bson::oid::ObjectId;
+use crate::models::skill::Skill;
+use crate::repositories::skill_repository::SkillRepository;
+use crate::repositories::repository::Repository;
+use crate::errors::AppError;
+
+#[derive(Clone)]
+pub struct SkillService {
+    repository: SkillRepository<'static, Skill>,
+}
+
+impl SkillService {
+    pub fn new(repository: SkillRepository<'static, Skill>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn create_skill(&self, mut skill: Skill) -> Result<Skill, AppError> {
+        skill.id.get_or_insert_with(ObjectId::new);
+        self.repository.create(skill.clone()).await?;
+        Ok(skill)
+    }
+
+    pub async fn get_skills(&self) -> Result<Vec<Skill>, AppError> {
+        self.repository.find_all().await
+    }
+
+    pub async fn get_skill(&self, id: &str) -> Result<Option<Skill>, AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.get(&object_id).await
+    }
+
+    pub async fn get_skills_by_ids(&self, ids: &[ObjectId]) -> Result<Vec<Skill>, AppError> {
+        self.repository.find_by_ids(ids).await
+    }
+}
@@ -0,0 +1,70 @@
+use chrono::{SecondsFormat, Utc};
+use mongodb::bson::oid::ObjectId;
+use sha2::{Digest, Sha256};
+use crate::models::resume::ResolvedResume;
+use crate::models::snapshot::{ResumeSnapshot, SnapshotDiff, SnapshotSummary};
+use crate::repositories::snapshot_repository::SnapshotRepository;
+use crate::services::resume_diff::diff_resumes;
+use crate::services::resume_service::ResumeService;
+use crate::errors::AppError;
+
+#[derive(Clone)]
+pub struct SnapshotService {
+    repository: SnapshotRepository,
+    resume_service: ResumeService,
+}
+
+impl SnapshotService {
+    pub fn new(repository: SnapshotRepository, resume_service: ResumeService) -> Self {
+        Self { repository, resume_service }
+    }
+
+    /// Resolves the resume as it is right now and stores it immutably.
+    pub async fn freeze(&self, resume_id: &str, label: String) -> Result<ResumeSnapshot, AppError> {
+        let resume_object_id = ObjectId::parse_str(resume_id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        let document = self.resume_service.resolve(resume_id).await?;
+        let snapshot = ResumeSnapshot {
+            id: Some(ObjectId::new()),
+            resume_id: resume_object_id,
+            label,
+            content_hash: content_hash(&document)?,
+            created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            document,
+        };
+        self.repository.create(snapshot.clone()).await?;
+        Ok(snapshot)
+    }
+
+    pub async fn get_snapshots(&self, resume_id: &str) -> Result<Vec<SnapshotSummary>, AppError> {
+        let resume_object_id = ObjectId::parse_str(resume_id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        let snapshots = self.repository.find_by_resume(&resume_object_id).await?;
+        Ok(snapshots.iter().map(SnapshotSummary::from).collect())
+    }
+
+    pub async fn get_snapshot(&self, resume_id: &str, id: &str) -> Result<Option<ResumeSnapshot>, AppError> {
+        let resume_object_id = ObjectId::parse_str(resume_id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.get(&resume_object_id, &object_id).await
+    }
+
+    pub async fn diff(&self, resume_id: &str, from: &str, to: &str) -> Result<SnapshotDiff, AppError> {
+        let before = self.get_snapshot(resume_id, from)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Snapshot {} not found", from)))?;
+        let after = self.get_snapshot(resume_id, to)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Snapshot {} not found", to)))?;
+
+        Ok(SnapshotDiff {
+            from: SnapshotSummary::from(&before),
+            to: SnapshotSummary::from(&after),
+            diff: diff_resumes(&before.document, &after.document),
+        })
+    }
+}
+
+/// Hex-encoded SHA-256 of the document's JSON serialization.
+pub fn content_hash(document: &ResolvedResume) -> Result<String, AppError> {
+    let bytes = serde_json::to_vec(document)?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
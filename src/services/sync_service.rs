@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use validator::Validate;
+use crate::errors::{validation_messages, AppError};
+use crate::models::experience::Experience;
+use crate::models::resume_file::{ResumeFile, SyncAction, SyncPlan, SyncTarget};
+use crate::services::category_service::CategoryService;
+use crate::services::experience_service::ExperienceService;
+use crate::services::import_service::or_empty;
+use crate::services::profile_service::ProfileService;
+use crate::services::resume_service::ResumeService;
+use crate::services::resume_sync::{assign_ids, plan_experiences};
+use crate::services::skill_service::SkillService;
+
+/// Keeps the database in sync with a resume file: exports every record to
+/// a file, and plans and applies the experience changes a file brings.
+#[derive(Clone)]
+pub struct SyncService {
+    experience_service: ExperienceService<'static, Experience>,
+    profile_service: ProfileService,
+    skill_service: SkillService,
+    category_service: CategoryService,
+    resume_service: ResumeService,
+}
+
+impl SyncService {
+    pub fn new(
+        experience_service: ExperienceService<'static, Experience>,
+        profile_service: ProfileService,
+        skill_service: SkillService,
+        category_service: CategoryService,
+        resume_service: ResumeService,
+    ) -> Self {
+        Self { experience_service, profile_service, skill_service, category_service, resume_service }
+    }
+
+    pub async fn export(&self) -> Result<ResumeFile, AppError> {
+        Ok(ResumeFile {
+            profiles: or_empty(self.profile_service.get_profiles().await)?,
+            categories: or_empty(self.category_service.get_categories().await)?,
+            skills: or_empty(self.skill_service.get_skills().await)?,
+            experiences: or_empty(self.experience_service.get_experiences().await)?,
+            resumes: or_empty(self.resume_service.get_resumes().await)?,
+            ..ResumeFile::default()
+        })
+    }
+
+    /// Validates the experiences of the file, gives them the ids they were
+    /// written without and plans the changes that make the database match
+    /// them.
+    pub async fn plan(&self, file: &mut ResumeFile) -> Result<SyncPlan, AppError> {
+        validate_experiences(&file.experiences)?;
+        let stored = or_empty(self.experience_service.get_experiences().await)?;
+        assign_ids(&mut file.experiences, &stored);
+        Ok(plan_experiences(&file.experiences, &stored))
+    }
+
+    /// Applies the plan of [`SyncService::plan`]: each experience created,
+    /// updated (together with its responsibilities) or deleted through
+    /// [`ExperienceService`]. Returns the plan that was applied.
+    pub async fn apply(&self, mut file: ResumeFile) -> Result<SyncPlan, AppError> {
+        let mut plan = self.plan(&mut file).await?;
+
+        let mut updated = HashSet::new();
+        for change in &plan.changes {
+            // Every planned change has one, as ids are assigned before planning
+            let id = change.experience_id.ok_or_else(|| AppError::InternalServerError(format!(
+                "Planned change to {} has no experience id", change.name
+            )))?;
+            match (change.action, change.target) {
+                (SyncAction::Delete, SyncTarget::Experience) => {
+                    self.experience_service.delete_experience(&id.to_hex()).await?;
+                }
+                (SyncAction::Create, SyncTarget::Experience) => {
+                    if let Some(experience) = file.experiences.iter().find(|e| e.id == Some(id)) {
+                        self.experience_service.create_experience(experience.clone()).await?;
+                    }
+                }
+                // The responsibilities live in the experience document, so
+                // all changes to one experience are a single update
+                _ => {
+                    if !updated.insert(id) {
+                        continue;
+                    }
+                    if let Some(experience) = file.experiences.iter().find(|e| e.id == Some(id)) {
+                        self.experience_service.update_experience(&id.to_hex(), experience.clone()).await?;
+                    }
+                }
+            }
+        }
+        plan.applied = true;
+        Ok(plan)
+    }
+}
+
+fn validate_experiences(experiences: &[Experience]) -> Result<(), AppError> {
+    let mut messages = Vec::new();
+    let mut ids = HashSet::new();
+    for (index, experience) in experiences.iter().enumerate() {
+        if let Err(errors) = experience.validate() {
+            messages.extend(validation_messages(&errors).into_iter().map(|m| format!("experiences[{}].{}", index, m)));
+        }
+        if let Some(id) = experience.id.filter(|id| !ids.insert(*id)) {
+            messages.push(format!("experiences[{}]._id: duplicate id {}", index, id));
+        }
+    }
+    if messages.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(messages.join("; ")))
+    }
+}
@@ -0,0 +1,176 @@
+use mongodb::bson::oid::ObjectId;
+use crate::models::experience::Experience;
+use crate::models::resume::{Resume, ResumeExperience};
+use crate::models::skill::Skill;
+use crate::models::tailor::{RankedExperience, RankedResponsibility, RankedSkill, TailorReport, TailorRequest};
+use crate::services::experience_service::ExperienceService;
+use crate::services::import_service::or_empty;
+use crate::services::relevance::{extract_keywords, Bm25};
+use crate::services::resume_service::ResumeService;
+use crate::services::skill_service::SkillService;
+use crate::errors::AppError;
+
+/// Name of a draft resume when the request does not give one.
+pub const DEFAULT_DRAFT_NAME: &str = "Tailored resume";
+
+#[derive(Clone)]
+pub struct TailorService {
+    experience_service: ExperienceService<'static, Experience>,
+    skill_service: SkillService,
+    resume_service: ResumeService,
+}
+
+impl TailorService {
+    pub fn new(
+        experience_service: ExperienceService<'static, Experience>,
+        skill_service: SkillService,
+        resume_service: ResumeService,
+    ) -> Self {
+        Self { experience_service, skill_service, resume_service }
+    }
+
+    /// Ranks the stored experiences (the profile's, when one is given),
+    /// their responsibilities and all skills against the job description,
+    /// and stores a draft resume when asked to.
+    pub async fn tailor(&self, request: &TailorRequest) -> Result<TailorReport, AppError> {
+        let experiences = match &request.profile_id {
+            Some(profile_id) => self.experience_service.get_experiences_by_profile(profile_id).await?,
+            None => or_empty(self.experience_service.get_experiences().await)?,
+        };
+        let skills = or_empty(self.skill_service.get_skills().await)?;
+        let mut report = rank(&request.job_description, &experiences, &skills);
+
+        if request.create_resume {
+            let profile_id = request.profile_id
+                .ok_or_else(|| AppError::ValidationError("profile_id is required to create a resume".to_string()))?;
+            let name = request.resume_name.clone().unwrap_or_else(|| DEFAULT_DRAFT_NAME.to_string());
+            let draft = draft_resume(&report, &experiences, profile_id, name, request.top);
+            report.resume = Some(self.resume_service.create_resume(draft).await?);
+        }
+        Ok(report)
+    }
+}
+
+/// Ranks experiences, responsibilities and skills by BM25 relevance to the
+/// job description, each against the others of its kind. An experience is
+/// matched on its title, bullets and environment, a responsibility on its
+/// bullet and the situation and task of its STAR story, and a skill on its
+/// name, description and categories. Ties keep the input order.
+pub fn rank(job_description: &str, experiences: &[Experience], skills: &[Skill]) -> TailorReport {
+    let keywords = extract_keywords(job_description);
+
+    let documents: Vec<String> = experiences.iter()
+        .map(|e| {
+            let mut parts = vec![e.job_title.clone()];
+            parts.extend(e.responsibilities.iter().map(|r| r.bullet()));
+            parts.extend(e.environment.iter().map(|s| s.name.clone()));
+            parts.join("\n")
+        })
+        .collect();
+    let index = Bm25::new(&documents);
+    let mut ranked_experiences: Vec<RankedExperience> = experiences.iter().enumerate()
+        .map(|(i, e)| {
+            let relevance = index.score(i, &keywords);
+            RankedExperience {
+                experience_id: e.id,
+                job_title: e.job_title.clone(),
+                company: e.company.clone(),
+                score: relevance.score,
+                matched: relevance.matched,
+            }
+        })
+        .collect();
+
+    let responsibilities: Vec<(&Experience, _)> = experiences.iter()
+        .flat_map(|e| e.ordered_responsibilities().into_iter().map(move |r| (e, r)))
+        .collect();
+    let documents: Vec<String> = responsibilities.iter()
+        .map(|(_, r)| {
+            let mut parts = vec![r.bullet()];
+            if let Some(star) = &r.star {
+                parts.extend(star.situation.iter().chain(&star.task).cloned());
+            }
+            parts.join("\n")
+        })
+        .collect();
+    let index = Bm25::new(&documents);
+    let mut ranked_responsibilities: Vec<RankedResponsibility> = responsibilities.iter().enumerate()
+        .map(|(i, (e, r))| {
+            let relevance = index.score(i, &keywords);
+            RankedResponsibility {
+                experience_id: e.id,
+                responsibility_id: r.id,
+                text: r.bullet(),
+                score: relevance.score,
+                matched: relevance.matched,
+            }
+        })
+        .collect();
+
+    let documents: Vec<String> = skills.iter()
+        .map(|s| {
+            let categories: Vec<&str> = s.categories.iter().map(|c| c.name.as_str()).collect();
+            format!("{}\n{}\n{}", s.name, s.description, categories.join(" "))
+        })
+        .collect();
+    let index = Bm25::new(&documents);
+    let mut ranked_skills: Vec<RankedSkill> = skills.iter().enumerate()
+        .map(|(i, s)| {
+            let relevance = index.score(i, &keywords);
+            RankedSkill { skill_id: s.id, name: s.name.clone(), score: relevance.score, matched: relevance.matched }
+        })
+        .collect();
+
+    ranked_experiences.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked_responsibilities.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked_skills.sort_by(|a, b| b.score.total_cmp(&a.score));
+    TailorReport {
+        keywords,
+        experiences: ranked_experiences,
+        responsibilities: ranked_responsibilities,
+        skills: ranked_skills,
+        resume: None,
+    }
+}
+
+/// A resume keeping each matching job with its `top` most relevant
+/// bullets, most relevant first, and the matching skills. Jobs are listed
+/// most recent first; a job that matches only through its title or
+/// environment keeps its first `top` bullets.
+pub fn draft_resume(report: &TailorReport, experiences: &[Experience], profile_id: ObjectId, name: String, top: usize) -> Resume {
+    let mut matching: Vec<&Experience> = experiences.iter()
+        .filter(|e| report.experiences.iter().any(|r| r.experience_id == e.id && r.score > 0.0))
+        .collect();
+    matching.sort_by(|a, b| b.start_date.cmp(&a.start_date));
+
+    let selections = matching.into_iter()
+        .filter_map(|experience| {
+            let mut bullets: Vec<ObjectId> = report.responsibilities.iter()
+                .filter(|r| r.experience_id == experience.id && r.score > 0.0)
+                .filter_map(|r| r.responsibility_id)
+                .take(top)
+                .collect();
+            if bullets.is_empty() {
+                bullets = experience.ordered_responsibilities().iter().filter_map(|r| r.id).take(top).collect();
+            }
+            Some(ResumeExperience {
+                experience_id: experience.id?,
+                responsibilities: Some(bullets),
+                overrides: Default::default(),
+                responsibility_overrides: vec![],
+            })
+        })
+        .collect();
+
+    Resume {
+        id: None,
+        name,
+        profile_id,
+        parent_id: None,
+        summary: None,
+        sections: vec![],
+        experiences: selections,
+        skills: report.skills.iter().filter(|s| s.score > 0.0).filter_map(|s| s.skill_id).collect(),
+        excluded_experiences: vec![],
+    }
+}
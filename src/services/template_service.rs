@@ -0,0 +1,63 @@
+use actix_web::web;
+use mongodb::bson::oid::ObjectId;
+use crate::models::template::{Template, TemplateSummary};
+use crate::renderers::template::{compile_template, RenderLimits};
+use crate::repositories::repository::Repository;
+use crate::repositories::template_repository::TemplateRepository;
+use crate::services::resume_service::ResumeService;
+use crate::errors::AppError;
+
+#[derive(Clone)]
+pub struct TemplateService {
+    repository: TemplateRepository<'static, Template>,
+    resume_service: ResumeService,
+}
+
+impl TemplateService {
+    pub fn new(repository: TemplateRepository<'static, Template>, resume_service: ResumeService) -> Self {
+        Self { repository, resume_service }
+    }
+
+    /// Stores the template once it compiles.
+    pub async fn create_template(&self, mut template: Template) -> Result<Template, AppError> {
+        compile_template(&template)?;
+        template.id.get_or_insert_with(ObjectId::new);
+        self.repository.create(template.clone()).await?;
+        Ok(template)
+    }
+
+    pub async fn get_templates(&self) -> Result<Vec<TemplateSummary>, AppError> {
+        let templates = self.repository.find_all().await?;
+        Ok(templates.iter().map(TemplateSummary::from).collect())
+    }
+
+    pub async fn get_template(&self, id: &str) -> Result<Option<Template>, AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.get(&object_id).await
+    }
+
+    pub async fn update_template(&self, id: &str, mut template: Template) -> Result<(), AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        compile_template(&template)?;
+        template.id = Some(object_id);
+        self.repository.update(&object_id, template).await
+    }
+
+    pub async fn delete_template(&self, id: &str) -> Result<(), AppError> {
+        let object_id = ObjectId::parse_str(id).map_err(|e| AppError::InvalidObjectId(e.to_string()))?;
+        self.repository.delete(&object_id).await
+    }
+
+    /// Renders the template against a resume. Rendering runs on the
+    /// blocking thread pool so that a slow template cannot stall the server.
+    pub async fn preview(&self, id: &str, resume_id: &str, date_format: String) -> Result<String, AppError> {
+        let template = self.get_template(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Template {} not found", id)))?;
+        let resume = self.resume_service.resolve(resume_id).await?;
+        let compiled = compile_template(&template)?;
+        web::block(move || compiled.render(&resume, &date_format, RenderLimits::default()))
+            .await
+            .map_err(|e| AppError::InternalServerError(format!("Template rendering was interrupted: {}", e)))?
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::{Datelike, NaiveDate};
+use crate::models::experience::Experience;
+use crate::models::timeline::{Gap, Inconsistency, Overlap, Timeline, TimelineRole, YearBreakdown};
+
+/// Shortest gap, in days, reported when the request does not give one.
+pub const DEFAULT_GAP_DAYS: u32 = 90;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+// A role's dates, with an end no later than today
+struct Period<'a> {
+    start: NaiveDate,
+    end: NaiveDate,
+    experience: &'a Experience,
+}
+
+// Consecutive roles with no day between them
+struct Block<'a> {
+    start: NaiveDate,
+    end: NaiveDate,
+    first: &'a Experience,
+    last: &'a Experience,
+}
+
+/// Lays the experiences out from their `start_date` and `end_date`, both
+/// inclusive. Roles still running count until `today`; roles with invalid
+/// dates or starting after today are reported and left out. Gaps are only
+/// reported when longer than `gap_days`.
+pub fn timeline(experiences: &[Experience], gap_days: u32, today: NaiveDate) -> Timeline {
+    let mut inconsistencies = Vec::new();
+    let mut periods = Vec::new();
+    for experience in experiences {
+        let start = NaiveDate::parse_from_str(&experience.start_date, DATE_FORMAT);
+        let end = NaiveDate::parse_from_str(&experience.end_date, DATE_FORMAT);
+        let (Ok(start), Ok(end)) = (start, end) else {
+            inconsistencies.push(inconsistency("invalid-date", experience, format!(
+                "Dates must be in YYYY-MM-DD format, got '{}' to '{}'", experience.start_date, experience.end_date
+            )));
+            continue;
+        };
+        if end < start {
+            inconsistencies.push(inconsistency("end-before-start", experience, format!(
+                "Ends on {} before it starts on {}", end, start
+            )));
+        } else if start > today {
+            inconsistencies.push(inconsistency("starts-in-future", experience, format!(
+                "Starts on {}, which is after today", start
+            )));
+        } else {
+            periods.push(Period { start, end: end.min(today), experience });
+        }
+    }
+    periods.sort_by_key(|period| (period.start, period.end));
+
+    let mut overlaps = Vec::new();
+    for (i, first) in periods.iter().enumerate() {
+        for second in periods[i + 1..].iter().take_while(|second| second.start <= first.end) {
+            let end = first.end.min(second.end);
+            overlaps.push(Overlap {
+                start: second.start.to_string(),
+                end: end.to_string(),
+                days: days(second.start, end),
+                roles: [role(first.experience), role(second.experience)],
+            });
+        }
+    }
+
+    let mut blocks: Vec<Block> = Vec::new();
+    for period in &periods {
+        match blocks.last_mut() {
+            Some(block) if (period.start - block.end).num_days() <= 1 => {
+                if period.end > block.end {
+                    block.end = period.end;
+                    block.last = period.experience;
+                }
+            }
+            _ => blocks.push(Block { start: period.start, end: period.end, first: period.experience, last: period.experience }),
+        }
+    }
+    let gaps = blocks.windows(2)
+        .filter_map(|pair| {
+            let (start, end) = (pair[0].end.succ_opt()?, pair[1].start.pred_opt()?);
+            let length = days(start, end);
+            (length > i64::from(gap_days)).then(|| Gap {
+                start: start.to_string(),
+                end: end.to_string(),
+                days: length,
+                after: role(pair[0].last),
+                before: role(pair[1].first),
+            })
+        })
+        .collect();
+
+    let total_days: i64 = blocks.iter().map(|block| days(block.start, block.end)).sum();
+    let years = match (blocks.first(), blocks.last()) {
+        (Some(first), Some(last)) => (first.start.year()..=last.end.year())
+            .map(|year| {
+                let from = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+                let to = NaiveDate::from_ymd_opt(year, 12, 31).unwrap();
+                YearBreakdown {
+                    year,
+                    days: blocks.iter().map(|block| days(block.start.max(from), block.end.min(to)).max(0)).sum(),
+                    roles: periods.iter()
+                        .filter(|period| period.start <= to && period.end >= from)
+                        .map(|period| role(period.experience))
+                        .collect(),
+                }
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Timeline {
+        total_days,
+        total_years: (total_days as f64 / 365.25 * 100.0).round() / 100.0,
+        gaps,
+        overlaps,
+        inconsistencies,
+        years,
+    }
+}
+
+// Inclusive of both ends
+fn days(start: NaiveDate, end: NaiveDate) -> i64 {
+    (end - start).num_days() + 1
+}
+
+fn role(experience: &Experience) -> TimelineRole {
+    TimelineRole {
+        experience_id: experience.id,
+        job_title: experience.job_title.clone(),
+        company: experience.company.clone(),
+    }
+}
+
+fn inconsistency(kind: &str, experience: &Experience, message: String) -> Inconsistency {
+    Inconsistency { kind: kind.to_string(), message, role: role(experience) }
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use rust_server::models::ats::Severity;
+use rust_server::models::resume::{ResumeSection, SectionKind};
+use rust_server::services::ats::ats_report;
+
+#[test]
+fn test_clean_resume_scores_full_marks() {
+    let report = ats_report(&common::resolved_resume(), None);
+    assert_eq!(report.findings, vec![]);
+    assert_eq!(report.score, 100);
+    assert!(report.keywords.is_none());
+}
+
+#[test]
+fn test_findings_have_rules_and_locations() {
+    let mut resume = common::resolved_resume();
+    resume.profile.email = None;
+    resume.sections = vec![ResumeSection::new(SectionKind::Summary), ResumeSection::new(SectionKind::Experience)];
+    resume.summary = Some("Shipping “reliable” systems since Jan 2018 — and counting.".to_string());
+    resume.experiences[0].responsibilities[0] = common::responsibility(&"Scaled services ".repeat(14));
+    resume.experiences[1].responsibilities[0] = common::responsibility("Built tooling adopted on 03/2019 by 200 engineers");
+    resume.experiences[1].end_date = "2017-12-31".to_string();
+
+    let report = ats_report(&resume, None);
+    let found: Vec<(&str, Option<&str>)> = report.findings.iter()
+        .map(|f| (f.rule.as_str(), f.location.as_deref()))
+        .collect();
+    assert_eq!(found, vec![
+        ("contact-missing", Some("profile.email")),
+        ("date-range-invalid", Some("experiences[1]")),
+        ("section-missing", Some("skills")),
+        ("bullet-too-long", Some("experiences[0].responsibilities[0]")),
+        ("non-ascii", Some("summary")),
+        ("date-format-mixed", None),
+    ]);
+    assert_eq!(report.findings[0].severity, Severity::Error);
+    assert!(report.findings[4].message.contains("'“' with '\"'"));
+    assert!(report.findings[4].message.contains("'—' with '--'"));
+    assert!(report.findings[5].message.contains("'Jan 2018' (summary)"));
+    assert_eq!(report.score, 100 - 15 - 15 - 5 * 4);
+}
+
+#[test]
+fn test_job_description_coverage() {
+    let job = "Backend engineer for Rust and Kubernetes services. Engineers pair on Terraform and Kubernetes.";
+    let report = ats_report(&common::resolved_resume(), Some(job));
+    let keywords = report.keywords.unwrap();
+
+    // The certificate mentions Kubernetes
+    assert_eq!(keywords.matched, vec!["engineer", "kubernetes", "rust"]);
+    assert_eq!(keywords.missing, vec!["terraform"]);
+    assert_eq!(keywords.coverage, 0.75);
+    assert_eq!(report.findings.len(), 1);
+    assert_eq!(report.findings[0].rule, "keyword-missing");
+    assert!(report.findings[0].message.contains("'terraform'"));
+    assert_eq!(report.score, 87);
+}
@@ -0,0 +1,120 @@
+#![allow(dead_code)]
+
+use mongodb::bson::oid::ObjectId;
+use rust_server::models::category::Category;
+use rust_server::models::certificate::Certificate;
+use rust_server::models::education::Education;
+use rust_server::models::experience::Experience;
+use rust_server::models::language::Language;
+use rust_server::models::profile::{Profile, ProfileLink};
+use rust_server::models::responsibility::{Responsibility, Star};
+use rust_server::models::resume::{ResolvedResume, ResumeSection};
+use rust_server::models::skill::Skill;
+
+pub fn skill(name: &str, category: Option<&str>) -> Skill {
+    Skill {
+        id: Some(ObjectId::new()),
+        name: name.to_string(),
+        description: name.to_string(),
+        categories: category
+            .map(|c| vec![Category { id: None, name: c.to_string() }])
+            .unwrap_or_default(),
+        next: None,
+    }
+}
+
+pub fn responsibility(name: &str) -> Responsibility {
+    Responsibility { id: Some(ObjectId::new()), name: name.to_string(), next: None, star: None }
+}
+
+pub fn profile() -> Profile {
+    Profile {
+        id: Some(ObjectId::new()),
+        name: "Ada Lovelace".to_string(),
+        label: Some("Backend Engineer".to_string()),
+        email: Some("ada@example.com".to_string()),
+        phone: Some("+44 20 7946 0000".to_string()),
+        url: Some("https://ada.example.com".to_string()),
+        location: Some("London, UK".to_string()),
+        summary: Some("Engineer focused on reliable distributed systems.".to_string()),
+        links: vec![ProfileLink {
+            network: "GitHub".to_string(),
+            username: Some("ada".to_string()),
+            url: "https://github.com/ada".to_string(),
+        }],
+        education: vec![Education {
+            institution: "University of London".to_string(),
+            area: Some("Mathematics".to_string()),
+            study_type: Some("BSc".to_string()),
+            start_date: Some("2014-09-01".to_string()),
+            end_date: Some("2017-06-30".to_string()),
+            score: None,
+        }],
+        languages: vec![
+            Language { name: "English".to_string(), native: true, level: None },
+            Language { name: "French".to_string(), native: false, level: Some("B2".to_string()) },
+        ],
+        certificates: vec![Certificate {
+            name: "Certified Kubernetes Administrator".to_string(),
+            issuer: Some("CNCF".to_string()),
+            date: Some("2022-05-01".to_string()),
+            url: None,
+        }],
+    }
+}
+
+pub fn experiences() -> Vec<Experience> {
+    vec![
+        Experience {
+            id: Some(ObjectId::new()),
+            profile_id: None,
+            job_title: "Senior Backend Engineer".to_string(),
+            company: "Acme & Sons".to_string(),
+            location: "London".to_string(),
+            start_date: "2021-03-01".to_string(),
+            end_date: "2024-02-29".to_string(),
+            responsibilities: vec![
+                responsibility("Led the migration of billing to Rust, cutting costs by 30%"),
+                Responsibility {
+                    star: Some(Star {
+                        situation: Some("Checkout was slow.".to_string()),
+                        task: None,
+                        action: Some("Introduced request caching".to_string()),
+                        result: Some("Reduced p99 latency by 45%".to_string()),
+                    }),
+                    ..responsibility("Checkout performance")
+                },
+            ],
+            environment: vec![skill("Rust", Some("Languages"))],
+        },
+        Experience {
+            id: Some(ObjectId::new()),
+            profile_id: None,
+            job_title: "Software Engineer".to_string(),
+            company: "Globex".to_string(),
+            location: "Remote".to_string(),
+            start_date: "2018-01-15".to_string(),
+            end_date: "2021-02-28".to_string(),
+            responsibilities: vec![responsibility("Built internal tooling for 200 engineers")],
+            environment: vec![],
+        },
+    ]
+}
+
+pub fn resolved_resume() -> ResolvedResume {
+    let profile = profile();
+    ResolvedResume {
+        resume_id: Some(ObjectId::new()),
+        name: "Backend".to_string(),
+        summary: profile.summary.clone(),
+        profile,
+        sections: ResumeSection::default_layout(),
+        experiences: experiences(),
+        skills: vec![
+            skill("Rust", Some("Languages")),
+            skill("PostgreSQL", Some("Databases")),
+            skill("Go", Some("Languages")),
+            skill("Leadership", None),
+        ],
+    }
+}
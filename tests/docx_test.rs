@@ -0,0 +1,68 @@
+mod common;
+
+use std::io::{Cursor, Read};
+use rust_server::renderers::docx::{escape, render_docx, DocxOptions};
+use rust_server::renderers::pdf::PageSize;
+
+fn part(document: &[u8], name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(Cursor::new(document)).unwrap();
+    let mut content = String::new();
+    archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+    content
+}
+
+#[test]
+fn test_docx_package() {
+    let document = render_docx(&common::resolved_resume(), &DocxOptions::default(), "2024-05-01T10:00:00Z").unwrap();
+
+    let archive = zip::ZipArchive::new(Cursor::new(&document)).unwrap();
+    let mut names: Vec<&str> = archive.file_names().collect();
+    names.sort();
+    assert_eq!(names, vec![
+        "[Content_Types].xml",
+        "_rels/.rels",
+        "docProps/core.xml",
+        "word/_rels/document.xml.rels",
+        "word/document.xml",
+        "word/numbering.xml",
+        "word/styles.xml",
+    ]);
+
+    let core = part(&document, "docProps/core.xml");
+    assert!(core.contains("<dc:title>Ada Lovelace – Backend</dc:title>"));
+    assert!(core.contains("<dcterms:created xsi:type=\"dcterms:W3CDTF\">2024-05-01T10:00:00Z</dcterms:created>"));
+
+    let rels = part(&document, "word/_rels/document.xml.rels");
+    assert!(rels.contains("Target=\"mailto:ada@example.com\" TargetMode=\"External\""));
+    assert!(rels.contains("Target=\"https://github.com/ada\""));
+}
+
+#[test]
+fn test_docx_structure() {
+    let document = render_docx(&common::resolved_resume(), &DocxOptions::default(), "2024-05-01T10:00:00Z").unwrap();
+    let xml = part(&document, "word/document.xml");
+
+    assert!(xml.contains("<w:pStyle w:val=\"Title\"/></w:pPr><w:r><w:t xml:space=\"preserve\">Ada Lovelace</w:t>"));
+    assert!(xml.contains("<w:pStyle w:val=\"Heading1\"/></w:pPr><w:r><w:t xml:space=\"preserve\">Experience</w:t>"));
+    assert!(xml.contains("<w:pStyle w:val=\"Heading2\"/></w:pPr><w:r><w:t xml:space=\"preserve\">Senior Backend Engineer — Acme &amp; Sons</w:t>"));
+    assert_eq!(xml.matches("<w:pStyle w:val=\"ListBullet\"/>").count(), 3);
+    assert!(xml.contains("<w:t xml:space=\"preserve\">Introduced request caching, reduced p99 latency by 45%</w:t>"));
+    assert!(xml.contains("<w:tblStyle w:val=\"SkillsTable\"/>"));
+    assert!(xml.contains("<w:t xml:space=\"preserve\">Languages</w:t></w:r></w:p></w:tc><w:tc><w:tcPr><w:tcW w:w=\"7000\" w:type=\"dxa\"/></w:tcPr><w:p><w:r><w:t xml:space=\"preserve\">Rust, Go</w:t>"));
+    assert!(xml.contains("<w:pgSz w:w=\"11906\" w:h=\"16838\"/>"));
+
+    let styles = part(&document, "word/styles.xml");
+    for style in ["Title", "Heading1", "Heading2", "ListBullet", "EntryDetails", "SkillsTable", "Hyperlink"] {
+        assert!(styles.contains(&format!("w:styleId=\"{}\"", style)));
+    }
+
+    let letter = DocxOptions { page_size: PageSize::Letter, ..DocxOptions::default() };
+    let document = render_docx(&common::resolved_resume(), &letter, "2024-05-01T10:00:00Z").unwrap();
+    assert!(part(&document, "word/document.xml").contains("<w:pgSz w:w=\"12240\" w:h=\"15840\"/>"));
+}
+
+#[test]
+fn test_escape() {
+    assert_eq!(escape("R&D <team> \"x\" 'y'"), "R&amp;D &lt;team&gt; &quot;x&quot; &apos;y&apos;");
+    assert_eq!(escape("a\u{1}b\tc"), "ab c");
+}
@@ -55,10 +55,17 @@ async fn test_validation_error() {
     assert!(body["timestamp"].as_str().is_some());
 }
 
+#[actix_web::test]
+async fn test_not_acceptable_error() {
+    let error = AppError::NotAcceptable("Supported types: application/json".to_string());
+    assert_eq!(format!("{}", error), "Not Acceptable: Supported types: application/json");
+    assert_eq!(error.error_response().status(), StatusCode::NOT_ACCEPTABLE);
+}
+
 #[actix_web::test]
 async fn test_error_conversions() {
     // Test MongoDB error conversion
-    let mongo_error = MongoError::from(std::io::Error::new(std::io::ErrorKind::Other, "DB error"));
+    let mongo_error = MongoError::from(std::io::Error::other("DB error"));
     let app_error: AppError = mongo_error.into();
     assert!(matches!(app_error, AppError::DatabaseError(_)));
 
@@ -68,7 +75,7 @@ async fn test_error_conversions() {
     assert!(matches!(app_error, AppError::SerializationError(_)));
 
     // Test IO error conversion
-    let io_error = IoError::new(std::io::ErrorKind::Other, "IO error");
+    let io_error = IoError::other("IO error");
     let app_error: AppError = io_error.into();
     assert!(matches!(app_error, AppError::IoError(_)));
 }
@@ -96,3 +103,21 @@ async fn test_error_response_structure() {
     assert_eq!(body["error_code"], "ERR_SERVER");
     assert!(body["timestamp"].as_str().is_some());
 }
+
+#[actix_web::test]
+async fn test_validation_messages() {
+    use rust_server::errors::validation_messages;
+    use rust_server::models::profile::{Profile, ProfileLink};
+    use validator::Validate;
+
+    let profile = Profile {
+        name: String::new(),
+        links: vec![ProfileLink { network: "GitHub".to_string(), username: None, url: "not a url".to_string() }],
+        ..Profile::default()
+    };
+    let errors = profile.validate().unwrap_err();
+    assert_eq!(validation_messages(&errors), vec![
+        "links[0].url: Link must be a valid URL".to_string(),
+        "name: Profile name is required".to_string(),
+    ]);
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use chrono::NaiveDate;
+use rust_server::importers::europass::map_europass;
+use rust_server::importers::MappedImport;
+use rust_server::models::experience::Experience;
+use rust_server::models::language::Language;
+use rust_server::models::resume::{ResolvedResume, ResumeSection};
+use rust_server::renderers::europass::render_europass;
+
+const SAMPLE: &str = include_str!("fixtures/europass/grace-hopper.xml");
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+}
+
+fn resume_from(import: &MappedImport) -> ResolvedResume {
+    ResolvedResume {
+        resume_id: None,
+        name: "Europass".to_string(),
+        profile: import.profile.clone().unwrap(),
+        summary: None,
+        sections: ResumeSection::default_layout(),
+        experiences: import.experiences.iter().map(|(_, e)| e.clone()).collect(),
+        skills: import.skills.iter().map(|(_, s)| s.clone()).collect(),
+    }
+}
+
+fn bullets(experience: &Experience) -> Vec<String> {
+    experience.ordered_responsibilities().into_iter().map(|r| r.name).collect()
+}
+
+fn skills(import: &MappedImport) -> Vec<(String, Vec<String>)> {
+    import.skills.iter()
+        .map(|(_, s)| (s.name.clone(), s.categories.iter().map(|c| c.name.clone()).collect()))
+        .collect()
+}
+
+#[test]
+fn test_maps_sample_file() {
+    let import = map_europass(SAMPLE, today());
+    let report = &import.report;
+
+    let profile = import.profile.as_ref().unwrap();
+    assert_eq!(profile.name, "Grace Brewster Hopper");
+    assert_eq!(profile.label.as_deref(), Some("Computer Scientist & Rear Admiral"));
+    assert_eq!(profile.location.as_deref(), Some("Arlington, United States"));
+    assert_eq!(profile.phone.as_deref(), Some("+1 703 555 0100"));
+    assert_eq!(profile.url.as_deref(), Some("https://grace.example.com"));
+    assert_eq!(profile.links.len(), 1);
+    assert_eq!((profile.links[0].network.as_str(), profile.links[0].url.as_str()), ("GitHub", "https://github.com/ghopper"));
+
+    assert_eq!(import.experiences.len(), 2);
+    let (source, navy) = &import.experiences[0];
+    assert_eq!(source, "LearnerInfo.WorkExperienceList.WorkExperience[0]");
+    assert_eq!((navy.start_date.as_str(), navy.end_date.as_str()), ("1967-08-01", "1986-08-14"));
+    assert_eq!(navy.location, "Washington");
+    assert_eq!(bullets(navy), vec!["Standardised COBOL across the Navy", "Validated compilers for R&D programmes"]);
+    let eckert = &import.experiences[1].1;
+    assert_eq!((eckert.start_date.as_str(), eckert.end_date.as_str()), ("1949-01-01", "2024-06-01"));
+    assert_eq!(bullets(eckert), vec!["Built the A-0 compiler", "Designed FLOW-MATIC"]);
+
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].source, "LearnerInfo.WorkExperienceList.WorkExperience[2]");
+    assert!(report.failures[0].messages.iter().any(|m| m.contains("Location is required")));
+
+    assert_eq!(profile.education.len(), 1);
+    let education = &profile.education[0];
+    assert_eq!(education.institution, "Yale University");
+    assert_eq!((education.study_type.as_deref(), education.area.as_deref()), (Some("PhD"), Some("Mathematics")));
+    assert_eq!((education.start_date.as_deref(), education.end_date.as_deref()), (Some("1930-01-01"), Some("1934-01-01")));
+
+    assert_eq!(profile.languages, vec![
+        Language { name: "English".to_string(), native: true, level: None },
+        Language { name: "German".to_string(), native: false, level: Some("B1".to_string()) },
+        Language { name: "Latin".to_string(), native: false, level: None },
+    ]);
+    assert!(report.warnings.iter().any(|w| w.contains("ForeignLanguage[0]") && w.contains("B1, B2, C1")));
+
+    assert_eq!(skills(&import), vec![
+        ("COBOL".to_string(), vec!["Languages".to_string()]),
+        ("FLOW-MATIC".to_string(), vec!["Languages".to_string()]),
+        ("Debugging".to_string(), vec![]),
+    ]);
+
+    for path in [
+        "LearnerInfo.Identification.Demographics",
+        "LearnerInfo.WorkExperienceList.WorkExperience[0].Employer.Sector",
+        "LearnerInfo.EducationList.Education[0].Level",
+        "LearnerInfo.Skills.Communication",
+        "LearnerInfo.AchievementList",
+    ] {
+        assert!(report.unmapped.contains(&path.to_string()), "{} should be unmapped", path);
+    }
+}
+
+#[test]
+fn test_sample_file_round_trips() {
+    let import = map_europass(SAMPLE, today());
+    let xml = render_europass(&resume_from(&import), "2024-06-01T00:00:00Z");
+    let again = map_europass(&xml, today());
+
+    assert!(again.report.failures.is_empty(), "{:?}", again.report.failures);
+    assert!(again.report.unmapped.is_empty(), "{:?}", again.report.unmapped);
+    let (before, after) = (import.profile.as_ref().unwrap(), again.profile.as_ref().unwrap());
+    assert_eq!(after.name, before.name);
+    assert_eq!(after.label, before.label);
+    assert_eq!(after.location, before.location);
+    assert_eq!(after.url, before.url);
+    assert_eq!(after.links.len(), before.links.len());
+    assert_eq!(after.education, before.education);
+    assert_eq!(after.languages, before.languages);
+    assert_eq!(again.experiences.len(), import.experiences.len());
+    for ((_, before), (_, after)) in import.experiences.iter().zip(&again.experiences) {
+        assert_eq!((&after.job_title, &after.company, &after.location), (&before.job_title, &before.company, &before.location));
+        assert_eq!((&after.start_date, &after.end_date), (&before.start_date, &before.end_date));
+        assert_eq!(bullets(after), bullets(before));
+    }
+    assert_eq!(skills(&again), skills(&import));
+}
+
+#[test]
+fn test_export_round_trips_resume() {
+    let resume = common::resolved_resume();
+    let xml = render_europass(&resume, "2024-05-01T10:00:00Z");
+    assert!(xml.contains(r#"<SkillsPassport xmlns="http://europass.cedefop.europa.eu/Europass""#));
+    assert!(xml.contains(r#"<From year="2021" month="--03" day="---01"/>"#));
+    assert!(xml.contains("<Name>Acme &amp; Sons</Name>"));
+    assert!(xml.contains("<Listening>B2</Listening>"));
+
+    let import = map_europass(&xml, today());
+    assert!(import.report.failures.is_empty(), "{:?}", import.report.failures);
+    assert!(import.report.unmapped.is_empty(), "{:?}", import.report.unmapped);
+
+    let profile = import.profile.as_ref().unwrap();
+    assert_eq!(profile.name, resume.profile.name);
+    assert_eq!(profile.label, resume.profile.label);
+    assert_eq!((&profile.email, &profile.phone, &profile.url), (&resume.profile.email, &resume.profile.phone, &resume.profile.url));
+    assert_eq!(profile.location, resume.profile.location);
+    assert_eq!((profile.links[0].network.as_str(), profile.links[0].url.as_str()), ("GitHub", "https://github.com/ada"));
+    assert_eq!(profile.education, resume.profile.education);
+    assert_eq!(profile.languages, resume.profile.languages);
+
+    assert_eq!(import.experiences.len(), resume.experiences.len());
+    for ((_, imported), original) in import.experiences.iter().zip(&resume.experiences) {
+        assert_eq!((&imported.job_title, &imported.company, &imported.location), (&original.job_title, &original.company, &original.location));
+        assert_eq!((&imported.start_date, &imported.end_date), (&original.start_date, &original.end_date));
+        let expected: Vec<String> = original.responsibilities.iter().map(|r| r.bullet()).collect();
+        assert_eq!(bullets(imported), expected);
+    }
+
+    let mut expected: Vec<(String, Vec<String>)> = resume.skills.iter()
+        .map(|s| (s.name.clone(), s.categories.iter().map(|c| c.name.clone()).collect()))
+        .collect();
+    let mut imported = skills(&import);
+    expected.sort();
+    imported.sort();
+    assert_eq!(imported, expected);
+}
+
+#[test]
+fn test_rejects_malformed_documents() {
+    let import = map_europass("<SkillsPassport><LearnerInfo></SkillsPassport>", today());
+    assert_eq!(import.report.failures[0].source, "$");
+    assert!(import.profile.is_none());
+
+    let import = map_europass("<resume/>", today());
+    assert_eq!(import.report.failures[0].messages, vec!["Root element must be SkillsPassport, found resume"]);
+}
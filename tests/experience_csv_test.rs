@@ -0,0 +1,94 @@
+mod common;
+
+use rust_server::importers::experience_csv::{map_experience_csv, CsvImportMode};
+use rust_server::importers::ImportReport;
+use rust_server::renderers::experience_csv::{render_experience_csv, EXPERIENCE_CSV_COLUMNS};
+
+#[test]
+fn test_export_round_trips() {
+    let experiences = common::experiences();
+    let csv = render_experience_csv(&experiences);
+    assert!(csv.starts_with(&format!("{}\n", EXPERIENCE_CSV_COLUMNS.join(","))));
+    assert!(csv.contains("Led the migration of billing to Rust, cutting costs by 30% | Checkout performance"));
+
+    let mut report = ImportReport::default();
+    let rows = map_experience_csv(&csv, &mut report);
+    assert!(report.failures.is_empty(), "{:?}", report.failures);
+    assert_eq!(rows.len(), 2);
+
+    let row = &rows[0];
+    assert_eq!(row.source, "line 2");
+    assert_eq!(row.experience.id, experiences[0].id);
+    assert_eq!(row.experience.company, "Acme & Sons");
+    assert_eq!(row.experience.end_date, "2024-02-29");
+    let bullets: Vec<String> = row.experience.ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(bullets, vec!["Led the migration of billing to Rust, cutting costs by 30%", "Checkout performance"]);
+    assert_eq!(row.skills, vec!["Rust"]);
+    assert!(rows[1].skills.is_empty());
+}
+
+#[test]
+fn test_list_items_with_delimiters_round_trip() {
+    let mut experiences = common::experiences();
+    experiences[0].responsibilities[0].name = "Built CI | CD pipelines in C:\\ci".to_string();
+    experiences[0].responsibilities[1].name = "Cut build times\nby 40%".to_string();
+    experiences[0].environment[0].name = "PL/SQL | T-SQL".to_string();
+    let csv = render_experience_csv(&experiences);
+    assert!(csv.contains("Built CI \\| CD pipelines in C:\\\\ci | Cut build times\\nby 40%"), "{}", csv);
+
+    let mut report = ImportReport::default();
+    let rows = map_experience_csv(&csv, &mut report);
+    assert!(report.failures.is_empty(), "{:?}", report.failures);
+    let bullets: Vec<String> = rows[0].experience.ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(bullets, vec!["Built CI | CD pipelines in C:\\ci", "Cut build times\nby 40%"]);
+    assert_eq!(rows[0].skills, vec!["PL/SQL | T-SQL"]);
+
+    // A backslash before anything else is kept as typed
+    let csv = "job_title,company,location,start_date,end_date,responsibilities\n\
+        Engineer,Initech,Austin,2016-03-01,2019-12-31,Wrote C:\\Tools docs|Ran on-call\n";
+    let rows = map_experience_csv(csv, &mut report);
+    let bullets: Vec<String> = rows[0].experience.ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(bullets, vec!["Wrote C:\\Tools docs", "Ran on-call"]);
+}
+
+#[test]
+fn test_rows_report_validator_messages() {
+    let csv = "job_title,company,location,start_date,end_date,responsibilities,notes\n\
+        Engineer,Initech,Austin,2016-03-01,2019-12-31,\"Maintained TPS reports\nFixed the printer\",\n\
+        ,Globex,Springfield,March 2015,2016-01-01,,\n\
+        ,,,,,,\n\
+        Intern,Hooli,Palo Alto,2014-06-01,2014-09-01,,coffee\n";
+    let mut report = ImportReport::default();
+    let rows = map_experience_csv(csv, &mut report);
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].experience.responsibilities.len(), 2);
+    assert_eq!(rows[1].source, "line 6");
+
+    assert_eq!(report.failures.len(), 1);
+    let failure = &report.failures[0];
+    assert_eq!(failure.source, "line 4");
+    assert!(failure.messages.iter().any(|m| m.contains("Job title is required")));
+    assert!(failure.messages.iter().any(|m| m.contains("Start date must be in YYYY-MM-DD format")));
+    assert_eq!(report.unmapped, vec!["column notes"]);
+}
+
+#[test]
+fn test_rejects_missing_columns_and_bad_ids() {
+    let mut report = ImportReport::default();
+    assert!(map_experience_csv("job_title,company\nEngineer,Initech\n", &mut report).is_empty());
+    assert_eq!(report.failures[0].messages, vec!["Missing columns: location, start_date, end_date"]);
+
+    let mut report = ImportReport::default();
+    let csv = "id,job_title,company,location,start_date,end_date\nnot-an-id,Engineer,Initech,Austin,2016-03-01,2019-12-31\n";
+    assert!(map_experience_csv(csv, &mut report).is_empty());
+    assert_eq!(report.failures[0].messages, vec!["id: 'not-an-id' is not a valid ObjectId"]);
+}
+
+#[test]
+fn test_import_modes() {
+    assert_eq!("best_effort".parse::<CsvImportMode>().unwrap(), CsvImportMode::BestEffort);
+    assert_eq!("ALL_OR_NOTHING".parse::<CsvImportMode>().unwrap(), CsvImportMode::AllOrNothing);
+    assert_eq!(CsvImportMode::default(), CsvImportMode::AllOrNothing);
+    assert!("partial".parse::<CsvImportMode>().is_err());
+}
@@ -20,6 +20,7 @@ fn test_find_all() {
         // Insert test data
         let test_experience = Experience {
             id: None,
+            profile_id: None,
             job_title: "Test Job Title".to_string(),
             company: "Test Company".to_string(),
             location: "Test Location".to_string(),
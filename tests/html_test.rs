@@ -0,0 +1,107 @@
+mod common;
+
+use rust_server::models::resume::{ResumeSection, SectionKind};
+use rust_server::renderers::html::{render_html, ResumeView, Theme};
+use validator::Validate;
+
+#[test]
+fn test_every_theme_renders() {
+    let resume = common::resolved_resume();
+    for name in Theme::SUPPORTED {
+        let theme: Theme = name.parse().unwrap();
+        let html = render_html(&resume, theme, "%b %Y").unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(&format!("<body class=\"theme-{}\">", name)));
+        assert!(html.contains("<h1 class=\"p-name\">Ada Lovelace</h1>"));
+        assert!(html.contains("Acme &amp; Sons"));
+        assert!(!html.contains("&amp;amp;"));
+        assert!(html.contains("<li>Introduced request caching, reduced p99 latency by 45%</li>"));
+        assert!(html.contains("<time class=\"dt-start\" datetime=\"2021-03-01\">Mar 2021</time>"));
+        assert!(html.contains("<span class=\"institution p-org h-card\">University of London</span>"));
+        assert!(html.contains("<script type=\"application/ld+json\">{\"@context\":\"https://schema.org\""));
+        assert!(html.contains("<dt>Languages</dt>"));
+    }
+    assert!("fancy".parse::<Theme>().is_err());
+}
+
+#[test]
+fn test_render_escapes_content() {
+    let mut resume = common::resolved_resume();
+    resume.summary = Some("<script>alert(1)</script>".to_string());
+    let html = render_html(&resume, Theme::Classic, "%Y").unwrap();
+    assert!(!html.contains("<script>"));
+    assert!(html.contains("&lt;script&gt;"));
+}
+
+#[test]
+fn test_only_web_and_mail_links() {
+    let mut resume = common::resolved_resume();
+    resume.profile.url = Some("javascript:alert(1)".to_string());
+    resume.profile.links[0].url = " JavaScript:alert(1)".to_string();
+    assert!(resume.profile.validate().is_err());
+    let html = render_html(&resume, Theme::Classic, "%Y").unwrap();
+    assert!(!html.to_lowercase().contains("javascript"));
+    assert!(!html.contains("GitHub"));
+
+    resume.profile.url = Some("mailto:ada@example.com".to_string());
+    resume.profile.links[0].url = "https://github.com/ada".to_string();
+    assert!(resume.profile.validate().is_ok());
+}
+
+#[test]
+fn test_view_model() {
+    let mut resume = common::resolved_resume();
+    resume.summary = Some("First paragraph.\n\nSecond paragraph.".to_string());
+    resume.sections = vec![ResumeSection { kind: SectionKind::Skills, title: Some("Toolbox".to_string()) }];
+    let view = ResumeView::new(&resume, "%Y-%m");
+
+    assert_eq!(view.summary, vec!["First paragraph.", "Second paragraph."]);
+    assert_eq!(view.sections.len(), 1);
+    assert_eq!(view.sections[0].title, "Toolbox");
+    assert_eq!(view.experiences[0].start, "2021-03");
+    assert_eq!(view.experiences[0].end_date, "2024-02-29");
+    assert_eq!(view.experiences[0].environment, vec!["Rust"]);
+    let categories: Vec<&str> = view.skill_groups.iter().map(|g| g.category.as_str()).collect();
+    assert_eq!(categories, vec!["Languages", "Databases", "Other"]);
+
+    let html = render_html(&resume, Theme::Modern, "%Y").unwrap();
+    assert!(html.contains("Toolbox"));
+    assert!(!html.contains("Senior Backend Engineer"));
+}
+
+#[test]
+fn test_education_renders_once_whatever_the_layout() {
+    let mut resume = common::resolved_resume();
+    let section = |kind| ResumeSection { kind, title: None };
+    for sections in [
+        vec![section(SectionKind::Skills)],
+        vec![section(SectionKind::Experience), section(SectionKind::Experience)],
+    ] {
+        resume.sections = sections;
+        for name in Theme::SUPPORTED {
+            let html = render_html(&resume, name.parse().unwrap(), "%Y").unwrap();
+            assert_eq!(html.matches("<h2>Education</h2>").count(), 1, "{} with {:?}", name, resume.sections);
+        }
+    }
+}
+
+#[test]
+fn test_h_resume_markup() {
+    let html = render_html(&common::resolved_resume(), Theme::Classic, "%b %Y").unwrap();
+    for class in [
+        "resume h-resume",
+        "p-contact h-card",
+        "label p-job-title",
+        "summary p-summary",
+        "experience p-experience h-event",
+        "job-title p-name",
+        "company p-org h-card",
+        "responsibilities e-description",
+        "education p-education h-event",
+        "skill p-skill",
+    ] {
+        assert!(html.contains(&format!("class=\"{}\"", class)), "missing class {}", class);
+    }
+    assert!(html.contains("<a class=\"u-email\" href=\"mailto:ada@example.com\">"));
+    assert!(html.contains("<a class=\"u-url\" rel=\"me\" href=\"https:&#x2F;&#x2F;github.com&#x2F;ada\">GitHub</a>"));
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use rust_server::models::resume::{ResumeSection, SectionKind};
+use rust_server::models::skill::Skill;
+use rust_server::renderers::json_ld::{person, resume_json_ld, to_script};
+use serde_json::json;
+
+#[test]
+fn test_person_from_resume() {
+    let resume = common::resolved_resume();
+    let document = resume_json_ld(&resume);
+
+    assert_eq!(document["@context"], "https://schema.org");
+    assert_eq!(document["@type"], "Person");
+    assert_eq!(document["name"], "Ada Lovelace");
+    assert_eq!(document["jobTitle"], "Backend Engineer");
+    assert_eq!(document["address"], json!({ "@type": "PostalAddress", "addressLocality": "London", "addressCountry": "UK" }));
+    assert_eq!(document["sameAs"], json!(["https://github.com/ada"]));
+
+    let role = &document["worksFor"][0];
+    assert_eq!(role["@type"], "OrganizationRole");
+    assert_eq!(role["roleName"], "Senior Backend Engineer");
+    assert_eq!((&role["startDate"], &role["endDate"]), (&json!("2021-03-01"), &json!("2024-02-29")));
+    assert_eq!(role["worksFor"], json!({ "@type": "Organization", "name": "Acme & Sons", "location": { "@type": "Place", "name": "London" } }));
+    assert!(role["description"].as_str().unwrap().starts_with("Led the migration of billing to Rust"));
+
+    assert_eq!(document["hasCredential"], json!([{
+        "@type": "EducationalOccupationalCredential",
+        "name": "BSc, Mathematics",
+        "credentialCategory": "BSc",
+        "about": "Mathematics",
+        "recognizedBy": { "@type": "EducationalOrganization", "name": "University of London" },
+        "validFrom": "2017-06-30"
+    }]));
+    assert_eq!(document["knowsLanguage"][1], json!({ "@type": "Language", "name": "French" }));
+    assert_eq!(document["knowsAbout"], json!(["Rust", "PostgreSQL", "Go", "Leadership"]));
+}
+
+#[test]
+fn test_person_follows_layout_and_dedupes_skills() {
+    let mut resume = common::resolved_resume();
+    resume.sections = vec![ResumeSection { kind: SectionKind::Summary, title: None }];
+    let document = resume_json_ld(&resume);
+    assert_eq!(document["description"], "Engineer focused on reliable distributed systems.");
+    assert!(document.get("worksFor").is_none());
+    assert!(document.get("knowsAbout").is_none());
+
+    let experiences = common::experiences();
+    let skills: Vec<&Skill> = experiences.iter().chain(&experiences).flat_map(|e| &e.environment).collect();
+    let document = person(&common::profile(), None, &experiences, &skills);
+    assert_eq!(document["worksFor"].as_array().unwrap().len(), 2);
+    assert_eq!(document["knowsAbout"], json!(["Rust"]));
+
+    // Stored experiences list their bullets in linked order
+    let mut experiences = common::experiences();
+    experiences[0].responsibilities[1].next = experiences[0].responsibilities[0].id;
+    let document = person(&common::profile(), None, &experiences, &[]);
+    let description = document["worksFor"][0]["description"].as_str().unwrap();
+    assert!(description.starts_with("Introduced request caching"), "{}", description);
+    assert!(description.ends_with("\nLed the migration of billing to Rust, cutting costs by 30%"), "{}", description);
+}
+
+#[test]
+fn test_script_cannot_be_closed_by_content() {
+    let mut resume = common::resolved_resume();
+    resume.summary = Some("</script><script>alert(1)</script>".to_string());
+    let script = to_script(&resume_json_ld(&resume));
+    assert!(!script.contains("</script>"));
+    let parsed: serde_json::Value = serde_json::from_str(&script).unwrap();
+    assert_eq!(parsed["description"], "</script><script>alert(1)</script>");
+    assert_eq!(parsed["worksFor"][0]["worksFor"]["name"], "Acme & Sons");
+}
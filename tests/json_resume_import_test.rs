@@ -0,0 +1,111 @@
+mod common;
+
+use chrono::NaiveDate;
+use rust_server::importers::json_resume::map_json_resume;
+use rust_server::importers::normalize_partial_date;
+use rust_server::renderers::json_resume::export_json_resume;
+use serde_json::json;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+}
+
+#[test]
+fn test_normalize_partial_date() {
+    assert_eq!(normalize_partial_date("2020").as_deref(), Some("2020-01-01"));
+    assert_eq!(normalize_partial_date("2020-07").as_deref(), Some("2020-07-01"));
+    assert_eq!(normalize_partial_date("2020-07-15").as_deref(), Some("2020-07-15"));
+    assert_eq!(normalize_partial_date("2020-13"), None);
+    assert_eq!(normalize_partial_date("July 2020"), None);
+}
+
+#[test]
+fn test_maps_entries_and_reports_per_entry_failures() {
+    let document = json!({
+        "basics": { "name": "Grace Hopper", "email": "grace@example.com", "image": "me.png",
+                    "location": { "city": "Arlington", "region": "VA" } },
+        "work": [
+            { "name": "Navy", "position": "Rear Admiral", "location": "Washington", "startDate": "1967",
+              "endDate": "1986-08", "highlights": ["Standardised COBOL", "Led compiler work"], "url": "https://navy.mil" },
+            { "name": "Eckert-Mauchly", "position": "Programmer", "location": "Philadelphia", "startDate": "March 1949" },
+            { "name": "Remington Rand", "position": "Director", "location": "Philadelphia", "startDate": "1950-01" }
+        ],
+        "skills": [
+            { "name": "Languages", "level": "Master", "keywords": ["COBOL", "FLOW-MATIC"] },
+            { "name": "Mentoring" }
+        ],
+        "volunteer": []
+    });
+
+    let import = map_json_resume(&document, today());
+    let report = &import.report;
+
+    let profile = import.profile.unwrap();
+    assert_eq!(profile.name, "Grace Hopper");
+    assert_eq!(profile.location.as_deref(), Some("Arlington, VA"));
+
+    assert_eq!(import.experiences.len(), 2);
+    let (source, navy) = &import.experiences[0];
+    assert_eq!(source, "work[0]");
+    assert_eq!((navy.start_date.as_str(), navy.end_date.as_str()), ("1967-01-01", "1986-08-01"));
+    let bullets: Vec<String> = navy.ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(bullets, vec!["Standardised COBOL", "Led compiler work"]);
+    assert_eq!(import.experiences[1].1.end_date, "2024-06-01");
+
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].source, "work[1]");
+    assert!(report.failures[0].messages.iter().any(|m| m.contains("Start date must be in YYYY-MM-DD format")));
+    assert!(report.warnings.iter().any(|w| w.starts_with("work[2]")));
+
+    for path in ["basics.image", "work[0].url", "skills[0].level", "volunteer"] {
+        assert!(report.unmapped.contains(&path.to_string()), "{} should be unmapped", path);
+    }
+
+    let skills: Vec<(&str, Vec<&str>)> = import.skills.iter()
+        .map(|(_, s)| (s.name.as_str(), s.categories.iter().map(|c| c.name.as_str()).collect()))
+        .collect();
+    assert_eq!(skills, vec![("COBOL", vec!["Languages"]), ("FLOW-MATIC", vec!["Languages"]), ("Mentoring", vec![])]);
+}
+
+#[test]
+fn test_import_of_exported_document_round_trips() {
+    let resume = common::resolved_resume();
+    let document = export_json_resume(&resume, "2024-05-01T10:00:00Z").unwrap();
+    let import = map_json_resume(&document, today());
+
+    assert!(import.report.failures.is_empty(), "{:?}", import.report.failures);
+    assert!(import.report.unmapped.is_empty(), "{:?}", import.report.unmapped);
+    let profile = import.profile.unwrap();
+    assert_eq!(profile.location, resume.profile.location);
+    assert_eq!(profile.links.len(), 1);
+    assert_eq!(profile.education, resume.profile.education);
+    assert_eq!(profile.languages, resume.profile.languages);
+    assert_eq!(profile.certificates, resume.profile.certificates);
+    assert_eq!(import.experiences.len(), resume.experiences.len());
+    assert_eq!(import.experiences[0].1.company, "Acme & Sons");
+    assert_eq!(import.skills.len(), resume.skills.len());
+}
+
+#[test]
+fn test_maps_language_fluency() {
+    let document = json!({
+        "basics": { "name": "Grace Hopper" },
+        "languages": [
+            { "language": "English", "fluency": "Native speaker" },
+            { "language": "German", "fluency": "b2 - upper intermediate" },
+            { "language": "Latin", "fluency": "Fluent" }
+        ]
+    });
+
+    let import = map_json_resume(&document, today());
+    let languages: Vec<(String, bool, Option<String>)> = import.profile.unwrap().languages
+        .into_iter()
+        .map(|l| (l.name, l.native, l.level))
+        .collect();
+    assert_eq!(languages, vec![
+        ("English".to_string(), true, None),
+        ("German".to_string(), false, Some("B2".to_string())),
+        ("Latin".to_string(), false, None),
+    ]);
+    assert_eq!(import.report.warnings, vec!["languages[2].fluency: 'Fluent' is not a CEFR level and was dropped"]);
+}
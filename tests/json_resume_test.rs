@@ -0,0 +1,48 @@
+mod common;
+
+use rust_server::models::resume::{ResumeSection, SectionKind};
+use rust_server::renderers::json_resume::{export_json_resume, validate_json_resume};
+use serde_json::json;
+
+#[test]
+fn test_export_maps_work_highlights_and_skills() {
+    let document = export_json_resume(&common::resolved_resume(), "2024-05-01T10:00:00Z").unwrap();
+
+    assert_eq!(document["basics"]["name"], "Ada Lovelace");
+    assert_eq!(document["basics"]["location"]["city"], "London");
+    assert_eq!(document["basics"]["profiles"][0]["network"], "GitHub");
+
+    let work = &document["work"][0];
+    assert_eq!(work["name"], "Acme & Sons");
+    assert_eq!(work["position"], "Senior Backend Engineer");
+    assert_eq!(work["startDate"], "2021-03-01");
+    assert_eq!(work["highlights"][1], "Introduced request caching, reduced p99 latency by 45%");
+
+    assert_eq!(document["skills"], json!([
+        { "name": "Languages", "keywords": ["Rust", "Go"] },
+        { "name": "Databases", "keywords": ["PostgreSQL"] },
+        { "name": "Other", "keywords": ["Leadership"] },
+    ]));
+    assert_eq!(document["meta"]["lastModified"], "2024-05-01T10:00:00Z");
+}
+
+#[test]
+fn test_export_respects_layout() {
+    let mut resume = common::resolved_resume();
+    resume.sections = vec![ResumeSection::new(SectionKind::Experience)];
+    let document = export_json_resume(&resume, "2024-05-01T10:00:00Z").unwrap();
+    assert!(document.get("skills").is_none());
+    assert!(document["basics"].get("summary").is_none());
+    assert_eq!(document["work"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_schema_rejects_invalid_documents() {
+    let errors = validate_json_resume(&json!({
+        "work": [{ "name": "Acme", "startDate": "March 2020" }],
+        "unknown": true
+    }))
+    .unwrap_err();
+    assert!(errors.iter().any(|e| e.starts_with("/work/0/startDate")));
+    assert!(validate_json_resume(&json!({ "work": [{ "startDate": "2020-03" }] })).is_ok());
+}
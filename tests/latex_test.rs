@@ -0,0 +1,66 @@
+mod common;
+
+use rust_server::renderers::latex::{escape, render_latex, LatexLayout, LatexOptions};
+
+// Every brace opened in the document is closed, ignoring escaped ones.
+fn balanced(tex: &str) -> bool {
+    let mut depth = 0i32;
+    let mut chars = tex.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+        if depth < 0 {
+            return false;
+        }
+    }
+    depth == 0
+}
+
+#[test]
+fn test_escape_special_characters() {
+    assert_eq!(escape("R&D 100% $5 #1 a_b {x}"), "R\\&D 100\\% \\$5 \\#1 a\\_b \\{x\\}");
+    assert_eq!(escape("~/bin ^2 C:\\Temp"), "\\textasciitilde{}/bin \\textasciicircum{}2 C:\\textbackslash{}Temp");
+    assert_eq!(escape("a <b> | c"), "a \\textless{}b\\textgreater{} \\textbar{} c");
+}
+
+#[test]
+fn test_moderncv_layout() {
+    let tex = render_latex(&common::resolved_resume(), &LatexOptions::default());
+
+    assert!(tex.starts_with("\\documentclass[11pt,a4paper,sans]{moderncv}"));
+    assert!(tex.contains("\\name{Ada}{Lovelace}"));
+    assert!(tex.contains("\\social[github]{ada}"));
+    assert!(tex.contains("\\homepage{ada.example.com}"));
+    assert!(tex.contains("\\cventry{Mar 2021--Feb 2024}{Senior Backend Engineer}{Acme \\& Sons}{London}{}{"));
+    assert!(tex.contains("\\item Led the migration of billing to Rust, cutting costs by 30\\%\n"));
+    assert!(tex.contains("\\cvitem{Languages}{Rust, Go}"));
+    assert!(tex.trim_end().ends_with("\\end{document}"));
+    assert!(balanced(&tex));
+}
+
+#[test]
+fn test_article_layout() {
+    let mut resume = common::resolved_resume();
+    resume.experiences[1].job_title = "Engineer {Tools} & #Infra_Team".to_string();
+    resume.skills[0].name = "C#".to_string();
+    let options = LatexOptions { layout: LatexLayout::Article, date_format: "%m/%Y".to_string() };
+    let tex = render_latex(&resume, &options);
+
+    assert!(tex.starts_with("\\documentclass[11pt,a4paper]{article}"));
+    assert!(tex.contains("\\href{mailto:ada@example.com}{ada@example.com}"));
+    assert!(tex.contains("\\subsection*{Engineer \\{Tools\\} \\& \\#Infra\\_Team, Globex \\hfill {\\normalfont\\small 01/2018--02/2021}}"));
+    assert!(tex.contains("\\item[{Languages}] C\\#, Go"));
+    assert!(balanced(&tex));
+}
+
+#[test]
+fn test_layout_option() {
+    assert_eq!("plain".parse::<LatexLayout>().unwrap(), LatexLayout::Article);
+    assert!("europecv".parse::<LatexLayout>().is_err());
+}
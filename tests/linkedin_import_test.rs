@@ -0,0 +1,137 @@
+use std::io::{Cursor, Write};
+use chrono::NaiveDate;
+use mongodb::bson::oid::ObjectId;
+use rust_server::importers::linkedin::{linkedin_date, map_linkedin, same_position, LINKEDIN_FILE_LIMIT, LINKEDIN_TOTAL_LIMIT};
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+}
+
+fn archive(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, contents) in files {
+        zip.start_file(*name, FileOptions::default()).unwrap();
+        zip.write_all(contents.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_linkedin_dates() {
+    assert_eq!(linkedin_date("2019"), "2019-01-01");
+    assert_eq!(linkedin_date("Mar 2021"), "2021-03-01");
+    assert_eq!(linkedin_date("Mar 15, 2021"), "2021-03-15");
+    assert_eq!(linkedin_date("2021-03-15"), "2021-03-15");
+    assert_eq!(linkedin_date("sometime"), "sometime");
+}
+
+#[test]
+fn test_maps_export_rows() {
+    let positions = "Company Name,Title,Description,Location,Started On,Finished On\n\
+        Acme,Senior Engineer,\"• Led the billing rewrite\n- Cut latency by 40%\n\n\",\"Berlin, Germany\",Jan 2020,\n\
+        Initech,Engineer,Maintained TPS reports,Austin,Mar 2016,Dec 2019\n\
+        Globex,Intern,,Springfield,last summer,2015\n";
+    let files = [
+        ("Profile.csv", "First Name,Last Name,Headline,Summary,Geo Location,Birth Date\nAda,Lovelace,Backend Engineer,,London,Dec 10\n"),
+        ("Positions.csv", positions),
+        ("Skills.csv", "Name\nRust\n\nGo\n"),
+        ("Education.csv", "School Name,Start Date,End Date,Notes,Degree Name,Activities\nUniversity of London,2010,2013,,BSc,Chess club\n"),
+        ("Certifications.csv", "Name,Url,Authority,Started On,Finished On,License Number\nCKA,,CNCF,May 2022,,LF-123\n"),
+        ("Connections.csv", "First Name,Last Name\n"),
+    ];
+
+    let import = map_linkedin(&archive(&files), today());
+    let mapped = &import.mapped;
+    let report = &mapped.report;
+
+    let profile = mapped.profile.as_ref().unwrap();
+    assert_eq!(profile.name, "Ada Lovelace");
+    assert_eq!(profile.label.as_deref(), Some("Backend Engineer"));
+    assert_eq!(profile.summary, None);
+
+    assert_eq!(mapped.experiences.len(), 2);
+    let (source, acme) = &mapped.experiences[0];
+    assert_eq!(source, "Positions.csv:2");
+    assert_eq!(acme.location, "Berlin, Germany");
+    assert_eq!((acme.start_date.as_str(), acme.end_date.as_str()), ("2020-01-01", "2024-06-01"));
+    let bullets: Vec<String> = acme.ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(bullets, vec!["Led the billing rewrite", "Cut latency by 40%"]);
+    assert!(report.warnings.iter().any(|w| w.starts_with("Positions.csv:2: no Finished On")));
+    assert_eq!(mapped.experiences[1].1.end_date, "2019-12-01");
+
+    assert_eq!(report.failures.len(), 1);
+    assert!(report.failures[0].source.starts_with("Positions.csv:"));
+    assert!(report.failures[0].messages.iter().any(|m| m.contains("Start date must be in YYYY-MM-DD format")));
+
+    let skills: Vec<&str> = mapped.skills.iter().map(|(_, s)| s.name.as_str()).collect();
+    assert_eq!(skills, vec!["Rust", "Go"]);
+
+    let (source, education) = &import.education[0];
+    assert_eq!(source, "Education.csv:2");
+    assert_eq!(education.institution, "University of London");
+    assert_eq!(education.study_type.as_deref(), Some("BSc"));
+    assert_eq!(education.end_date.as_deref(), Some("2013-01-01"));
+
+    let (_, certificate) = &import.certificates[0];
+    assert_eq!(certificate.name, "CKA");
+    assert_eq!(certificate.issuer.as_deref(), Some("CNCF"));
+    assert_eq!(certificate.date.as_deref(), Some("2022-05-01"));
+
+    for unmapped in ["Profile.csv.Birth Date", "Education.csv.Activities", "Certifications.csv.License Number", "Connections.csv"] {
+        assert!(report.unmapped.iter().any(|u| u == unmapped), "{} not reported in {:?}", unmapped, report.unmapped);
+    }
+    // Columns left empty in every row are not reported
+    assert!(!report.unmapped.iter().any(|u| u == "Education.csv.Notes"));
+}
+
+#[test]
+fn test_rejects_archives_without_export_files() {
+    let import = map_linkedin(b"not a zip", today());
+    assert!(import.mapped.report.failures[0].messages[0].starts_with("Not a ZIP archive"));
+
+    let import = map_linkedin(&archive(&[("notes.txt", "hello")]), today());
+    let report = &import.mapped.report;
+    assert!(report.failures[0].messages[0].contains("Positions.csv"));
+    assert_eq!(report.unmapped, vec!["notes.txt"]);
+}
+
+#[test]
+fn test_limits_uncompressed_size() {
+    let positions = "Company Name,Title,Description,Location,Started On,Finished On\nAcme,Engineer,,London,Mar 2021,\n";
+    let oversized = format!("Name\n{}", "a".repeat(LINKEDIN_FILE_LIMIT));
+    let import = map_linkedin(&archive(&[("Positions.csv", positions), ("Skills.csv", &oversized)]), today());
+    let report = &import.mapped.report;
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].source, "Skills.csv");
+    assert!(report.failures[0].messages[0].contains("larger than the limit"));
+    assert_eq!(import.mapped.experiences.len(), 1);
+
+    // Each file is under the limit, but not all of them together
+    let large = format!("Name\n{}", "a".repeat(LINKEDIN_TOTAL_LIMIT / 3 + 1));
+    let files = [("Skills.csv", large.as_str()), ("Education.csv", &large), ("Certifications.csv", &large)];
+    let import = map_linkedin(&archive(&files), today());
+    let report = &import.mapped.report;
+    assert_eq!(report.failures[0].source, "$");
+    assert!(report.failures[0].messages[0].starts_with("The export files are larger than the limit"));
+}
+
+#[test]
+fn test_positions_match_within_the_target_profile() {
+    let positions = "Company Name,Title,Description,Location,Started On,Finished On\nAcme,Engineer,,London,Mar 2021,\n";
+    let import = map_linkedin(&archive(&[("Positions.csv", positions)]), today());
+    let (ada, grace) = (Some(ObjectId::new()), Some(ObjectId::new()));
+    let mut imported = import.mapped.experiences[0].1.clone();
+    imported.profile_id = ada;
+
+    // Grace holds the same role; it is not Ada's
+    let mut stored = imported.clone();
+    stored.company = "ACME".to_string();
+    stored.profile_id = grace;
+    assert!(!same_position(&stored, &imported));
+    stored.profile_id = ada;
+    assert!(same_position(&stored, &imported));
+    stored.start_date = "2021-04-01".to_string();
+    assert!(!same_position(&stored, &imported));
+}
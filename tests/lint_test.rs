@@ -0,0 +1,69 @@
+mod common;
+
+use chrono::NaiveDate;
+use rust_server::models::ats::Severity;
+use rust_server::models::lint::Span;
+use rust_server::services::lint::Linter;
+
+fn rules(linter: &Linter, text: &str, past_role: bool) -> Vec<(String, Span)> {
+    linter.lint(text, past_role).into_iter().map(|finding| (finding.rule, finding.span)).collect()
+}
+
+#[test]
+fn test_lint_rules_and_spans() {
+    let linter = Linter::default();
+    assert_eq!(rules(&linter, "Led the migration of billing to Rust, cutting costs by 30%", true), vec![]);
+    assert_eq!(rules(&linter, "Responsible for the billing platform", false), vec![
+        ("weak-opening".to_string(), Span { start: 0, end: 15 }),
+        ("no-numbers".to_string(), Span { start: 0, end: 36 }),
+    ]);
+    assert_eq!(rules(&linter, "Our API was rebuilt by me in 2020", false), vec![
+        ("action-verb-missing".to_string(), Span { start: 0, end: 3 }),
+        ("first-person".to_string(), Span { start: 0, end: 3 }),
+        ("passive-voice".to_string(), Span { start: 8, end: 19 }),
+        ("first-person".to_string(), Span { start: 23, end: 25 }),
+    ]);
+    // `US` and `I/O` are not pronouns; spans count characters, not bytes
+    assert_eq!(rules(&linter, "Cut I/O costs for US customers by 40%", true), vec![]);
+    assert_eq!(rules(&linter, "Réduit — we cut costs by 40%", false)[1], ("first-person".to_string(), Span { start: 9, end: 11 }));
+}
+
+#[test]
+fn test_present_tense_only_for_past_roles() {
+    let linter = Linter::default();
+    let findings = linter.lint("Leads a team of 5 engineers", true);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].rule, "present-tense");
+    assert_eq!(findings[0].severity, Severity::Warning);
+    assert!(findings[0].message.contains("'Led' instead of 'Leads'"));
+    assert_eq!(rules(&linter, "Leads a team of 5 engineers", false), vec![]);
+    assert_eq!(linter.lint("Shipping 3 releases a week", true)[0].message, "This role has ended; write 'Shipped' instead of 'Shipping'");
+}
+
+#[test]
+fn test_rules_can_be_disabled() {
+    let linter = Linter::new(&["no-numbers".to_string(), "weak-opening".to_string()]).unwrap();
+    assert!(!linter.enabled("no-numbers"));
+    assert_eq!(rules(&linter, "Responsible for the billing platform", false), vec![]);
+
+    let error = Linter::new(&["shouting".to_string()]).unwrap_err();
+    assert!(error.to_string().contains("Unsupported lint rule 'shouting'. Supported lint rules: weak-opening, "));
+}
+
+#[test]
+fn test_lint_experience_reports_by_location() {
+    let linter = Linter::default();
+    let mut experience = common::experiences().remove(0);
+    experience.responsibilities.push(common::responsibility("Maintains the CI pipelines"));
+
+    let report = linter.lint_experience(&experience, NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+    assert_eq!(report.responsibilities.len(), 1);
+    let lint = &report.responsibilities[0];
+    assert_eq!(lint.location, "responsibilities[2]");
+    assert_eq!(lint.responsibility_id, experience.responsibilities[2].id);
+    assert_eq!(lint.findings.iter().map(|f| f.rule.as_str()).collect::<Vec<_>>(), vec!["present-tense", "no-numbers"]);
+
+    // Still ongoing on its end date's eve
+    let report = linter.lint_experience(&experience, NaiveDate::from_ymd_opt(2024, 2, 28).unwrap());
+    assert_eq!(report.responsibilities[0].findings.len(), 1);
+}
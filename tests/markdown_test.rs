@@ -0,0 +1,60 @@
+mod common;
+
+use rust_server::models::resume::{ResumeSection, SectionKind};
+use rust_server::renderers::{check_date_format, format_date};
+use rust_server::renderers::markdown::{escape, render_markdown, MarkdownOptions};
+
+#[test]
+fn test_render_markdown_document() {
+    let markdown = render_markdown(&common::resolved_resume(), &MarkdownOptions::default());
+
+    assert!(markdown.starts_with("# Ada Lovelace\n\n**Backend Engineer**\n\n"));
+    assert!(markdown.contains("[ada@example.com](mailto:ada@example.com) · +44 20 7946 0000"));
+    assert!(markdown.contains("## Summary\n\nEngineer focused on reliable distributed systems.\n\n## Experience\n\n"));
+    assert!(markdown.contains("### Senior Backend Engineer — Acme & Sons\n\n*London · Mar 2021 – Feb 2024*\n\n"));
+    assert!(markdown.contains(
+        "- Led the migration of billing to Rust, cutting costs by 30%\n- Introduced request caching, reduced p99 latency by 45%\n"
+    ));
+    assert!(markdown.contains("## Skills\n\n- **Languages:** Rust, Go\n- **Databases:** PostgreSQL\n- **Other:** Leadership\n"));
+    assert!(markdown.ends_with("Leadership\n"));
+}
+
+#[test]
+fn test_heading_level_and_date_format() {
+    let options = MarkdownOptions { heading_level: 2, date_format: "%Y-%m".to_string() };
+    let markdown = render_markdown(&common::resolved_resume(), &options);
+    assert!(markdown.starts_with("## Ada Lovelace"));
+    assert!(markdown.contains("\n### Experience\n"));
+    assert!(markdown.contains("\n#### Software Engineer — Globex\n\n*Remote · 2018-01 – 2021-02*"));
+
+    assert!(MarkdownOptions { heading_level: 5, ..MarkdownOptions::default() }.validate().is_err());
+    assert!(MarkdownOptions { date_format: "%Q".to_string(), ..MarkdownOptions::default() }.validate().is_err());
+}
+
+#[test]
+fn test_time_of_day_formats_are_rejected() {
+    for format in ["%H", "%M:%S", "%p", "%z", "%Y %s"] {
+        assert!(check_date_format(format).is_err(), "{} should be rejected", format);
+        assert_eq!(format_date("2020-01-01", format), "2020-01-01");
+    }
+    assert!(MarkdownOptions { date_format: "%H".to_string(), ..MarkdownOptions::default() }.validate().is_err());
+    assert_eq!(format_date("2020-01-01", "%d %B %Y"), "01 January 2020");
+}
+
+#[test]
+fn test_layout_and_escaping() {
+    let mut resume = common::resolved_resume();
+    resume.sections = vec![ResumeSection { kind: SectionKind::Skills, title: Some("Toolbox".to_string()) }];
+    let markdown = render_markdown(&resume, &MarkdownOptions::default());
+    assert!(markdown.contains("## Toolbox"));
+    assert!(!markdown.contains("## Experience"));
+
+    assert_eq!(escape("C# *and* [F#]_x_"), "C\\# \\*and\\* \\[F\\#\\]\\_x\\_");
+
+    // Link targets cannot end their link early
+    resume.profile.url = Some("https://example.com/a b>c".to_string());
+    resume.profile.links[0].url = "https://example.com/x) [y](https://evil.example".to_string();
+    let markdown = render_markdown(&resume, &MarkdownOptions::default());
+    assert!(markdown.contains("<https://example.com/a%20b%3Ec>"), "{}", markdown);
+    assert!(markdown.contains("[GitHub](https://example.com/x%29%20[y]%28https://evil.example)"), "{}", markdown);
+}
@@ -0,0 +1,44 @@
+use actix_web::{http::StatusCode, ResponseError};
+use rust_server::renderers::negotiation::{negotiate, MediaType};
+
+#[test]
+fn test_defaults_to_json() {
+    assert_eq!(negotiate(None, MediaType::ALL).unwrap(), MediaType::Json);
+    assert_eq!(negotiate(Some(""), MediaType::ALL).unwrap(), MediaType::Json);
+    assert_eq!(negotiate(Some("*/*"), MediaType::ALL).unwrap(), MediaType::Json);
+}
+
+#[test]
+fn test_picks_requested_type() {
+    assert_eq!(negotiate(Some("application/pdf"), MediaType::ALL).unwrap(), MediaType::Pdf);
+    assert_eq!(negotiate(Some("text/markdown; charset=utf-8"), MediaType::ALL).unwrap(), MediaType::Markdown);
+    assert_eq!(negotiate(Some("application/x-yaml"), MediaType::ALL).unwrap(), MediaType::Yaml);
+    assert_eq!(negotiate(Some("TEXT/PLAIN"), MediaType::ALL).unwrap(), MediaType::Text);
+}
+
+#[test]
+fn test_quality_values() {
+    let accept = "application/json;q=0.5, text/markdown;q=0.9, */*;q=0.1";
+    assert_eq!(negotiate(Some(accept), MediaType::ALL).unwrap(), MediaType::Markdown);
+    // A specific type keeps its own quality under a broader range
+    let accept = "text/*;q=0.8, text/markdown;q=0";
+    assert_eq!(negotiate(Some(accept), MediaType::ALL).unwrap(), MediaType::Text);
+    // Ties go to the server's order
+    let accept = "text/plain, application/yaml";
+    assert_eq!(negotiate(Some(accept), MediaType::ALL).unwrap(), MediaType::Yaml);
+    // Unreadable qualities are ignored
+    let accept = "application/pdf;q=high, text/plain";
+    assert_eq!(negotiate(Some(accept), MediaType::ALL).unwrap(), MediaType::Text);
+}
+
+#[test]
+fn test_not_acceptable_lists_supported_types() {
+    let error = negotiate(Some("image/png, application/json;q=0"), MediaType::ALL).unwrap_err();
+    assert_eq!(error.error_response().status(), StatusCode::NOT_ACCEPTABLE);
+    assert!(error.to_string().contains(
+        "Supported types: application/json, application/yaml, text/markdown, text/plain, application/pdf"
+    ));
+
+    let error = negotiate(Some("application/pdf"), &[MediaType::Json, MediaType::Yaml]).unwrap_err();
+    assert!(error.to_string().ends_with("Supported types: application/json, application/yaml"));
+}
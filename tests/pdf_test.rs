@@ -0,0 +1,106 @@
+mod common;
+
+use rust_server::models::experience::Experience;
+use rust_server::renderers::pdf::{layout_pdf, render_pdf, Element, PageSize, PdfOptions};
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle.as_bytes())
+}
+
+// Many experiences with long bullets, so the resume spans several pages.
+fn long_resume() -> rust_server::models::resume::ResolvedResume {
+    let mut resume = common::resolved_resume();
+    resume.experiences = (0..12)
+        .map(|i| Experience {
+            job_title: format!("Engineer {}", i),
+            responsibilities: (0..5)
+                .map(|j| common::responsibility(&format!(
+                    "Bullet {} of role {}: designed, built and operated services handling millions of requests a day across several regions with strict latency budgets",
+                    j, i
+                )))
+                .collect(),
+            ..common::experiences()[1].clone()
+        })
+        .collect();
+    resume
+}
+
+#[test]
+fn test_render_pdf_document() {
+    let document = render_pdf(&common::resolved_resume(), &PdfOptions::default()).unwrap();
+
+    assert!(document.starts_with(b"%PDF-"));
+    assert!(contains(&document, "/FontFile2"));
+    assert!(contains(&document, "/Subtype /Link") || contains(&document, "/Subtype/Link"));
+    assert!(contains(&document, "mailto:ada@example.com"));
+    assert!(contains(&document, "https://github.com/ada"));
+    assert!(contains(&document, "Ada Lovelace"));
+}
+
+#[test]
+fn test_layout_sections_and_links() {
+    let pages = layout_pdf(&common::resolved_resume(), &PdfOptions::default());
+    assert_eq!(pages.len(), 1);
+
+    let lines = pages[0].lines();
+    assert_eq!(lines[0], "Ada Lovelace");
+    assert!(lines.contains(&"EXPERIENCE"));
+    assert!(lines.contains(&"Senior Backend Engineer — Acme & Sons"));
+    assert!(lines.contains(&"London · Mar 2021 – Feb 2024"));
+    assert!(lines.contains(&"Introduced request caching, reduced p99 latency by 45%"));
+    assert!(lines.contains(&"Languages"));
+    assert!(lines.contains(&"Rust, Go"));
+
+    let links: Vec<&str> = pages[0].elements
+        .iter()
+        .filter_map(|element| match element {
+            Element::Link { uri, .. } => Some(uri.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(links, vec!["mailto:ada@example.com", "https://ada.example.com", "https://github.com/ada"]);
+}
+
+#[test]
+fn test_page_breaks_keep_header_with_bullets() {
+    let resume = long_resume();
+    for size in [PageSize::A4, PageSize::Letter] {
+        let options = PdfOptions { page_size: size, ..PdfOptions::default() };
+        let pages = layout_pdf(&resume, &options);
+        assert!(pages.len() > 1);
+
+        let (_, height) = size.dimensions();
+        for page in &pages {
+            let lines = page.lines();
+            for (index, line) in lines.iter().enumerate() {
+                if line.starts_with("Engineer ") {
+                    let bullets = lines[index..].iter().filter(|l| **l == "•").count();
+                    assert!(bullets >= 2, "header '{}' not followed by two bullets on its page", line);
+                }
+            }
+            for element in &page.elements {
+                if let Element::Text { y, .. } = element {
+                    assert!(*y > 0.0 && *y < height);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_long_bullets_wrap() {
+    let resume = long_resume();
+    let pages = layout_pdf(&resume, &PdfOptions::default());
+    let lines: Vec<&str> = pages.iter().flat_map(|p| p.lines()).collect();
+    let start = lines.iter().position(|l| l.starts_with("Bullet 0 of role 0")).unwrap();
+    let continuation = lines[start + 1];
+    assert!(continuation != "•" && !continuation.starts_with("Bullet"));
+    assert!(lines.iter().all(|line| line.chars().count() < 120));
+}
+
+#[test]
+fn test_options() {
+    assert_eq!("Letter".parse::<PageSize>().unwrap(), PageSize::Letter);
+    assert!("a5".parse::<PageSize>().is_err());
+    assert!(PdfOptions { date_format: "%Q".to_string(), ..PdfOptions::default() }.validate().is_err());
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use rust_server::renderers::qr::{render_qr_png, render_qr_svg, QrContent, QrOptions};
+use rust_server::renderers::vcard::render_vcard;
+
+#[test]
+fn test_svg_code() {
+    let svg = render_qr_svg("https://ada.example.com", &QrOptions::default()).unwrap();
+    // 25 modules (version 2) plus a border of 4 on each side, 8 units per module
+    assert!(svg.contains(r#"width="264" height="264" viewBox="0 0 33 33""#));
+    // Top-left finder pattern starts right after the quiet zone
+    assert!(svg.contains(r#"d="M4,4h1v1h-1z"#));
+}
+
+#[test]
+fn test_png_code() {
+    let options = QrOptions { scale: 2, border: 1 };
+    let png = render_qr_png(&render_vcard(&common::profile()), &options).unwrap();
+
+    let decoder = png::Decoder::new(png.as_slice());
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!(info.color_type, png::ColorType::Grayscale);
+    assert_eq!(info.width, info.height);
+    assert_eq!((info.width / 2 - 2 - 21) % 4, 0, "width must be a QR code size");
+
+    let pixel = |x: u32, y: u32| pixels[(y * info.width + x) as usize];
+    assert_eq!((pixel(0, 0), pixel(1, 1)), (0xFF, 0xFF));
+    assert_eq!((pixel(2, 2), pixel(3, 3)), (0x00, 0x00));
+}
+
+#[test]
+fn test_options_and_limits() {
+    assert!(QrOptions { scale: 0, border: 4 }.validate().is_err());
+    assert!(QrOptions { scale: 8, border: 17 }.validate().is_err());
+    assert!(QrOptions::default().validate().is_ok());
+    assert_eq!("URL".parse::<QrContent>().unwrap(), QrContent::Url);
+    assert!("text".parse::<QrContent>().is_err());
+    assert!(render_qr_svg(&"x".repeat(3000), &QrOptions::default()).is_err());
+}
@@ -0,0 +1,90 @@
+use mongodb::bson::oid::ObjectId;
+use rust_server::models::experience::Experience;
+use rust_server::models::profile::Profile;
+use rust_server::models::responsibility::Responsibility;
+use rust_server::models::resume::{ResolvedResume, ResumeSection};
+use rust_server::models::snapshot::{ChangeKind, WordChange, WordOp};
+use rust_server::services::resume_diff::{diff_resumes, diff_words};
+use rust_server::services::snapshot_service::content_hash;
+
+fn bullet(id: ObjectId, name: &str) -> Responsibility {
+    Responsibility { id: Some(id), name: name.to_string(), next: None, star: None }
+}
+
+fn experience(id: ObjectId, company: &str, responsibilities: Vec<Responsibility>) -> Experience {
+    Experience {
+        id: Some(id),
+        profile_id: None,
+        job_title: "Engineer".to_string(),
+        company: company.to_string(),
+        location: "Berlin".to_string(),
+        start_date: "2020-01-01".to_string(),
+        end_date: "2022-01-01".to_string(),
+        responsibilities,
+        environment: vec![],
+    }
+}
+
+fn document(experiences: Vec<Experience>) -> ResolvedResume {
+    ResolvedResume {
+        resume_id: None,
+        name: "Backend".to_string(),
+        profile: Profile { name: "Ada".to_string(), ..Profile::default() },
+        summary: None,
+        sections: ResumeSection::default_layout(),
+        experiences,
+        skills: vec![],
+    }
+}
+
+#[test]
+fn test_diff_words_merges_runs() {
+    let words = diff_words("Built the billing API", "Built the new billing API");
+    assert_eq!(words, vec![
+        WordChange { op: WordOp::Equal, text: "Built the ".to_string() },
+        WordChange { op: WordOp::Insert, text: "new ".to_string() },
+        WordChange { op: WordOp::Equal, text: "billing API".to_string() },
+    ]);
+}
+
+#[test]
+fn test_identical_documents() {
+    let id = ObjectId::new();
+    let doc = document(vec![experience(id, "Acme", vec![bullet(ObjectId::new(), "Shipped")])]);
+    let diff = diff_resumes(&doc, &doc.clone());
+    assert!(diff.identical);
+    assert_eq!(content_hash(&doc).unwrap(), content_hash(&doc.clone()).unwrap());
+}
+
+#[test]
+fn test_diff_reports_added_removed_and_changed_entries() {
+    let (kept, dropped, added) = (ObjectId::new(), ObjectId::new(), ObjectId::new());
+    let (first, second) = (ObjectId::new(), ObjectId::new());
+    let before = document(vec![
+        experience(kept, "Acme", vec![bullet(first, "Cut costs by 10%"), bullet(second, "Mentored interns")]),
+        experience(dropped, "Initech", vec![]),
+    ]);
+    let mut after = document(vec![
+        experience(kept, "Acme", vec![bullet(first, "Cut costs by 25%")]),
+        experience(added, "Globex", vec![]),
+    ]);
+    after.experiences[0].location = "Remote".to_string();
+
+    let diff = diff_resumes(&before, &after);
+    assert!(!diff.identical);
+    assert_ne!(content_hash(&before).unwrap(), content_hash(&after).unwrap());
+
+    let changes: Vec<(ChangeKind, &str)> = diff.experiences.iter().map(|e| (e.change, e.company.as_str())).collect();
+    assert_eq!(changes, vec![
+        (ChangeKind::Changed, "Acme"),
+        (ChangeKind::Removed, "Initech"),
+        (ChangeKind::Added, "Globex"),
+    ]);
+
+    let acme = &diff.experiences[0];
+    assert_eq!(acme.fields[0].field, "location");
+    assert_eq!(acme.responsibilities[0].change, ChangeKind::Changed);
+    assert!(acme.responsibilities[0].words.contains(&WordChange { op: WordOp::Insert, text: "25%".to_string() }));
+    assert_eq!(acme.responsibilities[1].change, ChangeKind::Removed);
+    assert_eq!(acme.responsibilities[1].before.as_deref(), Some("Mentored interns"));
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use mongodb::bson::oid::ObjectId;
+use rust_server::models::category::Category;
+use rust_server::models::experience::Experience;
+use rust_server::models::resume::Resume;
+use rust_server::models::resume_file::{FileFormat, ResumeFile, SyncAction, SyncTarget};
+use rust_server::services::resume_sync::{assign_ids, plan_experiences};
+
+fn file() -> ResumeFile {
+    let profile = common::profile();
+    let experiences = common::experiences();
+    ResumeFile {
+        resumes: vec![Resume {
+            id: Some(ObjectId::new()),
+            name: "Backend".to_string(),
+            profile_id: profile.id.unwrap(),
+            parent_id: None,
+            summary: None,
+            sections: vec![],
+            experiences: vec![],
+            skills: vec![experiences[0].environment[0].id.unwrap()],
+            excluded_experiences: vec![],
+        }],
+        profiles: vec![profile],
+        categories: vec![Category { id: Some(ObjectId::new()), name: "Languages".to_string() }],
+        skills: vec![common::skill("Rust", Some("Languages"))],
+        experiences,
+        ..ResumeFile::default()
+    }
+}
+
+fn round_trip(format: FileFormat) {
+    let file = file();
+    let text = file.render(format).unwrap();
+    let parsed = ResumeFile::parse(&text, format).unwrap();
+
+    assert_eq!(serde_json::to_value(&parsed.profiles[0]).unwrap(), serde_json::to_value(&file.profiles[0]).unwrap());
+    assert_eq!(parsed.categories[0].name, "Languages");
+    assert_eq!(parsed.skills[0].id, file.skills[0].id);
+    assert_eq!(parsed.resumes[0].skills, file.resumes[0].skills);
+    let (before, after) = (&file.experiences[0], &parsed.experiences[0]);
+    assert_eq!(after.id, before.id);
+    assert_eq!(after.start_date, before.start_date);
+    assert_eq!(after.environment[0].id, before.environment[0].id);
+    let names = |e: &Experience| e.ordered_responsibilities().into_iter().map(|r| (r.id, r.star)).collect::<Vec<_>>();
+    assert_eq!(names(after), names(before));
+    // Nothing changes when a file is exported and planned again
+    let again = ResumeFile::parse(&parsed.render(format).unwrap(), format).unwrap();
+    assert!(plan_experiences(&again.experiences, &parsed.experiences).changes.is_empty());
+}
+
+#[test]
+fn test_yaml_round_trip() {
+    let text = file().render(FileFormat::Yaml).unwrap();
+    // Ids are written as plain strings and links are implied by order
+    assert!(!text.contains("$oid"));
+    assert!(!text.contains("next:"));
+    round_trip(FileFormat::Yaml);
+}
+
+#[test]
+fn test_toml_round_trip() {
+    round_trip(FileFormat::Toml);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = ResumeFile::parse("version: 1\nexperiences: [", FileFormat::Yaml).unwrap_err().to_string();
+    assert!(error.contains("line 3 column 1"), "{}", error);
+    let error = ResumeFile::parse("version: 2\nexperiences: []\n", FileFormat::Yaml).unwrap_err().to_string();
+    assert!(error.contains("unsupported version 2"), "{}", error);
+    // A file without `experiences` would delete every stored experience
+    let error = ResumeFile::parse("version: 1\n", FileFormat::Yaml).unwrap_err().to_string();
+    assert!(error.contains("missing field `experiences`"), "{}", error);
+    let error = ResumeFile::parse("version: 1\nexperiences: []\nexperience: []\n", FileFormat::Yaml).unwrap_err().to_string();
+    assert!(error.contains("unknown field `experience`"), "{}", error);
+    let error = ResumeFile::parse("version = 1\nexperiences = []\nexperince = []\n", FileFormat::Toml).unwrap_err().to_string();
+    assert!(error.contains("unknown field `experince`"), "{}", error);
+    assert!(ResumeFile::parse("version = 1\nexperiences = []\n", FileFormat::Toml).unwrap().experiences.is_empty());
+    assert!("json".parse::<FileFormat>().is_err());
+
+    // Responsibilities without ids get one and are linked in order
+    let text = "version = 1\n[[experiences]]\njob_title = \"Engineer\"\ncompany = \"Initech\"\nlocation = \"Austin\"\n\
+        start_date = \"2016-03-01\"\nend_date = \"2019-12-31\"\n\
+        [[experiences.responsibilities]]\nname = \"First\"\n[[experiences.responsibilities]]\nname = \"Second\"\n";
+    let mut file = ResumeFile::parse(text, FileFormat::Toml).unwrap();
+    assert!(file.experiences[0].responsibilities[0].id.is_none());
+    assign_ids(&mut file.experiences, &[]);
+    assert!(file.experiences[0].id.is_some());
+    let responsibilities = &file.experiences[0].responsibilities;
+    assert_eq!(responsibilities[0].next, responsibilities[1].id);
+    assert!(responsibilities[1].id.is_some() && responsibilities[1].next.is_none());
+}
+
+#[test]
+fn test_plan_experience_and_responsibility_changes() {
+    let stored = common::experiences();
+    let text = ResumeFile { experiences: stored.clone(), ..ResumeFile::default() }.render(FileFormat::Yaml).unwrap();
+    let mut file = ResumeFile::parse(&text, FileFormat::Yaml).unwrap().experiences;
+
+    // Changed title, first bullet reworded, second removed, one added
+    file[0].job_title = "Staff Engineer".to_string();
+    file[0].responsibilities[0].name = "Led the billing migration".to_string();
+    file[0].responsibilities.truncate(1);
+    file[0].responsibilities.push(common::responsibility("Mentored four engineers"));
+    file[0].responsibilities[0].next = file[0].responsibilities[1].id;
+    // The second experience is gone and a new one appears
+    file.remove(1);
+    let mut created = common::experiences().remove(1);
+    created.id = None;
+    file.push(created);
+
+    let plan = plan_experiences(&file, &stored);
+    let summary: Vec<(SyncAction, SyncTarget, &str)> = plan.changes.iter().map(|c| (c.action, c.target, c.name.as_str())).collect();
+    assert_eq!(summary, vec![
+        (SyncAction::Update, SyncTarget::Experience, "Staff Engineer at Acme & Sons"),
+        (SyncAction::Update, SyncTarget::Responsibility, "Led the billing migration"),
+        (SyncAction::Create, SyncTarget::Responsibility, "Mentored four engineers"),
+        (SyncAction::Delete, SyncTarget::Responsibility, "Checkout performance"),
+        (SyncAction::Create, SyncTarget::Experience, "Software Engineer at Globex"),
+        (SyncAction::Delete, SyncTarget::Experience, "Software Engineer at Globex"),
+    ]);
+    assert_eq!(plan.changes[0].fields, vec!["job_title"]);
+    assert_eq!(plan.changes[1].fields, vec!["name", "order"]);
+    assert!(!plan.applied);
+}
+
+#[test]
+fn test_files_without_ids_sync_stably() {
+    let mut stored = common::experiences();
+    assign_ids(&mut stored, &[]);
+    // Written by hand: the bullets have no ids
+    let mut written = stored.clone();
+    written[0].responsibilities.iter_mut().for_each(|r| r.id = None);
+    let text = ResumeFile { experiences: written, ..ResumeFile::default() }.render(FileFormat::Yaml).unwrap();
+    let mut file = ResumeFile::parse(&text, FileFormat::Yaml).unwrap();
+    assert!(file.experiences[0].responsibilities.iter().all(|r| r.id.is_none()));
+
+    // Each bullet takes the id of the stored one with its name
+    assign_ids(&mut file.experiences, &stored);
+    assert_eq!(file.experiences[0].responsibilities[0].id, stored[0].responsibilities[0].id);
+    assert!(plan_experiences(&file.experiences, &stored).changes.is_empty());
+
+    // A stored experience without an id cannot be deleted, so it is not planned
+    let mut unaddressable = common::experiences().remove(1);
+    unaddressable.id = None;
+    stored.push(unaddressable);
+    assert!(plan_experiences(&file.experiences, &stored).changes.is_empty());
+}
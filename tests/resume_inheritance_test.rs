@@ -0,0 +1,107 @@
+use mongodb::bson::oid::ObjectId;
+use rust_server::models::resume::{
+    ExperienceOverrides, Resume, ResumeExperience, ResumeSection, ResponsibilityOverride, SectionKind,
+};
+use rust_server::services::resume_inheritance::{flatten_chain, merge_variant, overridden_fields};
+
+fn entry(experience_id: ObjectId) -> ResumeExperience {
+    ResumeExperience {
+        experience_id,
+        responsibilities: None,
+        overrides: ExperienceOverrides::default(),
+        responsibility_overrides: vec![],
+    }
+}
+
+fn resume(name: &str, parent_id: Option<ObjectId>, experiences: Vec<ResumeExperience>) -> Resume {
+    Resume {
+        id: Some(ObjectId::new()),
+        name: name.to_string(),
+        profile_id: ObjectId::new(),
+        parent_id,
+        summary: None,
+        sections: vec![],
+        experiences,
+        skills: vec![],
+        excluded_experiences: vec![],
+    }
+}
+
+#[test]
+fn test_variant_inherits_base_changes_unless_overridden() {
+    let (acme, globex) = (ObjectId::new(), ObjectId::new());
+    let mut base_acme = entry(acme);
+    base_acme.overrides.job_title = Some("Engineer".to_string());
+    base_acme.overrides.location = Some("Berlin".to_string());
+    let mut base = resume("base", None, vec![base_acme, entry(globex)]);
+    base.summary = Some("Base summary".to_string());
+    base.skills = vec![ObjectId::new()];
+
+    let mut own_acme = entry(acme);
+    own_acme.overrides.job_title = Some("Platform Engineer".to_string());
+    let variant = resume("platform", base.id, vec![own_acme]);
+
+    let effective = merge_variant(&base, &variant);
+    assert_eq!(effective.name, "platform");
+    assert_eq!(effective.summary.as_deref(), Some("Base summary"));
+    assert_eq!(effective.skills, base.skills);
+    assert_eq!(effective.experiences.len(), 2);
+    assert_eq!(effective.experiences[0].overrides.job_title.as_deref(), Some("Platform Engineer"));
+    assert_eq!(effective.experiences[0].overrides.location.as_deref(), Some("Berlin"));
+
+    // A later change to the base flows into the variant
+    base.summary = Some("Updated base summary".to_string());
+    assert_eq!(merge_variant(&base, &variant).summary.as_deref(), Some("Updated base summary"));
+}
+
+#[test]
+fn test_variant_can_exclude_and_add_entries() {
+    let (acme, globex, initech) = (ObjectId::new(), ObjectId::new(), ObjectId::new());
+    let base = resume("base", None, vec![entry(acme), entry(globex)]);
+    let mut variant = resume("management", base.id, vec![entry(initech)]);
+    variant.excluded_experiences = vec![globex];
+    variant.sections = vec![ResumeSection::new(SectionKind::Experience)];
+
+    let effective = merge_variant(&base, &variant);
+    let ids: Vec<ObjectId> = effective.experiences.iter().map(|e| e.experience_id).collect();
+    assert_eq!(ids, vec![acme, initech]);
+    assert_eq!(effective.sections.len(), 1);
+}
+
+#[test]
+fn test_flatten_multi_level_chain() {
+    let acme = ObjectId::new();
+    let base = resume("base", None, vec![entry(acme)]);
+    let mut middle = resume("backend", base.id, vec![]);
+    middle.summary = Some("Backend".to_string());
+    let mut leaf_entry = entry(acme);
+    leaf_entry.responsibilities = Some(vec![]);
+    let leaf = resume("backend-short", middle.id, vec![leaf_entry]);
+
+    let effective = flatten_chain(&[base, middle, leaf]).unwrap();
+    assert_eq!(effective.name, "backend-short");
+    assert_eq!(effective.summary.as_deref(), Some("Backend"));
+    assert_eq!(effective.experiences[0].responsibilities, Some(vec![]));
+}
+
+#[test]
+fn test_overridden_fields_lists_paths() {
+    let (acme, globex, bullet) = (ObjectId::new(), ObjectId::new(), ObjectId::new());
+    let base = resume("base", None, vec![entry(acme), entry(globex)]);
+    let mut own_acme = entry(acme);
+    own_acme.overrides.company = Some("ACME Corp".to_string());
+    own_acme.responsibility_overrides = vec![ResponsibilityOverride {
+        responsibility_id: bullet,
+        name: "Reworded".to_string(),
+    }];
+    let mut variant = resume("variant", base.id, vec![own_acme]);
+    variant.summary = Some("Variant".to_string());
+    variant.excluded_experiences = vec![globex];
+
+    assert_eq!(overridden_fields(&base, &variant), vec![
+        "summary".to_string(),
+        format!("excluded_experiences[{}]", globex),
+        format!("experiences[{}].company", acme),
+        format!("experiences[{}].responsibility_overrides[{}]", acme, bullet),
+    ]);
+}
@@ -0,0 +1,162 @@
+use mongodb::bson::oid::ObjectId;
+use rust_server::errors::AppError;
+use rust_server::models::experience::Experience;
+use rust_server::models::profile::Profile;
+use rust_server::models::responsibility::Responsibility;
+use rust_server::models::resume::{
+    ExperienceOverrides, Resume, ResumeExperience, ResumeSection, ResponsibilityOverride, SectionKind,
+};
+use rust_server::models::skill::Skill;
+use rust_server::services::resume_resolver::{check_selection, resolve_resume};
+
+fn responsibility(id: ObjectId, name: &str, next: Option<ObjectId>) -> Responsibility {
+    Responsibility { id: Some(id), name: name.to_string(), next, star: None }
+}
+
+fn skill(name: &str) -> Skill {
+    Skill {
+        id: Some(ObjectId::new()),
+        name: name.to_string(),
+        description: name.to_string(),
+        categories: vec![],
+        next: None,
+    }
+}
+
+fn profile() -> Profile {
+    Profile {
+        name: "Ada Lovelace".to_string(),
+        summary: Some("Engineer".to_string()),
+        ..Profile::default()
+    }
+}
+
+fn profile_id() -> ObjectId {
+    ObjectId::parse_str("65f1c0ffee00000000000001").unwrap()
+}
+
+fn resume(experiences: Vec<ResumeExperience>, skills: Vec<ObjectId>) -> Resume {
+    Resume {
+        id: None,
+        name: "Backend".to_string(),
+        profile_id: profile_id(),
+        parent_id: None,
+        summary: None,
+        sections: vec![],
+        experiences,
+        skills,
+        excluded_experiences: vec![],
+    }
+}
+
+fn selection(experience_id: ObjectId) -> ResumeExperience {
+    ResumeExperience {
+        experience_id,
+        responsibilities: None,
+        overrides: ExperienceOverrides::default(),
+        responsibility_overrides: vec![],
+    }
+}
+
+// Stored as [third, first, second] but linked first -> second -> third
+fn experience() -> (Experience, [ObjectId; 3]) {
+    let ids = [ObjectId::new(), ObjectId::new(), ObjectId::new()];
+    let experience = Experience {
+        id: Some(ObjectId::new()),
+        profile_id: Some(profile_id()),
+        job_title: "Engineer".to_string(),
+        company: "Acme".to_string(),
+        location: "Berlin".to_string(),
+        start_date: "2020-01-01".to_string(),
+        end_date: "2022-01-01".to_string(),
+        responsibilities: vec![
+            responsibility(ids[2], "third", None),
+            responsibility(ids[0], "first", Some(ids[1])),
+            responsibility(ids[1], "second", Some(ids[2])),
+        ],
+        environment: vec![],
+    };
+    (experience, ids)
+}
+
+fn names(experience: &Experience) -> Vec<&str> {
+    experience.responsibilities.iter().map(|r| r.name.as_str()).collect()
+}
+
+#[test]
+fn test_resolve_follows_linked_order_and_defaults() {
+    let (source, _) = experience();
+    let rust = skill("Rust");
+    let resume = resume(vec![selection(source.id.unwrap())], vec![rust.id.unwrap()]);
+
+    let resolved = resolve_resume(&resume, profile(), &[source], &[rust]).unwrap();
+    assert_eq!(names(&resolved.experiences[0]), vec!["first", "second", "third"]);
+    assert_eq!(resolved.sections, ResumeSection::default_layout());
+    assert_eq!(resolved.summary.as_deref(), Some("Engineer"));
+    assert_eq!(resolved.skills[0].name, "Rust");
+}
+
+#[test]
+fn test_resolve_applies_selection_and_overrides() {
+    let (source, ids) = experience();
+    let mut chosen = selection(source.id.unwrap());
+    chosen.responsibilities = Some(vec![ids[2], ids[0]]);
+    chosen.overrides.job_title = Some("Staff Engineer".to_string());
+    chosen.responsibility_overrides = vec![ResponsibilityOverride {
+        responsibility_id: ids[0],
+        name: "first, reworded".to_string(),
+    }];
+    let mut resume = resume(vec![chosen], vec![]);
+    resume.sections = vec![ResumeSection::new(SectionKind::Experience)];
+
+    let resolved = resolve_resume(&resume, profile(), std::slice::from_ref(&source), &[]).unwrap();
+    let experience = &resolved.experiences[0];
+    assert_eq!(experience.job_title, "Staff Engineer");
+    assert_eq!(names(experience), vec!["third", "first, reworded"]);
+    // Links are rewritten so the resolved order survives re-ordering
+    let relinked: Vec<String> = experience.ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(relinked, vec!["third", "first, reworded"]);
+    assert_eq!(resolved.sections.len(), 1);
+
+    // The source record is left untouched
+    assert_eq!(source.job_title, "Engineer");
+}
+
+#[test]
+fn test_resolve_reports_missing_references() {
+    let (source, _) = experience();
+    let missing = resume(vec![selection(ObjectId::new())], vec![]);
+    let result = resolve_resume(&missing, profile(), &[source], &[]);
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[test]
+fn test_selection_must_match_profile_and_responsibilities() {
+    let (source, ids) = experience();
+    let mut chosen = selection(source.id.unwrap());
+    chosen.responsibilities = Some(vec![ids[0]]);
+    let mut resume = resume(vec![chosen], vec![]);
+    assert!(check_selection(&resume, std::slice::from_ref(&source)).is_ok());
+
+    // An override of a responsibility left out, or of another experience's
+    resume.experiences[0].responsibility_overrides = vec![ResponsibilityOverride {
+        responsibility_id: ids[1],
+        name: "second, reworded".to_string(),
+    }];
+    let error = check_selection(&resume, std::slice::from_ref(&source)).unwrap_err();
+    assert!(matches!(&error, AppError::ValidationError(m) if m.contains("does not match a selected responsibility")), "{}", error);
+    resume.experiences[0].responsibilities = None;
+    assert!(check_selection(&resume, std::slice::from_ref(&source)).is_ok());
+    resume.experiences[0].responsibility_overrides[0].responsibility_id = ObjectId::new();
+    assert!(matches!(check_selection(&resume, std::slice::from_ref(&source)), Err(AppError::ValidationError(_))));
+    resume.experiences[0].responsibility_overrides.clear();
+
+    // Experiences of another profile, or of none, are rejected on save and on resolve
+    for owner in [Some(ObjectId::new()), None] {
+        let foreign = Experience { profile_id: owner, ..source.clone() };
+        let error = check_selection(&resume, std::slice::from_ref(&foreign)).unwrap_err();
+        assert!(error.to_string().contains(&format!("does not belong to profile {}", profile_id())), "{}", error);
+        assert!(matches!(resolve_resume(&resume, profile(), &[foreign], &[]), Err(AppError::ValidationError(_))));
+    }
+    assert!(matches!(check_selection(&resume, &[]), Err(AppError::ValidationError(_))));
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use rust_server::renderers::site::{render_site, Portfolio, SiteFile, SiteOptions};
+use rust_server::services::site_service::{read_assets, write_site};
+
+fn portfolio() -> Portfolio {
+    Portfolio {
+        profile: common::profile(),
+        experiences: common::experiences(),
+        resumes: vec![common::resolved_resume()],
+        assets: vec![SiteFile { path: "img/avatar.png".to_string(), content: vec![137, 80, 78, 71] }],
+    }
+}
+
+fn options() -> SiteOptions {
+    SiteOptions { base_url: "https://ada.dev/".to_string(), ..SiteOptions::default() }
+}
+
+fn page<'a>(files: &'a [SiteFile], path: &str) -> &'a str {
+    let file = files.iter().find(|f| f.path == path).unwrap_or_else(|| panic!("{} is missing", path));
+    std::str::from_utf8(&file.content).unwrap()
+}
+
+#[test]
+fn test_site_layout() {
+    let files = render_site(&portfolio(), &options()).unwrap();
+    let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec![
+        "index.html",
+        "projects/acme-sons-senior-backend-engineer.html",
+        "projects/globex-software-engineer.html",
+        "resumes/backend.html",
+        "sitemap.xml",
+        "robots.txt",
+        "assets/site.css",
+        "assets/img/avatar.png",
+    ]);
+
+    let index = page(&files, "index.html");
+    assert!(index.contains("<h1 class=\"p-name\">Ada Lovelace</h1>"));
+    assert!(index.contains("<a href=\"projects/acme-sons-senior-backend-engineer.html\">"));
+    assert!(index.contains("<a href=\"resumes/backend.html\">Backend</a>"));
+    assert!(index.contains("<link rel=\"canonical\" href=\"https://ada.dev/\">"));
+    assert!(index.contains("\"@type\":\"Person\""));
+
+    let project = page(&files, "projects/acme-sons-senior-backend-engineer.html");
+    assert!(project.contains("<link rel=\"stylesheet\" href=\"../assets/site.css\">"));
+    assert!(project.contains("<span class=\"company p-org h-card\">Acme &amp; Sons</span>"));
+    assert!(project.contains("\"roleName\":\"Senior Backend Engineer\""));
+    assert!(!project.contains("Globex"));
+
+    assert!(page(&files, "resumes/backend.html").starts_with("<!DOCTYPE html>"));
+    let sitemap = page(&files, "sitemap.xml");
+    assert!(sitemap.contains("<loc>https://ada.dev/</loc>"));
+    assert!(sitemap.contains("<loc>https://ada.dev/resumes/backend.html</loc>"));
+    assert!(page(&files, "robots.txt").contains("Sitemap: https://ada.dev/sitemap.xml"));
+}
+
+#[test]
+fn test_options_are_validated() {
+    let error = render_site(&portfolio(), &SiteOptions::default()).unwrap_err().to_string();
+    assert!(error.contains("must be an absolute http or https URL"), "{}", error);
+    let options = SiteOptions { date_format: "%Q".to_string(), ..options() };
+    assert!(render_site(&portfolio(), &options).is_err());
+}
+
+#[test]
+fn test_write_and_read_back() {
+    let dir = std::env::temp_dir().join(format!("portfolio-test-{}", std::process::id()));
+    let files = render_site(&portfolio(), &options()).unwrap();
+    write_site(&files, &dir).unwrap();
+    assert!(dir.join("projects").join("globex-software-engineer.html").is_file());
+
+    let assets = read_assets(&dir.join("assets")).unwrap();
+    let names: Vec<&str> = assets.iter().map(|a| a.path.as_str()).collect();
+    assert_eq!(names, vec!["img/avatar.png", "site.css"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,77 @@
+use rust_server::models::experience::Experience;
+use rust_server::models::responsibility::{Responsibility, Star};
+use rust_server::models::skill::Skill;
+use rust_server::models::story::Story;
+use validator::Validate;
+
+fn star() -> Star {
+    Star {
+        situation: Some("Checkout latency spiked during peak sales.".to_string()),
+        task: Some("Bring p99 latency under 200ms.".to_string()),
+        action: Some("Rewrote the pricing service in Rust.".to_string()),
+        result: Some("Cut p99 latency by 60%.".to_string()),
+    }
+}
+
+fn responsibility(name: &str, star: Option<Star>) -> Responsibility {
+    Responsibility { id: None, name: name.to_string(), next: None, star }
+}
+
+fn experience(company: &str, skill: &str, responsibilities: Vec<Responsibility>) -> Experience {
+    Experience {
+        id: None,
+        profile_id: None,
+        job_title: "Backend Engineer".to_string(),
+        company: company.to_string(),
+        location: "Remote".to_string(),
+        start_date: "2021-01-01".to_string(),
+        end_date: "2023-06-30".to_string(),
+        responsibilities,
+        environment: vec![Skill {
+            id: None,
+            name: skill.to_string(),
+            description: skill.to_string(),
+            categories: vec![],
+            next: None,
+        }],
+    }
+}
+
+#[test]
+fn test_bullet_condenses_star() {
+    let with_star = responsibility("Pricing service", Some(star()));
+    assert_eq!(with_star.bullet(), "Rewrote the pricing service in Rust, cut p99 latency by 60%");
+
+    let plain = responsibility("Maintained CI pipelines", None);
+    assert_eq!(plain.bullet(), "Maintained CI pipelines");
+}
+
+#[test]
+fn test_narrative_skips_missing_fields() {
+    let partial = Star { action: Some("Led the migration.".to_string()), ..Star::default() };
+    assert_eq!(partial.narrative(), "Action: Led the migration.");
+    assert_eq!(star().narrative().split("\n\n").count(), 4);
+}
+
+#[test]
+fn test_empty_star_field_fails_validation() {
+    let invalid = responsibility("Pricing", Some(Star { task: Some(String::new()), ..star() }));
+    assert!(invalid.validate().is_err());
+    assert!(responsibility("Pricing", Some(star())).validate().is_ok());
+}
+
+#[test]
+fn test_collect_stories_filters_by_skill() {
+    let experiences = vec![
+        experience("Acme", "Rust", vec![responsibility("Pricing", Some(star())), responsibility("On-call", None)]),
+        experience("Globex", "Go", vec![responsibility("Billing", Some(star()))]),
+    ];
+
+    let all = Story::collect(&experiences, None);
+    assert_eq!(all.len(), 2);
+
+    let rust = Story::collect(&experiences, Some("rust"));
+    assert_eq!(rust.len(), 1);
+    assert_eq!(rust[0].company, "Acme");
+    assert_eq!(rust[0].title, "Pricing");
+}
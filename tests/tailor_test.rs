@@ -0,0 +1,103 @@
+mod common;
+
+use mongodb::bson::oid::ObjectId;
+use rust_server::services::relevance::{extract_keywords, tokenize, Bm25};
+use rust_server::services::tailor_service::{draft_resume, rank};
+
+const JOB: &str = "We are hiring a Backend Engineer to build services in Rust and Golang on K8s. \
+    You will own latency and caching work, run Postgres at scale and mentor engineers. \
+    Experience with Machine Learning is a plus.";
+
+#[test]
+fn test_tokenize_uses_dictionary_and_stems() {
+    let keys: Vec<String> = tokenize("Scaled Node.js and C++ services on k8s, using machine learning; scaling CI/CD.")
+        .into_iter()
+        .map(|token| token.key)
+        .collect();
+    assert_eq!(keys, vec!["scal", "node.js", "c++", "servic", "kubernetes", "machine learning", "scal", "ci/cd"]);
+
+    let keywords = extract_keywords(JOB);
+    let golang = keywords.iter().find(|k| k.term == "go").unwrap();
+    assert!(golang.tech);
+    assert!(keywords.iter().any(|k| k.term == "postgresql" && k.tech));
+    assert!(keywords.iter().any(|k| k.term == "latency" && !k.tech));
+    assert!(!keywords.iter().any(|k| k.term == "the" || k.term == "you"));
+    // `engineer` and `engineers` are one keyword, shown as first written
+    let engineer = keywords.iter().find(|k| k.term == "engineer").unwrap();
+    assert_eq!(engineer.count, 2);
+}
+
+#[test]
+fn test_bm25_prefers_rarer_and_tech_terms() {
+    let index = Bm25::new(&["rust rust services", "python services", "team services"]);
+    let keywords = extract_keywords("rust services");
+    let rust = index.score(0, &keywords);
+    assert_eq!(rust.matched, vec!["rust", "services"]);
+    assert!(rust.score > index.score(1, &keywords).score);
+    assert_eq!(index.score(1, &keywords).score, index.score(2, &keywords).score);
+    assert_eq!(index.score(1, &extract_keywords("haskell")).score, 0.0);
+}
+
+#[test]
+fn test_rank_explains_matches() {
+    let experiences = common::experiences();
+    let skills = common::resolved_resume().skills;
+    let report = rank(JOB, &experiences, &skills);
+
+    assert_eq!(report.experiences[0].company, "Acme & Sons");
+    assert!(report.experiences[0].matched.contains(&"rust".to_string()));
+    assert!(report.experiences[0].score > report.experiences[1].score);
+
+    // Dictionary terms weigh more than ordinary words
+    let top = &report.responsibilities[0];
+    assert_eq!(top.text, "Led the migration of billing to Rust, cutting costs by 30%");
+    assert_eq!(top.matched, vec!["rust"]);
+    let caching = report.responsibilities.iter().find(|r| r.text.contains("caching")).unwrap();
+    assert_eq!(caching.matched, vec!["latency", "caching"]);
+    assert_eq!(report.responsibilities.len(), 3);
+
+    let names: Vec<&str> = report.skills.iter().filter(|s| s.score > 0.0).map(|s| s.name.as_str()).collect();
+    assert_eq!(names.len(), 3);
+    assert!(names.contains(&"PostgreSQL") && names.contains(&"Go") && names.contains(&"Rust"));
+    assert_eq!(report.skills[3].name, "Leadership");
+}
+
+#[test]
+fn test_draft_keeps_top_bullets() {
+    let experiences = common::experiences();
+    let skills = common::resolved_resume().skills;
+    let report = rank(JOB, &experiences, &skills);
+    let profile_id = ObjectId::new();
+    let resume = draft_resume(&report, &experiences, profile_id, "Acme application".to_string(), 1);
+
+    assert_eq!(resume.name, "Acme application");
+    assert_eq!(resume.profile_id, profile_id);
+    // Globex matches through `engineers`
+    assert_eq!(resume.experiences.len(), 2);
+    assert_eq!(resume.experiences[0].experience_id, experiences[0].id.unwrap());
+    assert_eq!(resume.experiences[0].responsibilities, Some(vec![experiences[0].responsibilities[0].id.unwrap()]));
+    assert_eq!(resume.skills.len(), 3);
+    assert!(!resume.skills.contains(&skills[3].id.unwrap()));
+}
+
+#[test]
+fn test_inline_responsibilities_get_ids_to_be_drafted() {
+    // As sent inline to `POST /experiences`, without ids
+    let mut experiences = common::experiences();
+    experiences.iter_mut().flat_map(|e| &mut e.responsibilities).for_each(|r| r.id = None);
+    let report = rank(JOB, &experiences, &common::resolved_resume().skills);
+    let resume = draft_resume(&report, &experiences, ObjectId::new(), "Acme application".to_string(), 1);
+    assert_eq!(resume.experiences[0].responsibilities, Some(vec![]));
+
+    experiences.iter_mut().for_each(|e| e.assign_responsibility_ids());
+    let kept = experiences[0].responsibilities[0].id;
+    assert!(kept.is_some() && experiences[0].responsibilities[1].id.is_some());
+    experiences[0].assign_responsibility_ids();
+    assert_eq!(experiences[0].responsibilities[0].id, kept);
+    let names: Vec<String> = experiences[0].ordered_responsibilities().into_iter().map(|r| r.name).collect();
+    assert_eq!(names[0], "Led the migration of billing to Rust, cutting costs by 30%");
+
+    let report = rank(JOB, &experiences, &common::resolved_resume().skills);
+    let resume = draft_resume(&report, &experiences, ObjectId::new(), "Acme application".to_string(), 1);
+    assert_eq!(resume.experiences[0].responsibilities, Some(vec![kept.unwrap()]));
+}
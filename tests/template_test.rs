@@ -0,0 +1,134 @@
+mod common;
+
+use std::time::{Duration, Instant};
+use rust_server::models::template::{Template, TemplateAsset};
+use rust_server::renderers::template::{compile_template, RenderLimits, RANGE_LIMIT};
+
+fn template(source: &str) -> Template {
+    Template { id: None, name: "Designer".to_string(), source: source.to_string(), assets: vec![] }
+}
+
+fn render(source: &str, limits: RenderLimits) -> Result<String, String> {
+    compile_template(&template(source))
+        .and_then(|compiled| compiled.render(&common::resolved_resume(), "%b %Y", limits))
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_renders_view_model_and_assets() {
+    let mut upload = template(
+        "<link rel=\"stylesheet\" href=\"{{ assets['style.css'] | safe }}\">\
+         <h1>{{ profile.name }}</h1>{% for e in experiences %}<p>{{ e.company }} {{ e.start }}</p>{% endfor %}",
+    );
+    upload.assets.push(TemplateAsset {
+        name: "style.css".to_string(),
+        content_type: "text/css".to_string(),
+        data: "aDEgeyBjb2xvcjogcmVkIH0=".to_string(),
+    });
+    let html = compile_template(&upload).unwrap()
+        .render(&common::resolved_resume(), "%b %Y", RenderLimits::default())
+        .unwrap();
+    assert!(html.contains("href=\"data:text/css;base64,aDEgeyBjb2xvcjogcmVkIH0=\""));
+    assert!(html.contains("<h1>Ada Lovelace</h1>"));
+    // Output is HTML-escaped like the bundled themes
+    assert!(html.contains("<p>Acme &amp; Sons Mar 2021</p>"));
+}
+
+#[test]
+fn test_syntax_errors_report_line_and_column() {
+    let error = compile_template(&template("<h1>{{ profile.name }}</h1>\n<p>{% if %}</p>")).err().unwrap().to_string();
+    assert!(error.contains("Invalid template: line 2, column 10"), "{}", error);
+
+    let error = compile_template(&template("{% extends \"base.html\" %}")).err().unwrap().to_string();
+    assert!(error.contains("base.html"), "{}", error);
+}
+
+#[test]
+fn test_rejects_invalid_assets() {
+    let asset = |name: &str, content_type: &str, data: &str| TemplateAsset {
+        name: name.to_string(),
+        content_type: content_type.to_string(),
+        data: data.to_string(),
+    };
+    let mut upload = template("<p></p>");
+    upload.assets = vec![asset("logo.png", "image/png", "not base64!")];
+    assert!(compile_template(&upload).err().unwrap().to_string().contains("Asset 'logo.png' is not valid base64"));
+    upload.assets = vec![asset("logo.png", "image/png\"><script>", "")];
+    assert!(compile_template(&upload).err().unwrap().to_string().contains("invalid content type"));
+    upload.assets = vec![asset("a.css", "text/css", ""), asset("a.css", "text/css", "")];
+    assert!(compile_template(&upload).err().unwrap().to_string().contains("listed more than once"));
+}
+
+#[test]
+fn test_rendering_is_sandboxed() {
+    let limits = RenderLimits::default();
+    let error = render("{% include \"/etc/passwd\" %}", limits).unwrap_err();
+    assert!(error.contains("Invalid template: `include` is not supported"), "{}", error);
+    // Including itself would recurse until the stack overflows
+    let error = render("<p>{% if profile %}{% include \"template.html\" %}{% endif %}</p>", limits).unwrap_err();
+    assert!(error.contains("Invalid template: `include` is not supported"), "{}", error);
+    let error = render("{{ get_env(name=\"HOME\") }}", limits).unwrap_err();
+    assert!(error.contains("`get_env` is not available"), "{}", error);
+    let error = render(&format!("{{% for i in range(end={}) %}}{{% endfor %}}", RANGE_LIMIT + 1), limits).unwrap_err();
+    assert!(error.contains("limited to"), "{}", error);
+
+    let small = RenderLimits { output_bytes: 100, ..limits };
+    let error = render("{% for i in range(end=100) %}{{ profile.name }}{% endfor %}", small).unwrap_err();
+    assert!(error.contains("exceeds the limit of 100 bytes"), "{}", error);
+
+    let quick = RenderLimits { timeout: Duration::from_millis(50), ..limits };
+    let source = "{% for a in range(end=10000) %}{% for b in range(end=10000) %}{% endfor %}{% endfor %}";
+    let error = render(source, quick).unwrap_err();
+    assert!(error.contains("exceeds the time limit of 50 ms"), "{}", error);
+}
+
+#[test]
+fn test_loops_without_output_are_abandoned_at_the_deadline() {
+    let quick = RenderLimits { timeout: Duration::from_millis(200), ..RenderLimits::default() };
+    // No function call or output inside the loops to check the time against
+    let items = vec!["a"; 150].join(",");
+    let source = format!(
+        "{{% set xs = \"{}\" | split(pat=\",\") %}}{{% for a in xs %}}{{% for b in xs %}}{{% for c in xs %}}{{% endfor %}}{{% endfor %}}{{% endfor %}}",
+        items
+    );
+    let started = Instant::now();
+    let error = render(&source, quick).unwrap_err();
+    assert!(error.contains("exceeds the time limit of 200 ms"), "{}", error);
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn test_renders_stop_at_the_deadline() {
+    let quick = RenderLimits { timeout: Duration::from_millis(50), ..RenderLimits::default() };
+    // Neither output nor `range` inside the loops to check the time against
+    let source = "{% set r = range(end=10000) %}{% for a in r %}{% for b in r %}{% for c in r %}{% endfor %}{% endfor %}{% endfor %}";
+    for _ in 0..12 {
+        let started = Instant::now();
+        let error = render(source, quick).unwrap_err();
+        assert!(error.contains("exceeds the time limit of 50 ms"), "{}", error);
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+    let error = render(&format!("{{% block main %}}{}{{% endblock %}}", source), quick).unwrap_err();
+    assert!(error.contains("exceeds the time limit of 50 ms"), "{}", error);
+    // Every render returned its slot
+    assert!(render("<h1>{{ profile.name }}</h1>", RenderLimits::default()).is_ok());
+}
+
+#[test]
+fn test_values_cannot_grow_outside_the_output() {
+    let error = render(
+        "{% set_global s = profile.name %}{% for i in range(end=40) %}{% set_global s = s ~ s %}{% endfor %}",
+        RenderLimits::default(),
+    ).unwrap_err();
+    assert!(error.contains("`set s` cannot be built from `s`"), "{}", error);
+    let error = render("{% set a = profile.name %}{% set b = a ~ a %}", RenderLimits::default()).unwrap_err();
+    assert!(error.contains("`set b` cannot be built from `a`"), "{}", error);
+    let error = render("{% filter upper %}{{ profile.name }}{% endfilter %}", RenderLimits::default()).unwrap_err();
+    assert!(error.contains("`filter` sections are not supported"), "{}", error);
+    let error = render("{% macro twice(s) %}{{ s }}{{ s }}{% endmacro %}{{ self::twice(s=profile.name) }}", RenderLimits::default()).unwrap_err();
+    assert!(error.contains("macros are not supported"), "{}", error);
+
+    // Values computed from the resume are fine
+    let html = render("{% set name = profile.name | upper %}{% for e in experiences %}{% set title = e.job_title ~ \"!\" %}{{ title }}{% endfor %}{{ name }}", RenderLimits::default()).unwrap();
+    assert!(html.ends_with("Software Engineer!ADA LOVELACE"), "{}", html);
+}
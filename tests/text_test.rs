@@ -0,0 +1,81 @@
+mod common;
+
+use rust_server::models::resume::{ResumeSection, SectionKind};
+use rust_server::renderers::{experience_resume, ordered_experience};
+use rust_server::renderers::text::{render_experience_text, render_text, to_ascii, wrap, TextOptions};
+
+#[test]
+fn test_render_text_document() {
+    let text = render_text(&common::resolved_resume(), &TextOptions::default());
+
+    assert!(text.starts_with("ADA LOVELACE\nBackend Engineer\nada@example.com | +44 20 7946 0000 | https://ada.example.com | London, UK\nGitHub: https://github.com/ada\n"));
+    assert!(text.contains("\nSUMMARY\nEngineer focused on reliable distributed systems.\n"));
+    assert!(text.contains("\nEXPERIENCE\nSenior Backend Engineer\nAcme & Sons | London\n03/2021 - 02/2024\n- Led the migration"));
+    assert!(text.contains("\nSoftware Engineer\nGlobex | Remote\n01/2018 - 02/2021\n"));
+    assert!(text.contains("\nEDUCATION\nBSc, Mathematics\nUniversity of London\n09/2014 - 06/2017\n"));
+    assert!(text.contains("\nSKILLS\nLanguages: Rust, Go\nDatabases: PostgreSQL\nOther: Leadership\n"));
+    assert!(text.find("EDUCATION").unwrap() < text.find("SKILLS").unwrap());
+    assert!(text.is_ascii());
+}
+
+#[test]
+fn test_headings_ignore_custom_titles() {
+    let mut resume = common::resolved_resume();
+    resume.sections = vec![
+        ResumeSection { kind: SectionKind::Skills, title: Some("Toolbox".to_string()) },
+        ResumeSection { kind: SectionKind::Experience, title: Some("Where I worked".to_string()) },
+    ];
+    let text = render_text(&resume, &TextOptions::default());
+    assert!(!text.contains("Toolbox") && !text.contains("Where I worked"));
+    assert!(text.find("SKILLS").unwrap() < text.find("EXPERIENCE").unwrap());
+    assert!(!text.contains("SUMMARY"));
+
+    resume.sections = vec![ResumeSection { kind: SectionKind::Skills, title: None }];
+    let text = render_text(&resume, &TextOptions::default());
+    assert!(text.trim_end().ends_with("09/2014 - 06/2017"));
+}
+
+#[test]
+fn test_line_width_and_ascii() {
+    let mut resume = common::resolved_resume();
+    resume.experiences[0].responsibilities[0].name =
+        "Led the “billing” migration — moving 1.2M invoices/day… to Rust at Zürich’s office".to_string();
+    let options = TextOptions { line_width: 40, ascii: true };
+    let text = render_text(&resume, &options);
+
+    assert!(text.lines().all(|line| line.chars().count() <= 40), "{}", text);
+    assert!(text.contains("- Led the \"billing\" migration - moving\n  1.2M invoices/day... to Rust at\n  Zurich's office\n"));
+    assert!(text.is_ascii());
+
+    let unicode = render_text(&resume, &TextOptions { ascii: false, ..options });
+    assert!(unicode.contains("“billing”"));
+
+    assert!(TextOptions { line_width: 20, ascii: true }.validate().is_err());
+}
+
+#[test]
+fn test_wrap_and_transliteration() {
+    assert_eq!(wrap("alpha beta gamma", 10, "  "), vec!["alpha beta", "  gamma"]);
+    assert_eq!(wrap("abcdefghijkl", 5, " "), vec!["abcde", " fghi", " jkl"]);
+    assert_eq!(wrap("", 10, ""), vec![""]);
+    assert_eq!(to_ascii("café • 10×faster → “done”…"), "cafe * 10xfaster -> \"done\"...");
+}
+
+#[test]
+fn test_render_single_experience() {
+    let experience = &common::experiences()[1];
+    let text = render_experience_text(experience, &TextOptions::default());
+    assert_eq!(text, "Software Engineer\nGlobex | Remote\n01/2018 - 02/2021\n- Built internal tooling for 200 engineers\n");
+}
+
+#[test]
+fn test_single_experience_follows_linked_order() {
+    // Stored as added, but linked so the second bullet comes first
+    let mut experience = common::experiences().remove(0);
+    experience.responsibilities[1].next = experience.responsibilities[0].id;
+    let ordered = ordered_experience(&experience);
+    let text = render_experience_text(&ordered, &TextOptions::default());
+    assert!(text.contains("- Introduced request caching, reduced p99 latency by 45%\n- Led the migration"), "{}", text);
+    assert_eq!(ordered.responsibilities[0].id, experience.responsibilities[1].id);
+    assert_eq!(experience_resume(&experience).experiences[0].responsibilities[0].id, experience.responsibilities[1].id);
+}
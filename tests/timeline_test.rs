@@ -0,0 +1,86 @@
+mod common;
+
+use chrono::NaiveDate;
+use mongodb::bson::oid::ObjectId;
+use rust_server::models::experience::Experience;
+use rust_server::services::timeline::timeline;
+
+fn role(job_title: &str, start_date: &str, end_date: &str) -> Experience {
+    Experience {
+        id: Some(ObjectId::new()),
+        job_title: job_title.to_string(),
+        company: "Initech".to_string(),
+        start_date: start_date.to_string(),
+        end_date: end_date.to_string(),
+        responsibilities: vec![],
+        ..common::experiences().remove(1)
+    }
+}
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 6, 30).unwrap()
+}
+
+// Globex 2018-01-15 to 2021-02-28 and Acme 2021-03-01 to 2024-02-29, then
+// a contract overlapping Acme, a gap and a role still running.
+fn history() -> Vec<Experience> {
+    let mut experiences = common::experiences();
+    experiences.push(role("Consultant", "2023-12-01", "2024-03-31"));
+    experiences.push(role("Staff Engineer", "2024-09-01", "2030-01-01"));
+    experiences
+}
+
+#[test]
+fn test_gaps_and_overlaps() {
+    let experiences = history();
+    let report = timeline(&experiences, 90, today());
+
+    // Globex and Acme follow each other without a gap
+    assert_eq!(report.gaps.len(), 1);
+    let gap = &report.gaps[0];
+    assert_eq!((gap.start.as_str(), gap.end.as_str(), gap.days), ("2024-04-01", "2024-08-31", 153));
+    assert_eq!(gap.after.job_title, "Consultant");
+    assert_eq!(gap.before.experience_id, experiences[3].id);
+    assert!(timeline(&experiences, 153, today()).gaps.is_empty());
+
+    assert_eq!(report.overlaps.len(), 1);
+    let overlap = &report.overlaps[0];
+    assert_eq!((overlap.start.as_str(), overlap.end.as_str(), overlap.days), ("2023-12-01", "2024-02-29", 91));
+    assert_eq!(overlap.roles[0].company, "Acme & Sons");
+    assert_eq!(overlap.roles[1].job_title, "Consultant");
+}
+
+#[test]
+fn test_totals_merge_overlaps() {
+    let report = timeline(&history(), 90, today());
+
+    // 2018-01-15 to 2024-03-31 and 2024-09-01 to today
+    assert_eq!(report.total_days, 2268 + 303);
+    assert_eq!(report.total_years, 7.04);
+    let years: Vec<(i32, i64, usize)> = report.years.iter().map(|y| (y.year, y.days, y.roles.len())).collect();
+    assert_eq!(years.first(), Some(&(2018, 351, 1)));
+    assert_eq!(years[3], (2021, 365, 2));
+    assert_eq!(years[6], (2024, 91 + 122, 3));
+    assert_eq!(years.last(), Some(&(2025, 181, 1)));
+    assert_eq!(report.years.iter().map(|y| y.days).sum::<i64>(), report.total_days);
+}
+
+#[test]
+fn test_inconsistent_dates_are_reported_and_skipped() {
+    let mut experiences = history();
+    experiences.push(role("Intern", "2017-06-30", "2017-06-01"));
+    experiences.push(role("Founder", "2026-01-01", "2027-01-01"));
+    experiences.push(role("Freelancer", "2019", "2020-01-01"));
+    let report = timeline(&experiences, 90, today());
+
+    let kinds: Vec<(&str, &str)> = report.inconsistencies.iter()
+        .map(|i| (i.kind.as_str(), i.role.job_title.as_str()))
+        .collect();
+    assert_eq!(kinds, vec![("end-before-start", "Intern"), ("starts-in-future", "Founder"), ("invalid-date", "Freelancer")]);
+    assert_eq!(report.inconsistencies[0].message, "Ends on 2017-06-01 before it starts on 2017-06-30");
+    assert_eq!(report.total_days, 2268 + 303);
+    assert_eq!(report.years[0].year, 2018);
+
+    let empty = timeline(&[], 90, today());
+    assert_eq!((empty.total_days, empty.total_years, empty.years.len()), (0, 0.0, 0));
+}
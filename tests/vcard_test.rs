@@ -0,0 +1,37 @@
+mod common;
+
+use rust_server::renderers::vcard::render_vcard;
+
+#[test]
+fn test_vcard_fields() {
+    let vcard = render_vcard(&common::profile());
+    let lines: Vec<&str> = vcard.split("\r\n").collect();
+
+    assert_eq!(&lines[..3], &["BEGIN:VCARD", "VERSION:4.0", "PRODID:-//rust-server//vCard//EN"]);
+    assert!(lines.contains(&"FN:Ada Lovelace"));
+    assert!(lines.contains(&"N:Lovelace;Ada;;;"));
+    assert!(lines.contains(&"TITLE:Backend Engineer"));
+    assert!(lines.contains(&"EMAIL;TYPE=home:ada@example.com"));
+    assert!(lines.contains(&"TEL;VALUE=uri;TYPE=cell,voice:tel:+44-20-7946-0000"));
+    assert!(lines.contains(&"ADR;TYPE=home:;;;London;;;UK"));
+    assert!(lines.contains(&"URL:https://ada.example.com"));
+    assert!(lines.contains(&"X-SOCIALPROFILE;TYPE=github:https://github.com/ada"));
+    assert!(lines.contains(&"NOTE:Engineer focused on reliable distributed systems."));
+    assert!(vcard.ends_with("END:VCARD\r\n"));
+}
+
+#[test]
+fn test_vcard_escapes_and_folds() {
+    let mut profile = common::profile();
+    profile.label = Some("Engineer; Rust, Go".to_string());
+    profile.summary = Some(format!("Line one\nLine two {}", "é".repeat(60)));
+    let vcard = render_vcard(&profile);
+
+    assert!(vcard.contains("TITLE:Engineer\\; Rust\\, Go\r\n"));
+    assert!(vcard.contains("NOTE:Line one\\nLine two "));
+    for line in vcard.split("\r\n") {
+        assert!(line.len() <= 75, "line of {} octets: {}", line.len(), line);
+    }
+    let unfolded = vcard.replace("\r\n ", "");
+    assert!(unfolded.contains(&format!("Line two {}\r\n", "é".repeat(60))));
+}